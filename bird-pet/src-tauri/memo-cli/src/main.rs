@@ -0,0 +1,135 @@
+//! memo-cli：BirdPet 的终端伴侣，与桌面应用读写同一数据目录
+//!
+//! ```text
+//! memo-cli add <内容>     记一条回忆（桌面应用启动时并入回忆面板）
+//! memo-cli today          打印今日报告
+//! memo-cli backup         备份数据到 <数据目录>/backups（保留最近 7 份）
+//! --data-dir <路径>       指定数据目录（默认与桌面应用一致）
+//! ```
+
+use chrono::Local;
+use memo_core::backup::backup_and_prune;
+use memo_core::inbox::{self, InboxMemory};
+use memo_core::report::render_day_text;
+use memo_core::store::{self, EVENTS_KEY};
+use memo_core::timeline::{timeline_for_day, MemoryEvent};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "用法: memo-cli [--data-dir <路径>] <add <内容> | today | backup>";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Add(String),
+    Today,
+    Backup,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    data_dir: Option<PathBuf>,
+    command: Command,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut data_dir = None;
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--data-dir" {
+            let dir = args.next().ok_or("--data-dir 缺少路径")?;
+            data_dir = Some(PathBuf::from(dir));
+        } else {
+            rest.push(arg);
+        }
+    }
+
+    let command = match rest.first().map(String::as_str) {
+        Some("add") => {
+            let message = rest[1..].join(" ").trim().to_string();
+            if message.is_empty() {
+                return Err("add 需要回忆内容".to_string());
+            }
+            Command::Add(message)
+        }
+        Some("today") if rest.len() == 1 => Command::Today,
+        Some("backup") if rest.len() == 1 => Command::Backup,
+        _ => return Err(USAGE.to_string()),
+    };
+    Ok(Args { data_dir, command })
+}
+
+fn run(args: Args) -> Result<String, String> {
+    let dir = match args.data_dir {
+        Some(dir) => dir,
+        None => store::default_data_dir().ok_or("无法确定数据目录，请用 --data-dir 指定")?,
+    };
+    let now = Local::now();
+
+    match args.command {
+        Command::Add(message) => {
+            let memory = InboxMemory {
+                message,
+                at: now.timestamp_millis(),
+            };
+            let pending = inbox::push(&dir, memory)?;
+            Ok(format!(
+                "记下啦，共 {pending} 条等待桌面应用下次启动时收进回忆"
+            ))
+        }
+        Command::Today => {
+            // 只读：记忆事件以桌面应用最近一次保存为准
+            let store = store::read_store(&dir)?;
+            let events: Vec<MemoryEvent> = store::get_or_default(&store, EVENTS_KEY);
+            let day = now.format("%Y-%m-%d").to_string();
+            let segments = timeline_for_day(events, &day, now.timestamp_millis())?;
+            Ok(render_day_text(&day, &segments))
+        }
+        Command::Backup => {
+            let path = backup_and_prune(&dir, &now.format("%Y%m%d-%H%M%S").to_string())?;
+            Ok(format!("已备份到 {}", path.display()))
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match parse_args(std::env::args().skip(1)).and_then(run) {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, Args, Command};
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn add_should_join_words_and_accept_data_dir_anywhere() {
+        assert_eq!(
+            parse(&["add", "今天", "修好了", "--data-dir", "/tmp/bird"]),
+            Ok(Args {
+                data_dir: Some(PathBuf::from("/tmp/bird")),
+                command: Command::Add("今天 修好了".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_should_be_rejected() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["add", "  "]).is_err());
+        assert!(parse(&["today", "extra"]).is_err());
+        assert!(parse(&["backup", "--data-dir"]).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::lock::with_lock;
+use crate::store::STORE_FILE;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 备份存放目录（位于数据目录下）
+pub const BACKUP_DIR: &str = "backups";
+/// 保留的备份份数（桌面应用与 memo-cli 共用）
+pub const KEEP_BACKUPS: usize = 7;
+
+/// 备份存储文件并清理旧备份，只保留最新的 KEEP_BACKUPS 份，返回备份路径
+///
+/// 持数据目录锁执行，桌面应用与 memo-cli 同时备份时不会交错复制和清理。
+/// 存储文件由 tauri-plugin-store 直接写入，锁挡不住这次写入，
+/// 所以复制前仍校验内容是完整的 JSON，避免留下半截备份。
+pub fn backup_and_prune(dir: &Path, stamp: &str) -> Result<PathBuf, String> {
+    with_lock(dir, || {
+        let path = copy_store(dir, stamp)?;
+        prune(dir, KEEP_BACKUPS)?;
+        Ok(path)
+    })
+}
+
+/// 把存储文件复制到 backups/pet-state-{stamp}.json，返回备份路径
+fn copy_store(dir: &Path, stamp: &str) -> Result<PathBuf, String> {
+    let source = dir.join(STORE_FILE);
+    let text =
+        fs::read_to_string(&source).map_err(|e| format!("读取 {} 失败: {e}", source.display()))?;
+    serde_json::from_str::<serde_json::Value>(&text)
+        .map_err(|_| "存储文件正在写入，请稍后重试".to_string())?;
+
+    let target_dir = dir.join(BACKUP_DIR);
+    fs::create_dir_all(&target_dir).map_err(|e| e.to_string())?;
+    let target = target_dir.join(format!("pet-state-{stamp}.json"));
+    fs::write(&target, text).map_err(|e| e.to_string())?;
+    Ok(target)
+}
+
+/// 只保留最新的 `keep` 份备份（按文件名中的时间戳排序），返回删除的份数
+pub fn prune(dir: &Path, keep: usize) -> Result<usize, String> {
+    let entries = match fs::read_dir(dir.join(BACKUP_DIR)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut backups: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("pet-state-") && name.ends_with(".json"))
+        })
+        .collect();
+    backups.sort();
+    let excess = backups.len().saturating_sub(keep);
+    for path in &backups[..excess] {
+        fs::remove_file(path).map_err(|e| e.to_string())?;
+    }
+    Ok(excess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backup_and_prune, prune, BACKUP_DIR, KEEP_BACKUPS};
+    use crate::store::STORE_FILE;
+
+    #[test]
+    fn backup_should_copy_only_complete_store() {
+        let dir = std::env::temp_dir().join(format!("memo-core-backup-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(STORE_FILE), r#"{"memoryEvents": [{"type": "inter"#).unwrap();
+        let partial = backup_and_prune(&dir, "a");
+
+        std::fs::write(dir.join(STORE_FILE), r#"{"memoryEvents": []}"#).unwrap();
+        let copied = backup_and_prune(&dir, "b").unwrap();
+        let content = std::fs::read_to_string(&copied).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(partial.is_err());
+        assert!(copied.ends_with("backups/pet-state-b.json"));
+        assert_eq!(content, r#"{"memoryEvents": []}"#);
+    }
+
+    #[test]
+    fn backup_should_prune_to_keep_limit() {
+        let dir = std::env::temp_dir().join(format!("memo-core-keep-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(STORE_FILE), "{}").unwrap();
+        for day in 1..=KEEP_BACKUPS + 2 {
+            backup_and_prune(&dir, &format!("202601{day:02}-090000")).unwrap();
+        }
+        let count = std::fs::read_dir(dir.join(BACKUP_DIR)).unwrap().count();
+        let oldest_kept = dir
+            .join(BACKUP_DIR)
+            .join("pet-state-20260103-090000.json")
+            .exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(count, KEEP_BACKUPS);
+        assert!(oldest_kept);
+    }
+
+    #[test]
+    fn prune_should_keep_newest_backups() {
+        let dir = std::env::temp_dir().join(format!("memo-core-prune-{}", std::process::id()));
+        assert_eq!(prune(&dir, 2).unwrap(), 0);
+
+        let backups = dir.join(BACKUP_DIR);
+        std::fs::create_dir_all(&backups).unwrap();
+        for stamp in ["20260101-090000", "20260103-090000", "20260102-090000"] {
+            std::fs::write(backups.join(format!("pet-state-{stamp}.json")), "{}").unwrap();
+        }
+        std::fs::write(backups.join("notes.txt"), "").unwrap();
+        let removed = prune(&dir, 2).unwrap();
+        let mut left: Vec<String> = std::fs::read_dir(&backups)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        left.sort();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(removed, 1);
+        assert_eq!(
+            left,
+            [
+                "notes.txt",
+                "pet-state-20260102-090000.json",
+                "pet-state-20260103-090000.json"
+            ]
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::lock::with_lock;
+use serde::{Deserialize, Serialize};
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// memo-cli 回忆收件箱
+///
+/// 桌面应用运行时持有存储文件的内存副本，直接改 pet-state.json 会被应用下次保存覆盖，
+/// 所以 CLI 只追加到独立的收件箱，由应用取出后并入回忆列表。
+pub const INBOX_FILE: &str = "cli-inbox.json";
+
+/// 待导入的回忆
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InboxMemory {
+    pub message: String,
+    /// 记录时间（ms）
+    pub at: i64,
+}
+
+fn read(dir: &Path) -> Result<Vec<InboxMemory>, String> {
+    match std::fs::read_to_string(dir.join(INBOX_FILE)) {
+        Ok(text) => serde_json::from_str(&text).map_err(|e| format!("收件箱格式错误: {e}")),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 追加一条回忆，返回收件箱中待导入的条数
+pub fn push(dir: &Path, memory: InboxMemory) -> Result<usize, String> {
+    with_lock(dir, || {
+        let mut pending = read(dir)?;
+        pending.push(memory);
+        let text = serde_json::to_string(&pending).map_err(|e| e.to_string())?;
+        std::fs::write(dir.join(INBOX_FILE), text).map_err(|e| e.to_string())?;
+        Ok(pending.len())
+    })
+}
+
+/// 取出全部待导入的回忆并清空收件箱
+pub fn take(dir: &Path) -> Result<Vec<InboxMemory>, String> {
+    with_lock(dir, || {
+        let pending = read(dir)?;
+        if !pending.is_empty() {
+            std::fs::remove_file(dir.join(INBOX_FILE)).map_err(|e| e.to_string())?;
+        }
+        Ok(pending)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{push, take, InboxMemory};
+
+    #[test]
+    fn take_should_drain_pushed_memories_in_order() {
+        let dir = std::env::temp_dir().join(format!("memo-core-inbox-{}", std::process::id()));
+        let memory = |message: &str, at| InboxMemory {
+            message: message.to_string(),
+            at,
+        };
+
+        assert_eq!(push(&dir, memory("第一条", 1)).unwrap(), 1);
+        assert_eq!(push(&dir, memory("第二条", 2)).unwrap(), 2);
+        let taken = take(&dir).unwrap();
+        let drained = take(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(taken, vec![memory("第一条", 1), memory("第二条", 2)]);
+        assert!(drained.is_empty());
+    }
+}
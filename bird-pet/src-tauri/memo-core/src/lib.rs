@@ -0,0 +1,11 @@
+//! BirdPet 数据核心：存储布局、时间线拼装、报告与月度回顾渲染、CLI 收件箱与备份
+//!
+//! 不依赖 Tauri，桌面应用与 memo-cli 共用同一份逻辑，读写同一数据目录。
+
+pub mod backup;
+pub mod inbox;
+pub mod lock;
+pub mod recap;
+pub mod report;
+pub mod store;
+pub mod timeline;
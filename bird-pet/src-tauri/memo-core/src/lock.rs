@@ -0,0 +1,22 @@
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+
+/// 数据目录下的锁文件
+pub const LOCK_FILE: &str = "memo.lock";
+
+/// 持有数据目录的进程间排他锁执行 `f`
+///
+/// 保护 memo-cli 与桌面应用共同读写的 CLI 收件箱和备份目录；pet-state.json 由
+/// tauri-plugin-store 直接写入，不经过这把锁（备份时另行校验内容完整）。
+/// 另一进程持锁时阻塞等待，锁随文件句柄释放（进程崩溃也不会残留）。
+pub fn with_lock<T>(dir: &Path, f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(dir.join(LOCK_FILE))
+        .map_err(|e| e.to_string())?;
+    file.lock().map_err(|e| format!("锁定数据目录失败: {e}"))?;
+    f()
+}
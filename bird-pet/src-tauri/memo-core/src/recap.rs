@@ -0,0 +1,170 @@
+use crate::report::escape_html;
+use serde::Deserialize;
+
+/// 图片宽度（像素）
+const WIDTH: u32 = 600;
+
+/// 前端 MonthlyRecap
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MonthlyRecap {
+    pub month: String,
+    pub top_apps: Vec<AppUsage>,
+    pub best_focus_day: Option<FocusDay>,
+    pub total_pomodoros: u32,
+    pub active_days: u32,
+    pub highlights: Vec<String>,
+    pub milestones: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AppUsage {
+    pub app: String,
+    pub minutes: u32,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FocusDay {
+    pub date: String,
+    pub pomodoros: u32,
+}
+
+/// 单行文本
+fn text(y: u32, size: u32, color: &str, content: &str) -> String {
+    format!(
+        "<text x=\"40\" y=\"{y}\" font-size=\"{size}\" fill=\"{color}\">{}</text>\n",
+        escape_html(content)
+    )
+}
+
+/// 将月度回顾按固定模板渲染为 SVG 图片（内联字体与配色，可直接分享）
+pub fn render_recap_svg(recap: &MonthlyRecap) -> String {
+    let mut body = String::new();
+    let mut y = 70;
+    body.push_str(&text(
+        y,
+        30,
+        "#4a3b2f",
+        &format!("🐦 {} 月度回顾", recap.month),
+    ));
+    y += 40;
+    body.push_str(&text(
+        y,
+        16,
+        "#9a8573",
+        &format!(
+            "一起度过 {} 天 · 完成 {} 个番茄",
+            recap.active_days, recap.total_pomodoros
+        ),
+    ));
+
+    y += 50;
+    body.push_str(&text(y, 20, "#e8844a", "最常用的应用"));
+    if recap.top_apps.is_empty() {
+        y += 30;
+        body.push_str(&text(y, 16, "#9a8573", "还没有记录到应用"));
+    }
+    let max_minutes = recap
+        .top_apps
+        .iter()
+        .map(|a| a.minutes)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for usage in &recap.top_apps {
+        y += 34;
+        let bar = 20 + (WIDTH - 260) * usage.minutes / max_minutes;
+        body.push_str(&format!(
+            "<rect x=\"200\" y=\"{}\" width=\"{bar}\" height=\"18\" rx=\"9\" fill=\"#fdebd9\" />\n",
+            y - 14
+        ));
+        body.push_str(&text(
+            y,
+            16,
+            "#4a3b2f",
+            &format!("{}  {} 分钟", usage.app, usage.minutes),
+        ));
+    }
+
+    if let Some(day) = &recap.best_focus_day {
+        y += 50;
+        body.push_str(&text(y, 20, "#e8844a", "最专注的一天"));
+        y += 30;
+        body.push_str(&text(
+            y,
+            16,
+            "#4a3b2f",
+            &format!("{} · {} 个番茄 🍅", day.date, day.pomodoros),
+        ));
+    }
+
+    for (title, items) in [
+        ("回忆亮点", &recap.highlights),
+        ("达成的里程碑", &recap.milestones),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        y += 50;
+        body.push_str(&text(y, 20, "#e8844a", title));
+        for item in items.iter().take(5) {
+            y += 30;
+            body.push_str(&text(y, 16, "#4a3b2f", &format!("· {item}")));
+        }
+    }
+
+    let height = y + 60;
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" \
+         viewBox=\"0 0 {WIDTH} {height}\" font-family=\"Segoe UI, Microsoft YaHei, sans-serif\">\n\
+         <rect width=\"100%\" height=\"100%\" rx=\"24\" fill=\"#fff8f0\" />\n{body}</svg>\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_recap_svg, AppUsage, FocusDay, MonthlyRecap};
+
+    #[test]
+    fn recap_svg_should_escape_and_include_sections() {
+        let recap = MonthlyRecap {
+            month: "2026-03".to_string(),
+            top_apps: vec![
+                AppUsage {
+                    app: "Code".to_string(),
+                    minutes: 1200,
+                },
+                AppUsage {
+                    app: "<script>".to_string(),
+                    minutes: 30,
+                },
+            ],
+            best_focus_day: Some(FocusDay {
+                date: "2026-03-12".to_string(),
+                pomodoros: 6,
+            }),
+            total_pomodoros: 40,
+            active_days: 22,
+            highlights: vec!["接上了新屏幕".to_string()],
+            milestones: Vec::new(),
+        };
+        let svg = render_recap_svg(&recap);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("2026-03 月度回顾"));
+        assert!(svg.contains("Code  1200 分钟"));
+        assert!(svg.contains("&lt;script&gt;"));
+        assert!(svg.contains("2026-03-12 · 6 个番茄"));
+        assert!(svg.contains("· 接上了新屏幕"));
+        assert!(!svg.contains("达成的里程碑"));
+    }
+
+    #[test]
+    fn empty_recap_should_render_placeholder() {
+        let svg = render_recap_svg(&MonthlyRecap::default());
+        assert!(svg.contains("还没有记录到应用"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,304 @@
+use crate::timeline::{SegmentKind, TimelineSegment};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// 报告周期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportPeriod {
+    /// 最近 7 天（记忆系统的滚动窗口）
+    Week,
+}
+
+impl ReportPeriod {
+    /// 覆盖的天数
+    pub fn days(self) -> usize {
+        match self {
+            ReportPeriod::Week => 7,
+        }
+    }
+
+    /// 报告标题
+    pub fn title(self) -> &'static str {
+        match self {
+            ReportPeriod::Week => "BirdPet 周报",
+        }
+    }
+
+    /// 导出文件名前缀
+    pub fn file_stem(self) -> &'static str {
+        match self {
+            ReportPeriod::Week => "birdpet-weekly",
+        }
+    }
+}
+
+/// 前端 DailySummary
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DailySummary {
+    pub date: String,
+    pub active_hours: (u32, u32),
+    pub dominant_context: String,
+    pub context_durations: BTreeMap<String, u32>,
+    pub interaction_count: u32,
+    pub pomodoro_count: u32,
+}
+
+/// 前端 UserProfile（仅取报告需要的字段）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserProfile {
+    pub total_interactions: u64,
+    pub streak_days: u32,
+    pub daily_summaries: Vec<DailySummary>,
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// 行为上下文中文名（与前端回忆面板 CONTEXT_LABELS 一致）
+fn context_label(context: &str) -> &str {
+    match context {
+        "coding" => "编程 💻",
+        "browsing" => "浏览网页 🌐",
+        "gaming" => "打游戏 🎮",
+        "music" => "听音乐 🎵",
+        "meeting" => "开会 📞",
+        "idle" => "休息 ☕",
+        "unknown" => "其他",
+        "system" => "未追踪（系统）",
+        other => other,
+    }
+}
+
+const REPORT_STYLE: &str = r#"
+body { margin: 0; padding: 32px; background: #fff8f0; color: #4a3b2f;
+  font-family: "Segoe UI", "Microsoft YaHei", sans-serif; }
+main { max-width: 720px; margin: 0 auto; }
+h1 { margin: 0 0 4px; font-size: 28px; }
+.range { color: #9a8573; margin-bottom: 24px; }
+.stats { display: grid; grid-template-columns: repeat(4, 1fr); gap: 12px; margin-bottom: 24px; }
+.stat { background: #fff; border-radius: 12px; padding: 16px; text-align: center;
+  box-shadow: 0 2px 8px rgba(0, 0, 0, 0.06); }
+.stat b { display: block; font-size: 24px; color: #e8844a; }
+h2 { font-size: 18px; margin: 24px 0 12px; }
+table { width: 100%; border-collapse: collapse; background: #fff; border-radius: 12px; overflow: hidden; }
+th, td { padding: 8px 12px; text-align: left; border-bottom: 1px solid #f3e6d8; }
+th { background: #fdebd9; }
+.empty { color: #9a8573; }
+footer { margin-top: 32px; text-align: center; color: #9a8573; }
+"#;
+
+/// 将用户画像渲染为独立的 HTML 报告（内联样式，可直接分享或归档）
+pub fn render_html(period: ReportPeriod, profile: &UserProfile, generated_at: &str) -> String {
+    let days: Vec<&DailySummary> = {
+        let all = &profile.daily_summaries;
+        all.iter()
+            .skip(all.len().saturating_sub(period.days()))
+            .collect()
+    };
+
+    let interactions: u32 = days.iter().map(|d| d.interaction_count).sum();
+    let pomodoros: u32 = days.iter().map(|d| d.pomodoro_count).sum();
+    let mut contexts: BTreeMap<&str, u32> = BTreeMap::new();
+    for day in &days {
+        for (context, minutes) in &day.context_durations {
+            *contexts.entry(context.as_str()).or_default() += minutes;
+        }
+    }
+    let mut contexts: Vec<(&str, u32)> = contexts.into_iter().collect();
+    contexts.sort_by_key(|&(_, minutes)| std::cmp::Reverse(minutes));
+
+    let range = match (days.first(), days.last()) {
+        (Some(first), Some(last)) => {
+            format!("{} ~ {}", escape_html(&first.date), escape_html(&last.date))
+        }
+        _ => "暂无数据".to_string(),
+    };
+
+    let mut html = String::new();
+    html.push_str("<!doctype html>\n<html lang=\"zh-CN\">\n<head>\n<meta charset=\"UTF-8\" />\n");
+    html.push_str(&format!("<title>{}</title>\n", period.title()));
+    html.push_str(&format!(
+        "<style>{REPORT_STYLE}</style>\n</head>\n<body>\n<main>\n"
+    ));
+    html.push_str(&format!("<h1>🐦 {}</h1>\n", period.title()));
+    html.push_str(&format!("<div class=\"range\">{range}</div>\n"));
+
+    html.push_str("<section class=\"stats\">\n");
+    for (value, label) in [
+        (days.len().to_string(), "活跃天数"),
+        (interactions.to_string(), "互动次数"),
+        (pomodoros.to_string(), "完成番茄"),
+        (profile.streak_days.to_string(), "连续天数"),
+    ] {
+        html.push_str(&format!(
+            "<div class=\"stat\"><b>{value}</b>{label}</div>\n"
+        ));
+    }
+    html.push_str("</section>\n");
+
+    html.push_str("<h2>时间都去哪了</h2>\n");
+    if contexts.is_empty() {
+        html.push_str("<p class=\"empty\">还没有记录到行为上下文</p>\n");
+    } else {
+        html.push_str("<table>\n<tr><th>类别</th><th>时长（分钟）</th></tr>\n");
+        for (context, minutes) in &contexts {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{minutes}</td></tr>\n",
+                escape_html(context_label(context))
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<h2>每日明细</h2>\n");
+    if days.is_empty() {
+        html.push_str("<p class=\"empty\">还没有完整的一天记录，明天再来看看吧</p>\n");
+    } else {
+        html.push_str(
+            "<table>\n<tr><th>日期</th><th>活跃时段</th><th>主要在做</th><th>互动</th><th>番茄</th></tr>\n",
+        );
+        for day in &days {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{:02}:00 - {:02}:59</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&day.date),
+                day.active_hours.0,
+                day.active_hours.1,
+                escape_html(context_label(&day.dominant_context)),
+                day.interaction_count,
+                day.pomodoro_count,
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str(&format!(
+        "<footer>生成于 {} · 累计互动 {} 次 · 你的小鸟伙伴 🐦</footer>\n",
+        escape_html(generated_at),
+        profile.total_interactions
+    ));
+    html.push_str("</main>\n</body>\n</html>\n");
+    html
+}
+
+/// 单日文字报告（memo-cli today）：各类活动的分钟数与完成的番茄数
+pub fn render_day_text(day: &str, segments: &[TimelineSegment]) -> String {
+    let mut minutes: BTreeMap<&str, i64> = BTreeMap::new();
+    let mut pomodoros = 0;
+    for segment in segments {
+        match segment.kind {
+            SegmentKind::Focus => pomodoros += 1,
+            SegmentKind::Break => {}
+            _ => {
+                if let Some(category) = &segment.category {
+                    *minutes.entry(category.as_str()).or_default() +=
+                        (segment.end - segment.start) / 60_000;
+                }
+            }
+        }
+    }
+    let mut minutes: Vec<(&str, i64)> = minutes.into_iter().filter(|&(_, m)| m > 0).collect();
+    minutes.sort_by_key(|&(_, m)| std::cmp::Reverse(m));
+
+    let mut text = format!("🐦 {day} 今日报告\n完成番茄：{pomodoros} 个\n");
+    if minutes.is_empty() {
+        text.push_str("还没有记录到行为上下文\n");
+    }
+    for (category, m) in minutes {
+        text.push_str(&format!("{}：{m} 分钟\n", context_label(category)));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        escape_html, render_day_text, render_html, DailySummary, ReportPeriod, UserProfile,
+    };
+    use crate::timeline::{SegmentKind, TimelineSegment};
+
+    fn day(date: &str, interactions: u32, pomodoros: u32) -> DailySummary {
+        DailySummary {
+            date: date.to_string(),
+            active_hours: (9, 18),
+            dominant_context: "coding".to_string(),
+            context_durations: [("coding".to_string(), 60), ("music".to_string(), 15)]
+                .into_iter()
+                .collect(),
+            interaction_count: interactions,
+            pomodoro_count: pomodoros,
+        }
+    }
+
+    #[test]
+    fn escape_html_should_escape_special_characters() {
+        assert_eq!(
+            escape_html(r#"<b>"Tom" & 'Jerry'</b>"#),
+            "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn week_report_should_only_include_last_seven_days() {
+        let profile = UserProfile {
+            total_interactions: 100,
+            streak_days: 3,
+            daily_summaries: (1..=9)
+                .map(|i| day(&format!("2026-03-{i:02}"), 10, 1))
+                .collect(),
+        };
+        let html = render_html(ReportPeriod::Week, &profile, "2026-03-10 09:00");
+
+        assert!(html.contains("2026-03-03 ~ 2026-03-09"));
+        assert!(!html.contains("<td>2026-03-02</td>"));
+        // 7 天 × 10 次互动
+        assert!(html.contains("<b>70</b>互动次数"));
+        // 7 天 × 60 分钟编码
+        assert!(html.contains("<td>编程 💻</td><td>420</td>"));
+    }
+
+    #[test]
+    fn empty_profile_should_render_placeholders() {
+        let html = render_html(ReportPeriod::Week, &UserProfile::default(), "now");
+        assert!(html.contains("暂无数据"));
+        assert!(html.contains("还没有完整的一天记录"));
+    }
+
+    #[test]
+    fn day_text_should_sum_minutes_and_count_pomodoros() {
+        let segment = |kind, start: i64, end: i64, category: Option<&str>| TimelineSegment {
+            kind,
+            start: start * 60_000,
+            end: end * 60_000,
+            category: category.map(str::to_string),
+            app: None,
+        };
+        let text = render_day_text(
+            "2026-03-15",
+            &[
+                segment(SegmentKind::Activity, 0, 30, Some("coding")),
+                segment(SegmentKind::Focus, 5, 30, None),
+                segment(SegmentKind::Untracked, 30, 35, Some("system")),
+                segment(SegmentKind::Activity, 35, 60, Some("coding")),
+            ],
+        );
+        assert_eq!(
+            text,
+            "🐦 2026-03-15 今日报告\n完成番茄：1 个\n编程 💻：55 分钟\n未追踪（系统）：5 分钟\n"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// 应用标识（tauri.conf.json identifier），也是数据目录名
+pub const APP_IDENTIFIER: &str = "com.birdpet.yuqian";
+/// 与前端 StorageService 共用的存储文件
+pub const STORE_FILE: &str = "pet-state.json";
+/// 记忆事件在存储中的 key（前端 STORE_KEYS.MEMORY_EVENTS）
+pub const EVENTS_KEY: &str = "memoryEvents";
+/// 用户画像在存储中的 key（前端 STORE_KEYS.USER_PROFILE）
+pub const PROFILE_KEY: &str = "userProfile";
+/// 用户偏好在存储中的 key（前端 STORE_KEYS.PREFERENCES）
+pub const PREFERENCES_KEY: &str = "preferences";
+
+/// 桌面应用的数据目录（与 Tauri 的 app_data_dir 一致）
+pub fn default_data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_IDENTIFIER))
+}
+
+/// 读取存储文件（只读）；文件不存在时视为空
+pub fn read_store(dir: &Path) -> Result<Map<String, Value>, String> {
+    let path = dir.join(STORE_FILE);
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Map::new()),
+        Err(e) => return Err(format!("读取 {} 失败: {e}", path.display())),
+    };
+    serde_json::from_str(&text).map_err(|e| format!("{} 格式错误: {e}", path.display()))
+}
+
+/// 取出某个 key 并反序列化；缺失或格式不符时返回默认值（与应用内读取一致）
+pub fn get_or_default<T: DeserializeOwned + Default>(store: &Map<String, Value>, key: &str) -> T {
+    store
+        .get(key)
+        .cloned()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_or_default, read_store, STORE_FILE};
+    use serde_json::json;
+
+    #[test]
+    fn missing_store_should_read_as_empty() {
+        let dir = std::env::temp_dir().join(format!("memo-core-store-{}", std::process::id()));
+        assert!(read_store(&dir).unwrap().is_empty());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(STORE_FILE), r#"{"count": 3, "name": "bird"}"#).unwrap();
+        let store = read_store(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(store.get("name"), Some(&json!("bird")));
+        assert_eq!(get_or_default::<u32>(&store, "count"), 3);
+        assert_eq!(get_or_default::<u32>(&store, "name"), 0);
+        assert_eq!(
+            get_or_default::<Vec<u32>>(&store, "missing"),
+            Vec::<u32>::new()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use chrono::{Duration as ChronoDuration, Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// 番茄专注时长（毫秒），与前端 pomodoro.ts 保持一致
+const FOCUS_DURATION_MS: i64 = 25 * 60 * 1000;
+/// 番茄休息时长（毫秒），与前端 pomodoro.ts 保持一致
+const BREAK_DURATION_MS: i64 = 5 * 60 * 1000;
+/// 暂停采集期间的内部上下文标记
+const UNTRACKED: &str = "untracked";
+
+/// 前端记忆系统持久化的原始事件
+#[derive(Debug, Clone, Deserialize)]
+pub struct MemoryEvent {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub timestamp: i64,
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+/// 时间线片段类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentKind {
+    /// 在某类应用中活动
+    Activity,
+    /// 闲置（离开电脑或停留在桌面）
+    Idle,
+    /// 番茄专注时段（叠加在活动片段之上）
+    Focus,
+    /// 番茄休息时段（叠加在活动片段之上）
+    Break,
+    /// 未追踪（UAC 安全桌面、管理员窗口等系统界面），category 固定为 "system"
+    Untracked,
+}
+
+/// 时间线片段（时间戳为毫秒，左闭右开）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimelineSegment {
+    pub kind: SegmentKind,
+    pub start: i64,
+    pub end: i64,
+    /// 行为上下文（coding / browsing / ...），仅活动与闲置片段有
+    pub category: Option<String>,
+    /// 进入该上下文时的前台应用名（旧数据可能缺失）
+    pub app: Option<String>,
+}
+
+fn event_str<'a>(event: &'a MemoryEvent, field: &str) -> Option<&'a str> {
+    event.data.as_ref()?.get(field)?.as_str()
+}
+
+/// 由原始事件拼装某一时间区间内的时间线（按开始时间排序）
+///
+/// - 上下文切换事件划分活动/闲置片段，`unknown` 视为无记录的空档
+/// - 采集暂停到恢复之间为未追踪片段，恢复后回到暂停前的上下文
+/// - 区间开始前的切换事件会把上下文延续到区间开头（是否传入由调用方决定）
+/// - 每个完成的番茄生成一段专注（完成前 25 分钟）和一段休息（完成后 5 分钟）
+pub fn build_timeline(events: &[MemoryEvent], start: i64, end: i64) -> Vec<TimelineSegment> {
+    let clamp = |t: i64| t.clamp(start, end);
+    let mut sorted: Vec<&MemoryEvent> = events.iter().collect();
+    sorted.sort_by_key(|e| e.timestamp);
+
+    let mut segments = Vec::new();
+    let mut current: Option<(i64, String, Option<String>)> = None;
+
+    let mut close = |current: Option<(i64, String, Option<String>)>, at: i64| {
+        if let Some((from, category, app)) = current {
+            let (from, to) = (clamp(from), clamp(at));
+            if to > from && category != "unknown" {
+                let (kind, category) = match category.as_str() {
+                    "idle" => (SegmentKind::Idle, category),
+                    UNTRACKED => (SegmentKind::Untracked, "system".to_string()),
+                    _ => (SegmentKind::Activity, category),
+                };
+                segments.push(TimelineSegment {
+                    kind,
+                    start: from,
+                    end: to,
+                    category: Some(category),
+                    app,
+                });
+            }
+        }
+    };
+
+    // 暂停前的上下文，恢复采集时接着记
+    let mut before_pause: Option<(String, Option<String>)> = None;
+    let tracking_kinds = ["context_switch", "tracking_paused", "tracking_resumed"];
+    for event in sorted
+        .iter()
+        .filter(|e| tracking_kinds.contains(&e.kind.as_str()))
+    {
+        if event.timestamp >= end {
+            break;
+        }
+        let paused = matches!(&current, Some((_, category, _)) if category == UNTRACKED);
+        match event.kind.as_str() {
+            "tracking_paused" => {
+                if paused {
+                    continue;
+                }
+                before_pause = current
+                    .as_ref()
+                    .map(|(_, category, app)| (category.clone(), app.clone()));
+                close(current.take(), event.timestamp);
+                current = Some((event.timestamp, UNTRACKED.to_string(), None));
+            }
+            "tracking_resumed" => {
+                if !paused {
+                    continue;
+                }
+                close(current.take(), event.timestamp);
+                current = before_pause
+                    .take()
+                    .map(|(category, app)| (event.timestamp, category, app));
+            }
+            _ => {
+                let Some(to) = event_str(event, "to") else {
+                    continue;
+                };
+                close(current.take(), event.timestamp);
+                current = Some((
+                    event.timestamp,
+                    to.to_string(),
+                    event_str(event, "app").map(str::to_string),
+                ));
+            }
+        }
+    }
+    close(current.take(), end);
+
+    for event in sorted.iter().filter(|e| e.kind == "pomodoro_complete") {
+        let done = event.timestamp;
+        for (kind, from, to) in [
+            (SegmentKind::Focus, done - FOCUS_DURATION_MS, done),
+            (SegmentKind::Break, done, done + BREAK_DURATION_MS),
+        ] {
+            let (from, to) = (clamp(from), clamp(to));
+            if to > from {
+                segments.push(TimelineSegment {
+                    kind,
+                    start: from,
+                    end: to,
+                    category: None,
+                    app: None,
+                });
+            }
+        }
+    }
+
+    segments.sort_by_key(|s| (s.start, s.end));
+    segments
+}
+
+/// 本地日期（YYYY-MM-DD）对应的毫秒区间 [当日 0 点, 次日 0 点)
+pub fn local_day_range(day: &str) -> Result<(i64, i64), String> {
+    let date = NaiveDate::parse_from_str(day, "%Y-%m-%d").map_err(|e| e.to_string())?;
+    let midnight = |d: NaiveDate| {
+        Local
+            .from_local_datetime(&d.and_hms_opt(0, 0, 0)?)
+            .earliest()
+            .map(|t| t.timestamp_millis())
+    };
+    let start = midnight(date).ok_or("invalid local date")?;
+    let end = midnight(date + ChronoDuration::days(1)).ok_or("invalid local date")?;
+    Ok((start, end))
+}
+
+/// 指定本地日期（YYYY-MM-DD）的时间线，当天的片段截止到 `now`（毫秒）
+///
+/// 只取当天事件，不把前一天最后的上下文延续到今天（应用可能早已退出）。
+pub fn timeline_for_day(
+    events: Vec<MemoryEvent>,
+    day: &str,
+    now: i64,
+) -> Result<Vec<TimelineSegment>, String> {
+    let (start, day_end) = local_day_range(day)?;
+    let end = day_end.min(now);
+    if end <= start {
+        return Ok(Vec::new());
+    }
+    let day_events: Vec<MemoryEvent> = events
+        .into_iter()
+        .filter(|e| e.timestamp >= start && e.timestamp < end)
+        .collect();
+    Ok(build_timeline(&day_events, start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_timeline, local_day_range, MemoryEvent, SegmentKind};
+    use serde_json::json;
+
+    const MIN: i64 = 60 * 1000;
+
+    fn switch(at: i64, to: &str, app: Option<&str>) -> MemoryEvent {
+        let data = match app {
+            Some(app) => json!({ "from": "unknown", "to": to, "app": app }),
+            None => json!({ "from": "unknown", "to": to }),
+        };
+        MemoryEvent {
+            kind: "context_switch".into(),
+            timestamp: at,
+            data: Some(data),
+        }
+    }
+
+    fn event(kind: &str, at: i64) -> MemoryEvent {
+        MemoryEvent {
+            kind: kind.into(),
+            timestamp: at,
+            data: None,
+        }
+    }
+
+    #[test]
+    fn context_switches_should_form_contiguous_segments() {
+        let events = vec![
+            switch(10 * MIN, "browsing", Some("chrome")),
+            switch(0, "coding", Some("Code")),
+            switch(30 * MIN, "idle", None),
+        ];
+        let timeline = build_timeline(&events, 0, 60 * MIN);
+
+        assert_eq!(timeline.len(), 3);
+        assert_eq!(timeline[0].kind, SegmentKind::Activity);
+        assert_eq!(timeline[0].app.as_deref(), Some("Code"));
+        assert_eq!((timeline[0].start, timeline[0].end), (0, 10 * MIN));
+        assert_eq!(timeline[1].category.as_deref(), Some("browsing"));
+        assert_eq!(timeline[2].kind, SegmentKind::Idle);
+        assert_eq!((timeline[2].start, timeline[2].end), (30 * MIN, 60 * MIN));
+    }
+
+    #[test]
+    fn unknown_context_should_leave_a_gap() {
+        let events = vec![
+            switch(0, "coding", None),
+            switch(5 * MIN, "unknown", None),
+            switch(20 * MIN, "coding", None),
+        ];
+        let timeline = build_timeline(&events, 0, 30 * MIN);
+
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].end, 5 * MIN);
+        assert_eq!(timeline[1].start, 20 * MIN);
+    }
+
+    #[test]
+    fn tracking_pause_should_form_untracked_segment_and_resume_context() {
+        let events = vec![
+            switch(0, "coding", Some("Code")),
+            event("tracking_paused", 10 * MIN),
+            event("tracking_paused", 11 * MIN),
+            event("tracking_resumed", 12 * MIN),
+        ];
+        let timeline = build_timeline(&events, 0, 20 * MIN);
+
+        assert_eq!(timeline.len(), 3);
+        assert_eq!(timeline[1].kind, SegmentKind::Untracked);
+        assert_eq!(timeline[1].category.as_deref(), Some("system"));
+        assert_eq!((timeline[1].start, timeline[1].end), (10 * MIN, 12 * MIN));
+        assert_eq!(timeline[2].app.as_deref(), Some("Code"));
+        assert_eq!((timeline[2].start, timeline[2].end), (12 * MIN, 20 * MIN));
+    }
+
+    #[test]
+    fn pomodoro_should_produce_focus_and_break_clamped_to_range() {
+        let events = vec![
+            event("pomodoro_complete", 20 * MIN),
+            event("interaction", MIN),
+        ];
+        let timeline = build_timeline(&events, 0, 22 * MIN);
+
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].kind, SegmentKind::Focus);
+        assert_eq!((timeline[0].start, timeline[0].end), (0, 20 * MIN));
+        assert_eq!(timeline[1].kind, SegmentKind::Break);
+        assert_eq!((timeline[1].start, timeline[1].end), (20 * MIN, 22 * MIN));
+    }
+
+    #[test]
+    fn local_day_range_should_reject_invalid_dates() {
+        assert!(local_day_range("2026-02-30").is_err());
+        assert!(local_day_range("yesterday").is_err());
+        let (start, end) = local_day_range("2026-03-01").unwrap();
+        assert!(end > start);
+    }
+}
@@ -1,9 +1,26 @@
+use crate::backup::backup_data;
+use crate::cli_inbox::take_cli_memories;
+use crate::consent::{self, get_consents, grant_consent, revoke_consent, ConsentKind};
+use crate::daylight::get_daylight;
+use crate::disk_guard::{get_disk_status, DiskGuard};
+use crate::git_watch::find_invalid_repos;
+use crate::nest::{get_nest_settings, set_nest_visible};
+use crate::operations::{cancel_operation, Operations};
+use crate::presence::{publish_status_discord, publish_status_mqtt, DiscordPresence};
+use crate::report::{render_recap, render_report};
+use crate::routing;
+use crate::secure_desktop::{self, UntrackedReason};
+use crate::session::{get_session_status, SessionState};
 use crate::shutdown_state::ShutdownState;
+use crate::supervisor::{get_subsystem_health, Supervisor};
+use crate::timeline::get_timeline;
+use crate::user_status::{get_user_status, publish_user_status, UserStatusState};
+use crate::window_tween::{animate_window_to, stop_window_animation, WindowTweens};
 use active_win_pos_rs::get_active_window;
 use serde::Serialize;
 use std::sync::{Arc, Mutex};
 use sysinfo::System;
-use tauri::State;
+use tauri::{AppHandle, Runtime, State};
 use tauri_plugin_autostart::MacosLauncher;
 
 /// 系统资源统计信息
@@ -56,19 +73,84 @@ struct ActiveWindowInfo {
     app_name: String,
     /// 窗口标题
     title: String,
+    /// 处于安全桌面 / 管理员窗口等无法追踪的系统界面时给出原因，此时应用名与标题为空
+    untracked: Option<UntrackedReason>,
 }
 
+/// 窗口标题需要用户授权（ConsentKind::ActivityTitles），未授权时只返回应用名
+///
+/// UAC 提示、锁屏与管理员窗口下系统 API 读不到前台信息，先行识别并返回 untracked，
+/// 前端据此暂停采集，而不是把读取失败当成「没有活动」。
 #[tauri::command]
-fn get_active_window_info() -> Option<ActiveWindowInfo> {
+fn get_active_window_info<R: Runtime>(app: AppHandle<R>) -> Option<ActiveWindowInfo> {
+    if let Some(reason) = secure_desktop::untracked_reason() {
+        return Some(ActiveWindowInfo {
+            app_name: String::new(),
+            title: String::new(),
+            untracked: Some(reason),
+        });
+    }
+    let include_title = consent::is_granted(&app, ConsentKind::ActivityTitles);
     match get_active_window() {
         Ok(win) => Some(ActiveWindowInfo {
             app_name: win.app_name,
-            title: win.title,
+            title: if include_title { win.title } else { String::new() },
+            untracked: None,
         }),
         Err(_) => None,
     }
 }
 
+/// 显示器信息
+#[derive(Debug, Serialize)]
+struct MonitorInfo {
+    /// 显示器名称（部分平台取不到）
+    name: Option<String>,
+    /// 物理分辨率宽
+    width: u32,
+    /// 物理分辨率高
+    height: u32,
+}
+
+/// 本机信息（自动回忆用于识别新电脑、系统升级、新显示器）
+#[derive(Debug, Serialize)]
+struct MachineInfo {
+    /// 主机名
+    host_name: Option<String>,
+    /// 操作系统名称，如 "Windows"
+    os_name: Option<String>,
+    /// 操作系统版本，如 "11 (22631)"
+    os_version: Option<String>,
+    /// 当前连接的显示器
+    monitors: Vec<MonitorInfo>,
+}
+
+#[tauri::command]
+fn get_machine_info<R: Runtime>(app: AppHandle<R>) -> MachineInfo {
+    let monitors = app
+        .available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| MonitorInfo {
+            name: m.name().cloned(),
+            width: m.size().width,
+            height: m.size().height,
+        })
+        .collect();
+    MachineInfo {
+        host_name: System::host_name(),
+        os_name: System::name(),
+        os_version: System::os_version(),
+        monitors,
+    }
+}
+
+/// 开发者模式：debug 构建或设置了 BIRD_PET_DEV 环境变量时开启（事件检查器等调试工具）
+#[tauri::command]
+fn is_dev_mode() -> bool {
+    cfg!(debug_assertions) || std::env::var_os("BIRD_PET_DEV").is_some()
+}
+
 pub fn configure_builder<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::Builder<R> {
     // 初始化系统监控（做一次基线刷新以便后续 CPU 读数准确）
     let mut sys = System::new();
@@ -87,7 +169,41 @@ pub fn configure_builder<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri
             system: Mutex::new(sys),
         })
         .manage(Arc::new(ShutdownState::default()))
-        .invoke_handler(tauri::generate_handler![get_system_stats, get_active_window_info]);
+        .manage(UserStatusState::default())
+        .manage(Arc::new(WindowTweens::default()))
+        .manage(Arc::new(DiskGuard::default()))
+        .manage(Arc::new(Operations::default()))
+        .manage(Arc::new(SessionState::default()))
+        .manage(Arc::new(Supervisor::default()))
+        .manage(Arc::new(DiscordPresence::default()))
+        .invoke_handler(routing::guard_invoke(tauri::generate_handler![
+            get_system_stats,
+            get_active_window_info,
+            publish_user_status,
+            get_user_status,
+            publish_status_mqtt,
+            publish_status_discord,
+            animate_window_to,
+            stop_window_animation,
+            set_nest_visible,
+            get_nest_settings,
+            get_timeline,
+            render_report,
+            render_recap,
+            get_consents,
+            grant_consent,
+            revoke_consent,
+            is_dev_mode,
+            get_daylight,
+            get_disk_status,
+            get_session_status,
+            find_invalid_repos,
+            get_subsystem_health,
+            cancel_operation,
+            get_machine_info,
+            take_cli_memories,
+            backup_data
+        ]));
 
     #[cfg(not(any(target_os = "android", target_os = "ios")))]
     let builder = builder.plugin(tauri_plugin_global_shortcut::Builder::new().build());
@@ -0,0 +1,42 @@
+use crate::disk_guard::DiskGuard;
+use crate::operations::Operation;
+use chrono::Local;
+use memo_core::backup;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, Runtime};
+
+/// 备份数据目录中的存储文件并清理旧备份（保留份数见 memo_core::backup），返回备份路径（仅主窗口）
+///
+/// 由前端任务队列的 backup 任务调用：失败（磁盘不足、存储正在写入等）时
+/// 返回错误，由队列退避重试。调用前前端应先把内存中的修改落盘。
+/// 备份过程通过 `op:progress` 上报进度，可用 `cancel_operation(op_id)` 取消。
+#[tauri::command]
+pub async fn backup_data<R: Runtime>(
+    app: AppHandle<R>,
+    op_id: Option<String>,
+) -> Result<String, String> {
+    if app.state::<Arc<DiskGuard>>().is_low() {
+        return Err("磁盘空间不足，稍后再备份".to_string());
+    }
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    let stamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let mut op = Operation::begin(&app, op_id, "backup", 1)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = write_backup(&mut op, &dir, &stamp);
+        op.finish(result)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+fn write_backup<R: Runtime>(
+    op: &mut Operation<R>,
+    dir: &Path,
+    stamp: &str,
+) -> Result<String, String> {
+    op.check()?;
+    let path = backup::backup_and_prune(dir, stamp)?;
+    op.step("复制存储文件并清理旧备份")?;
+    Ok(path.to_string_lossy().into_owned())
+}
@@ -0,0 +1,11 @@
+use memo_core::inbox::{self, InboxMemory};
+use tauri::{AppHandle, Manager, Runtime};
+
+/// 取出 memo-cli 记下的回忆并清空收件箱，由前端并入回忆列表
+///
+/// 与 CLI 共用数据目录下的文件锁，CLI 正在写入时会短暂等待。
+#[tauri::command]
+pub fn take_cli_memories<R: Runtime>(app: AppHandle<R>) -> Result<Vec<InboxMemory>, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    inbox::take(&dir)
+}
@@ -0,0 +1,219 @@
+use memo_core::store::STORE_FILE;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+/// 授权记录在存储中的 key（前端 STORE_KEYS.CONSENTS）
+const STORE_KEY: &str = "consents";
+
+/// 需要用户授权的数据采集类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentKind {
+    /// 前台窗口标题
+    ActivityTitles,
+    /// 剪贴板内容
+    Clipboard,
+    /// 麦克风音量
+    MicrophoneLevel,
+    /// 匿名使用统计
+    Telemetry,
+}
+
+impl ConsentKind {
+    pub const ALL: [ConsentKind; 4] = [
+        ConsentKind::ActivityTitles,
+        ConsentKind::Clipboard,
+        ConsentKind::MicrophoneLevel,
+        ConsentKind::Telemetry,
+    ];
+
+    /// 当前授权说明的版本；说明文字有实质变化时递增，旧版本的授权自动失效
+    pub fn text_version(self) -> u32 {
+        match self {
+            ConsentKind::ActivityTitles => 1,
+            ConsentKind::Clipboard => 1,
+            ConsentKind::MicrophoneLevel => 1,
+            ConsentKind::Telemetry => 1,
+        }
+    }
+
+    /// 展示给用户的授权说明
+    pub fn text(self) -> &'static str {
+        match self {
+            ConsentKind::ActivityTitles => {
+                "允许小鸟读取前台窗口的标题，用于更准确地判断你在做什么。标题只在本机使用，不会上传。"
+            }
+            ConsentKind::Clipboard => "允许小鸟读取剪贴板内容，用于快速记录。内容只保存在本机。",
+            ConsentKind::MicrophoneLevel => {
+                "允许小鸟读取麦克风音量（不录音、不保存声音），用于判断是否在通话。"
+            }
+            ConsentKind::Telemetry => "允许发送匿名的功能使用统计，帮助改进小鸟。不包含任何个人内容。",
+        }
+    }
+}
+
+/// 单项授权记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsentRecord {
+    pub granted: bool,
+    /// 用户同意/撤回时看到的说明版本
+    pub text_version: u32,
+    /// 同意/撤回时间（毫秒）
+    pub updated_at: u64,
+}
+
+/// 返回给前端的授权状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsentStatus {
+    pub kind: ConsentKind,
+    /// 是否对当前版本的说明有效授权
+    pub granted: bool,
+    pub text: &'static str,
+    pub text_version: u32,
+    /// 最近一次操作记录（从未操作过为 None）
+    pub record: Option<ConsentRecord>,
+}
+
+type ConsentMap = BTreeMap<ConsentKind, ConsentRecord>;
+
+/// 授权是否对当前说明版本有效
+pub fn is_effective(kind: ConsentKind, record: Option<&ConsentRecord>) -> bool {
+    record.is_some_and(|r| r.granted && r.text_version >= kind.text_version())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn load<R: Runtime>(app: &AppHandle<R>) -> ConsentMap {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn update<R: Runtime>(
+    app: &AppHandle<R>,
+    kind: ConsentKind,
+    record: ConsentRecord,
+) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let mut consents = load(app);
+    consents.insert(kind, record);
+    let value = serde_json::to_value(&consents).map_err(|e| e.to_string())?;
+    store.set(STORE_KEY, value);
+    // 授权变更立即落盘，不依赖前端的自动保存
+    store.save().map_err(|e| e.to_string())
+}
+
+/// 采集方在每次采集前调用：当前是否允许采集该类数据
+pub fn is_granted<R: Runtime>(app: &AppHandle<R>, kind: ConsentKind) -> bool {
+    is_effective(kind, load(app).get(&kind))
+}
+
+#[tauri::command]
+pub fn get_consents<R: Runtime>(app: AppHandle<R>) -> Vec<ConsentStatus> {
+    let consents = load(&app);
+    ConsentKind::ALL
+        .iter()
+        .map(|&kind| {
+            let record = consents.get(&kind).cloned();
+            ConsentStatus {
+                kind,
+                granted: is_effective(kind, record.as_ref()),
+                text: kind.text(),
+                text_version: kind.text_version(),
+                record,
+            }
+        })
+        .collect()
+}
+
+/// 同意某项采集；text_version 为用户实际看到的说明版本
+#[tauri::command]
+pub fn grant_consent<R: Runtime>(
+    app: AppHandle<R>,
+    kind: ConsentKind,
+    text_version: u32,
+) -> Result<(), String> {
+    if text_version != kind.text_version() {
+        return Err("授权说明已更新，请重新阅读后再同意".to_string());
+    }
+    update(
+        &app,
+        kind,
+        ConsentRecord {
+            granted: true,
+            text_version,
+            updated_at: now_ms(),
+        },
+    )
+}
+
+/// 撤回某项采集授权；采集方在下一次采集前就会看到撤回结果
+#[tauri::command]
+pub fn revoke_consent<R: Runtime>(app: AppHandle<R>, kind: ConsentKind) -> Result<(), String> {
+    update(
+        &app,
+        kind,
+        ConsentRecord {
+            granted: false,
+            text_version: kind.text_version(),
+            updated_at: now_ms(),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_effective, ConsentKind, ConsentRecord};
+
+    fn record(granted: bool, text_version: u32) -> ConsentRecord {
+        ConsentRecord {
+            granted,
+            text_version,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn missing_record_should_not_grant() {
+        assert!(!is_effective(ConsentKind::ActivityTitles, None));
+    }
+
+    #[test]
+    fn revoked_record_should_not_grant() {
+        let kind = ConsentKind::Clipboard;
+        assert!(is_effective(kind, Some(&record(true, kind.text_version()))));
+        assert!(!is_effective(
+            kind,
+            Some(&record(false, kind.text_version()))
+        ));
+    }
+
+    #[test]
+    fn outdated_text_version_should_not_grant() {
+        let kind = ConsentKind::Telemetry;
+        assert!(!is_effective(
+            kind,
+            Some(&record(true, kind.text_version() - 1))
+        ));
+    }
+
+    #[test]
+    fn kind_should_serialize_as_snake_case_map_key() {
+        let map =
+            std::collections::BTreeMap::from([(ConsentKind::MicrophoneLevel, record(true, 1))]);
+        let json = serde_json::to_value(&map).unwrap();
+        assert!(json.get("microphone_level").is_some());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{routing, supervisor};
+use chrono::{Local, NaiveDate, Utc};
+use memo_core::store::{PREFERENCES_KEY, STORE_FILE};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::time::Duration;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+/// 日出/日落前后各多少毫秒算作晨昏
+const TWILIGHT_MS: i64 = 30 * 60 * 1000;
+/// 后台检查相位的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// Unix 纪元对应的儒略日
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+/// J2000.0 对应的儒略日
+const J2000: f64 = 2_451_545.0;
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// 用户配置的位置（前端 UserPreferences.location）
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Location {
+    /// 纬度，北正南负
+    pub latitude: f64,
+    /// 经度，东正西负
+    pub longitude: f64,
+}
+
+/// 某一天的日出日落
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SunTimes {
+    /// 日出、日落时间（毫秒时间戳）
+    Normal { sunrise: i64, sunset: i64 },
+    /// 极昼：太阳整天不落
+    PolarDay,
+    /// 极夜：太阳整天不升
+    PolarNight,
+}
+
+/// 昼夜相位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DaylightPhase {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+/// 推送给前端的昼夜信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaylightInfo {
+    pub phase: DaylightPhase,
+    /// 今日日出（毫秒时间戳），极昼/极夜为 None
+    pub sunrise: Option<i64>,
+    /// 今日日落（毫秒时间戳），极昼/极夜为 None
+    pub sunset: Option<i64>,
+}
+
+fn sin_deg(deg: f64) -> f64 {
+    (deg * PI / 180.0).sin()
+}
+
+fn cos_deg(deg: f64) -> f64 {
+    (deg * PI / 180.0).cos()
+}
+
+fn jd_to_ms(jd: f64) -> i64 {
+    ((jd - UNIX_EPOCH_JD) * MS_PER_DAY).round() as i64
+}
+
+/// 按日出方程计算某天的日出日落（精度约 1-2 分钟）
+pub fn sun_times(date: NaiveDate, location: Location) -> SunTimes {
+    let unix_epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let days_since_epoch = (date - unix_epoch).num_days() as f64;
+    // 当天 UTC 正午距 J2000.0 的天数
+    let n = (days_since_epoch + UNIX_EPOCH_JD + 0.5 - J2000).round();
+    let mean_solar_noon = n - location.longitude / 360.0;
+
+    let anomaly = (357.5291 + 0.985_600_28 * mean_solar_noon).rem_euclid(360.0);
+    let center =
+        1.9148 * sin_deg(anomaly) + 0.02 * sin_deg(2.0 * anomaly) + 0.0003 * sin_deg(3.0 * anomaly);
+    let ecliptic_longitude = (anomaly + center + 180.0 + 102.9372).rem_euclid(360.0);
+    let transit = J2000 + mean_solar_noon + 0.0053 * sin_deg(anomaly)
+        - 0.0069 * sin_deg(2.0 * ecliptic_longitude);
+
+    let sin_declination = sin_deg(ecliptic_longitude) * sin_deg(23.4397);
+    let cos_declination = (1.0 - sin_declination * sin_declination).sqrt();
+    let cos_hour_angle = (sin_deg(-0.833) - sin_deg(location.latitude) * sin_declination)
+        / (cos_deg(location.latitude) * cos_declination);
+
+    if cos_hour_angle < -1.0 {
+        return SunTimes::PolarDay;
+    }
+    if cos_hour_angle > 1.0 {
+        return SunTimes::PolarNight;
+    }
+    let hour_angle = cos_hour_angle.acos() * 180.0 / PI;
+    SunTimes::Normal {
+        sunrise: jd_to_ms(transit - hour_angle / 360.0),
+        sunset: jd_to_ms(transit + hour_angle / 360.0),
+    }
+}
+
+/// 判断某一时刻所处的昼夜相位
+pub fn phase_at(now: i64, times: SunTimes) -> DaylightPhase {
+    match times {
+        SunTimes::PolarDay => DaylightPhase::Day,
+        SunTimes::PolarNight => DaylightPhase::Night,
+        SunTimes::Normal { sunrise, sunset } => {
+            if now < sunrise - TWILIGHT_MS || now >= sunset + TWILIGHT_MS {
+                DaylightPhase::Night
+            } else if now < sunrise + TWILIGHT_MS {
+                DaylightPhase::Dawn
+            } else if now < sunset - TWILIGHT_MS {
+                DaylightPhase::Day
+            } else {
+                DaylightPhase::Dusk
+            }
+        }
+    }
+}
+
+fn load_location<R: Runtime>(app: &AppHandle<R>) -> Option<Location> {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(PREFERENCES_KEY))
+        .and_then(|prefs| prefs.get("location").cloned())
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// 当前的昼夜信息；未配置位置时返回 None
+fn current_info<R: Runtime>(app: &AppHandle<R>) -> Option<DaylightInfo> {
+    let location = load_location(app)?;
+    let times = sun_times(Local::now().date_naive(), location);
+    let (sunrise, sunset) = match times {
+        SunTimes::Normal { sunrise, sunset } => (Some(sunrise), Some(sunset)),
+        _ => (None, None),
+    };
+    Some(DaylightInfo {
+        phase: phase_at(Utc::now().timestamp_millis(), times),
+        sunrise,
+        sunset,
+    })
+}
+
+#[tauri::command]
+pub fn get_daylight<R: Runtime>(app: AppHandle<R>) -> Option<DaylightInfo> {
+    current_info(&app)
+}
+
+/// 启动后台线程：每分钟检查一次相位，变化时向主窗口推送 `daylight:phase-changed`
+pub fn spawn_watcher<R: Runtime>(app: AppHandle<R>) {
+    let handle = app.clone();
+    supervisor::spawn(&app, "daylight", CHECK_INTERVAL, move || {
+        let app = handle.clone();
+        let mut last_phase = None;
+        move || {
+            let info = current_info(&app);
+            let phase = info.as_ref().map(|i| i.phase);
+            if phase != last_phase {
+                if let Some(info) = info {
+                    let _ = routing::emit_routed(&app, "daylight:phase-changed", info);
+                }
+                last_phase = phase;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{phase_at, sun_times, DaylightPhase, Location, SunTimes};
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    const BEIJING: Location = Location {
+        latitude: 39.9042,
+        longitude: 116.4074,
+    };
+
+    fn utc_ms(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0)
+            .unwrap()
+            .timestamp_millis()
+    }
+
+    #[test]
+    fn beijing_summer_solstice_should_match_almanac() {
+        // 北京 2024-06-21 日出约 04:46、日落约 19:46（UTC+8）
+        let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let SunTimes::Normal { sunrise, sunset } = sun_times(date, BEIJING) else {
+            panic!("expected normal day");
+        };
+        let tolerance = 5 * 60 * 1000;
+        assert!((sunrise - utc_ms(2024, 6, 20, 20, 46)).abs() < tolerance);
+        assert!((sunset - utc_ms(2024, 6, 21, 11, 46)).abs() < tolerance);
+    }
+
+    #[test]
+    fn polar_regions_should_report_polar_day_and_night() {
+        let svalbard = Location {
+            latitude: 78.2232,
+            longitude: 15.6267,
+        };
+        let june = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let december = NaiveDate::from_ymd_opt(2024, 12, 21).unwrap();
+        assert_eq!(sun_times(june, svalbard), SunTimes::PolarDay);
+        assert_eq!(sun_times(december, svalbard), SunTimes::PolarNight);
+    }
+
+    #[test]
+    fn phase_should_follow_twilight_windows() {
+        let hour = 60 * 60 * 1000;
+        let times = SunTimes::Normal {
+            sunrise: 6 * hour,
+            sunset: 18 * hour,
+        };
+        assert_eq!(phase_at(5 * hour, times), DaylightPhase::Night);
+        assert_eq!(phase_at(6 * hour, times), DaylightPhase::Dawn);
+        assert_eq!(phase_at(12 * hour, times), DaylightPhase::Day);
+        assert_eq!(phase_at(18 * hour, times), DaylightPhase::Dusk);
+        assert_eq!(phase_at(19 * hour, times), DaylightPhase::Night);
+        assert_eq!(phase_at(0, SunTimes::PolarDay), DaylightPhase::Day);
+    }
+}
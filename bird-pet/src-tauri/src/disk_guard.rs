@@ -0,0 +1,136 @@
+use crate::{routing, supervisor};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use sysinfo::Disks;
+use tauri::{AppHandle, Manager, Runtime, State};
+
+/// 剩余空间低于此值进入低磁盘模式（1 GiB）
+pub const LOW_SPACE_BYTES: u64 = 1024 * 1024 * 1024;
+/// 剩余空间回升到此值以上才退出低磁盘模式，避免在阈值附近反复切换（1.5 GiB）
+pub const RECOVER_SPACE_BYTES: u64 = LOW_SPACE_BYTES + LOW_SPACE_BYTES / 2;
+/// 后台检查间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 低磁盘状态（所有写入方共享）
+#[derive(Default)]
+pub struct DiskGuard {
+    low: AtomicBool,
+}
+
+impl DiskGuard {
+    /// 当前是否处于低磁盘模式；写入方在写入非必要数据前调用
+    pub fn is_low(&self) -> bool {
+        self.low.load(Ordering::Relaxed)
+    }
+
+    /// 按最新剩余空间更新状态，返回是否发生变化
+    fn update(&self, free_bytes: u64) -> bool {
+        let was_low = self.is_low();
+        let low = next_low_state(was_low, free_bytes);
+        self.low.store(low, Ordering::Relaxed);
+        low != was_low
+    }
+}
+
+/// 返回给前端 / 随事件推送的磁盘状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskStatus {
+    /// 数据目录所在磁盘的剩余空间；无法识别磁盘时为 None
+    pub free_bytes: Option<u64>,
+    pub threshold_bytes: u64,
+    pub low: bool,
+}
+
+/// 带回差的低磁盘判定
+pub fn next_low_state(was_low: bool, free_bytes: u64) -> bool {
+    if was_low {
+        free_bytes < RECOVER_SPACE_BYTES
+    } else {
+        free_bytes < LOW_SPACE_BYTES
+    }
+}
+
+/// 在磁盘列表（挂载点, 剩余空间）中找到 path 所在磁盘（最长挂载点前缀）的剩余空间
+pub fn free_space_for(path: &Path, disks: &[(PathBuf, u64)]) -> Option<u64> {
+    disks
+        .iter()
+        .filter(|(mount, _)| path.starts_with(mount))
+        .max_by_key(|(mount, _)| mount.as_os_str().len())
+        .map(|&(_, free)| free)
+}
+
+fn check<R: Runtime>(app: &AppHandle<R>, guard: &DiskGuard) -> (DiskStatus, bool) {
+    let free_bytes = app.path().app_data_dir().ok().and_then(|dir| {
+        let disks: Vec<(PathBuf, u64)> = Disks::new_with_refreshed_list()
+            .iter()
+            .map(|d| (d.mount_point().to_path_buf(), d.available_space()))
+            .collect();
+        free_space_for(&dir, &disks)
+    });
+    // 识别不到磁盘时保持原状态
+    let changed = free_bytes.is_some_and(|free| guard.update(free));
+    let status = DiskStatus {
+        free_bytes,
+        threshold_bytes: LOW_SPACE_BYTES,
+        low: guard.is_low(),
+    };
+    (status, changed)
+}
+
+#[tauri::command]
+pub fn get_disk_status<R: Runtime>(
+    app: AppHandle<R>,
+    guard: State<'_, Arc<DiskGuard>>,
+) -> DiskStatus {
+    check(&app, &guard).0
+}
+
+/// 启动后台线程：定期检查剩余空间，进入/退出低磁盘模式时向主窗口推送 `disk:space-changed`
+pub fn spawn_watcher<R: Runtime>(app: AppHandle<R>) {
+    let guard = app.state::<Arc<DiskGuard>>().inner().clone();
+    let handle = app.clone();
+    supervisor::spawn(&app, "disk_guard", CHECK_INTERVAL, move || {
+        let app = handle.clone();
+        let guard = Arc::clone(&guard);
+        move || {
+            let (status, changed) = check(&app, &guard);
+            if changed {
+                let _ = routing::emit_routed(&app, "disk:space-changed", status);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{free_space_for, next_low_state, LOW_SPACE_BYTES, RECOVER_SPACE_BYTES};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn low_state_should_have_hysteresis() {
+        assert!(next_low_state(false, LOW_SPACE_BYTES - 1));
+        assert!(!next_low_state(false, LOW_SPACE_BYTES));
+        // 已进入低磁盘模式时，回升到阈值以上但未达恢复线仍保持
+        assert!(next_low_state(true, LOW_SPACE_BYTES + 1));
+        assert!(!next_low_state(true, RECOVER_SPACE_BYTES));
+    }
+
+    #[test]
+    fn free_space_should_use_longest_mount_prefix() {
+        let disks = vec![
+            (PathBuf::from("/"), 100),
+            (PathBuf::from("/home"), 5),
+            (PathBuf::from("/home2"), 7),
+        ];
+        assert_eq!(
+            free_space_for(Path::new("/home/me/.local/share/app"), &disks),
+            Some(5)
+        );
+        assert_eq!(free_space_for(Path::new("/var/lib"), &disks), Some(100));
+        assert_eq!(free_space_for(Path::new("relative"), &disks), None);
+    }
+}
@@ -0,0 +1,382 @@
+use crate::session::SessionState;
+use crate::{routing, supervisor};
+use memo_core::store::{PREFERENCES_KEY, STORE_FILE};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_store::StoreExt;
+
+/// 检查仓库 reflog 的间隔
+///
+/// 用轮询而不是文件系统事件：任何 git 操作都会频繁改写 .git 下的 index、objects 等文件，
+/// 监听整个目录会被无关事件淹没；只监听 reflog 又要为新出现的远程分支目录逐个补注册，
+/// 网络盘与 WSL 路径上还收不到事件。每轮只对各 reflog 做一次 stat，长度不变就不读内容，
+/// 3 秒的间隔下开销可以忽略，也能和其他后台线程一样经 supervisor 统计 CPU、按会话暂停。
+const CHECK_INTERVAL: Duration = Duration::from_secs(3);
+
+/// 版本控制动态类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitActivityKind {
+    Commit,
+    Push,
+}
+
+/// 推送给前端的版本控制动态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitActivity {
+    pub kind: GitActivityKind,
+    /// 仓库目录名
+    pub repo: String,
+    /// 推送到的远程分支（如 origin/main），提交时为 None
+    pub branch: Option<String>,
+    /// 提交说明首行；推送时取本地 HEAD reflog 中该提交的说明，找不到时为 None
+    pub summary: Option<String>,
+}
+
+/// 前端 UserPreferences 中的相关字段
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct GitWatchPrefs {
+    git_watch_enabled: bool,
+    git_watch_repos: Vec<String>,
+}
+
+/// 解析一行 reflog：`<旧 sha> <新 sha> <作者> <时间> <时区>\t<说明>`，返回 (新 sha, 说明)
+pub fn parse_reflog_line(line: &str) -> Option<(&str, &str)> {
+    let (head, message) = line.split_once('\t')?;
+    let new_sha = head.split(' ').nth(1)?;
+    Some((new_sha, message))
+}
+
+/// 提交产生的 reflog 说明（commit / commit (amend) / commit (merge) / commit (initial)）中的提交说明
+pub fn commit_summary(message: &str) -> Option<&str> {
+    let (action, summary) = message.split_once(": ")?;
+    action.starts_with("commit").then_some(summary.trim())
+}
+
+/// 远程跟踪分支的 reflog 行是否由推送产生
+pub fn is_push(message: &str) -> bool {
+    message.starts_with("update by push")
+}
+
+/// 在 HEAD reflog 中查找最后一次产生该提交的说明
+pub fn summary_for(head_log: &str, sha: &str) -> Option<String> {
+    head_log
+        .lines()
+        .rev()
+        .filter_map(parse_reflog_line)
+        .filter(|&(new_sha, _)| new_sha == sha)
+        .find_map(|(_, message)| commit_summary(message))
+        .map(str::to_string)
+}
+
+/// 记录各 reflog 文件已读到的位置，只解析新增的完整行
+#[derive(Default)]
+struct LogTail {
+    offsets: HashMap<PathBuf, u64>,
+}
+
+impl LogTail {
+    /// 读取新增的完整行
+    ///
+    /// `baseline` 为 true 时首次见到的文件只记录当前位置（不庆祝历史提交）；
+    /// 之后才出现的文件（如第一次推送新分支）从头读取。
+    fn read_new(&mut self, path: &Path, baseline: bool) -> Vec<String> {
+        let Ok(len) = fs::metadata(path).map(|m| m.len()) else {
+            return Vec::new();
+        };
+        let offset = *self
+            .offsets
+            .entry(path.to_path_buf())
+            .or_insert(if baseline { len } else { 0 });
+        if len <= offset {
+            // reflog 被 expire 重写时从新的末尾继续
+            self.offsets.insert(path.to_path_buf(), len);
+            return Vec::new();
+        }
+
+        let mut bytes = Vec::new();
+        let read = File::open(path).and_then(|mut file| {
+            file.seek(SeekFrom::Start(offset))?;
+            file.take(len - offset).read_to_end(&mut bytes)
+        });
+        if read.is_err() {
+            return Vec::new();
+        }
+        // 只消费到最后一个换行，写了一半的行留到下次
+        let Some(end) = bytes.iter().rposition(|&b| b == b'\n') else {
+            return Vec::new();
+        };
+        self.offsets
+            .insert(path.to_path_buf(), offset + end as u64 + 1);
+        String::from_utf8_lossy(&bytes[..end])
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// 目录下的所有文件（远程分支名可能带斜杠，需要递归）
+fn files_under(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            files.extend(files_under(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// 仓库的 git 目录
+///
+/// 普通仓库的 `.git` 是目录；工作树和子模块的 `.git` 是内容为 `gitdir: <路径>` 的文件，
+/// 路径可能相对于仓库目录。都不是时返回 None。
+pub fn resolve_git_dir(repo: &Path) -> Option<PathBuf> {
+    let dot_git = repo.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    let text = fs::read_to_string(&dot_git).ok()?;
+    let target = text.lines().find_map(|line| line.strip_prefix("gitdir:"))?;
+    Some(repo.join(target.trim()))
+}
+
+/// 存放远程跟踪分支 reflog 的共享目录
+///
+/// 工作树的 git 目录里有 `commondir` 文件指向主仓库的 `.git`（通常是相对路径），
+/// 远程分支的 reflog 在那里；其余情况就是 git 目录本身。
+pub fn resolve_common_dir(git_dir: &Path) -> PathBuf {
+    match fs::read_to_string(git_dir.join("commondir")) {
+        Ok(text) if !text.trim().is_empty() => git_dir.join(text.trim()),
+        _ => git_dir.to_path_buf(),
+    }
+}
+
+/// 单个仓库的监视状态
+struct RepoWatch {
+    name: String,
+    /// HEAD reflog 所在目录（工作树各自独立）
+    git_dir: PathBuf,
+    /// 远程跟踪分支 reflog 所在目录
+    common_dir: PathBuf,
+    tail: LogTail,
+    baselined: bool,
+}
+
+impl RepoWatch {
+    fn new(repo: &Path) -> Self {
+        let name = repo
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| repo.display().to_string());
+        let git_dir = resolve_git_dir(repo).unwrap_or_else(|| repo.join(".git"));
+        Self {
+            name,
+            common_dir: resolve_common_dir(&git_dir),
+            git_dir,
+            tail: LogTail::default(),
+            baselined: false,
+        }
+    }
+
+    /// 检查 HEAD 与远程跟踪分支的 reflog，返回新产生的提交与推送
+    fn poll(&mut self) -> Vec<GitActivity> {
+        let baseline = !self.baselined;
+        self.baselined = true;
+        let mut found = Vec::new();
+
+        let head = self.git_dir.join("logs").join("HEAD");
+        for line in self.tail.read_new(&head, baseline) {
+            if let Some(summary) = parse_reflog_line(&line).and_then(|(_, m)| commit_summary(m)) {
+                found.push(GitActivity {
+                    kind: GitActivityKind::Commit,
+                    repo: self.name.clone(),
+                    branch: None,
+                    summary: Some(summary.to_string()),
+                });
+            }
+        }
+
+        let remotes = self.common_dir.join("logs").join("refs").join("remotes");
+        for path in files_under(&remotes) {
+            for line in self.tail.read_new(&path, baseline) {
+                let Some((sha, message)) = parse_reflog_line(&line) else {
+                    continue;
+                };
+                if !is_push(message) {
+                    continue;
+                }
+                let branch = path.strip_prefix(&remotes).ok().map(|p| {
+                    p.components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/")
+                });
+                let summary = fs::read_to_string(&head)
+                    .ok()
+                    .and_then(|log| summary_for(&log, sha));
+                found.push(GitActivity {
+                    kind: GitActivityKind::Push,
+                    repo: self.name.clone(),
+                    branch,
+                    summary,
+                });
+            }
+        }
+        found
+    }
+}
+
+/// 返回其中不是 Git 仓库（找不到 git 目录）的路径，供设置窗口校验输入
+#[tauri::command]
+pub fn find_invalid_repos(paths: Vec<String>) -> Vec<String> {
+    paths
+        .into_iter()
+        .filter(|path| !resolve_git_dir(Path::new(path)).is_some_and(|dir| dir.is_dir()))
+        .collect()
+}
+
+fn load_prefs<R: Runtime>(app: &AppHandle<R>) -> GitWatchPrefs {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(PREFERENCES_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// 启动后台线程：监视偏好中配置的仓库（git 目录下的 reflog，支持工作树与子模块），
+/// 有新提交或推送时向主窗口推送 `git:activity`；未开启时不读取任何仓库
+pub fn spawn_watcher<R: Runtime>(app: AppHandle<R>) {
+    let session = app.state::<Arc<SessionState>>().inner().clone();
+    let handle = app.clone();
+    supervisor::spawn(&app, "git_watch", CHECK_INTERVAL, move || {
+        let app = handle.clone();
+        let session = Arc::clone(&session);
+        let mut repos: HashMap<PathBuf, RepoWatch> = HashMap::new();
+        move || {
+            let prefs = load_prefs(&app);
+            if prefs.git_watch_enabled && session.is_active() {
+                let configured: Vec<PathBuf> =
+                    prefs.git_watch_repos.iter().map(PathBuf::from).collect();
+                repos.retain(|path, _| configured.contains(path));
+                for path in configured {
+                    let watch = repos
+                        .entry(path.clone())
+                        .or_insert_with(|| RepoWatch::new(&path));
+                    for activity in watch.poll() {
+                        let _ = routing::emit_routed(&app, "git:activity", activity);
+                    }
+                }
+            } else {
+                // 关闭或会话断开后恢复时重新记录基线，不补报期间的提交
+                repos.clear();
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        commit_summary, is_push, parse_reflog_line, resolve_common_dir, resolve_git_dir,
+        summary_for, LogTail,
+    };
+    use std::io::Write;
+
+    const OLD: &str = "0000000000000000000000000000000000000000";
+    const NEW: &str = "1111111111111111111111111111111111111111";
+
+    fn line(new_sha: &str, message: &str) -> String {
+        format!("{OLD} {new_sha} Bird <bird@example.com> 1767225600 +0800\t{message}\n")
+    }
+
+    #[test]
+    fn reflog_messages_should_be_classified() {
+        let entry = line(NEW, "commit (amend): fix: 修复登录");
+        let (sha, message) = parse_reflog_line(entry.trim_end()).unwrap();
+        assert_eq!(sha, NEW);
+        assert_eq!(commit_summary(message), Some("fix: 修复登录"));
+        assert_eq!(commit_summary("checkout: moving from main to dev"), None);
+        assert!(is_push("update by push"));
+        assert!(!is_push("fetch: fast-forward"));
+    }
+
+    #[test]
+    fn push_summary_should_come_from_head_reflog() {
+        let head_log = [
+            line(NEW, "commit: feat: first"),
+            line(
+                "2222222222222222222222222222222222222222",
+                "commit: feat: second",
+            ),
+            line(NEW, "checkout: moving from dev to main"),
+        ]
+        .concat();
+        assert_eq!(summary_for(&head_log, NEW).as_deref(), Some("feat: first"));
+        assert_eq!(summary_for(&head_log, "3333"), None);
+    }
+
+    #[test]
+    fn worktree_git_file_should_resolve_to_git_and_common_dirs() {
+        let root = std::env::temp_dir().join(format!("bird-pet-git-dirs-{}", std::process::id()));
+        let main = root.join("main");
+        let worktree = root.join("wt");
+        let wt_git_dir = main.join(".git").join("worktrees").join("wt");
+        std::fs::create_dir_all(&wt_git_dir).unwrap();
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::write(wt_git_dir.join("commondir"), "../..\n").unwrap();
+        std::fs::write(worktree.join(".git"), "gitdir: ../main/.git/worktrees/wt\n").unwrap();
+
+        let canonical = |path: &std::path::Path| path.canonicalize().unwrap();
+        let main_git = resolve_git_dir(&main);
+        let git_dir = resolve_git_dir(&worktree).unwrap();
+        let resolved = (
+            canonical(&git_dir),
+            canonical(&resolve_common_dir(&git_dir)),
+        );
+        let expected = (canonical(&wt_git_dir), canonical(&main.join(".git")));
+        let missing = resolve_git_dir(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(main_git, Some(main.join(".git")));
+        assert_eq!(resolved, expected);
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn log_tail_should_skip_history_and_partial_lines() {
+        let dir = std::env::temp_dir().join(format!("bird-pet-git-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("HEAD");
+        std::fs::write(&path, line(NEW, "commit: old")).unwrap();
+
+        let mut tail = LogTail::default();
+        assert!(tail.read_new(&path, true).is_empty());
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        let fresh = line(NEW, "commit: fresh");
+        file.write_all(fresh.as_bytes()).unwrap();
+        file.write_all(b"partial").unwrap();
+        let lines = tail.read_new(&path, true);
+        let second = tail.read_new(&path, true);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(lines, vec![fresh.trim_end().to_string()]);
+        assert!(second.is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use memo_core::store::STORE_FILE;
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, Manager, PhysicalPosition, Runtime, WebviewUrl, WebviewWindowBuilder, WindowEvent,
+};
+use tauri_plugin_store::StoreExt;
+
+/// 鸟巢窗口 label
+pub const NEST_LABEL: &str = "nest";
+/// 鸟巢设置在存储中的 key（前端 STORE_KEYS.NEST_WIDGET）
+const STORE_KEY: &str = "nestWidget";
+/// 鸟巢窗口尺寸（逻辑像素）
+const NEST_SIZE: f64 = 96.0;
+
+/// 鸟巢持久化设置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NestSettings {
+    /// 是否显示鸟巢
+    pub enabled: bool,
+    /// 用户放置的位置（物理像素），未放置过为 None
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+}
+
+impl NestSettings {
+    /// 已保存的位置（两个坐标都存在时）
+    pub fn position(&self) -> Option<(i32, i32)> {
+        Some((self.x?, self.y?))
+    }
+}
+
+fn load_settings<R: Runtime>(app: &AppHandle<R>) -> NestSettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(STORE_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_settings<R: Runtime>(app: &AppHandle<R>, settings: NestSettings) {
+    let Ok(store) = app.store(STORE_FILE) else {
+        return;
+    };
+    if let Ok(value) = serde_json::to_value(settings) {
+        store.set(STORE_KEY, value);
+    }
+}
+
+/// 保存的位置是否仍落在某个显示器内（显示器拔掉后避免窗口跑到屏幕外）
+fn is_on_screen<R: Runtime>(app: &AppHandle<R>, (x, y): (i32, i32)) -> bool {
+    let Ok(monitors) = app.available_monitors() else {
+        return false;
+    };
+    monitors.iter().any(|m| {
+        let pos = m.position();
+        let size = m.size();
+        x >= pos.x && y >= pos.y && x < pos.x + size.width as i32 && y < pos.y + size.height as i32
+    })
+}
+
+/// 打开鸟巢窗口（已存在则显示），恢复用户放置的位置并跟踪后续拖动
+pub fn open_nest<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    if let Some(window) = app.get_webview_window(NEST_LABEL) {
+        return window.show();
+    }
+
+    let saved = load_settings(app)
+        .position()
+        .filter(|pos| is_on_screen(app, *pos));
+
+    let mut builder =
+        WebviewWindowBuilder::new(app, NEST_LABEL, WebviewUrl::App("nest.html".into()))
+            .title("BirdPet Nest")
+            .inner_size(NEST_SIZE, NEST_SIZE)
+            .resizable(false)
+            .decorations(false)
+            .transparent(true)
+            .shadow(false)
+            .skip_taskbar(true)
+            .focused(false)
+            // 置于普通窗口之下（平台不支持时退化为普通层级）
+            .always_on_bottom(true)
+            .visible(false);
+    if saved.is_none() {
+        builder = builder.center();
+    }
+    let window = builder.build()?;
+
+    if let Some((x, y)) = saved {
+        window.set_position(PhysicalPosition::new(x, y))?;
+    }
+    window.show()?;
+
+    let handle = app.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Moved(pos) = event {
+            let mut settings = load_settings(&handle);
+            settings.x = Some(pos.x);
+            settings.y = Some(pos.y);
+            save_settings(&handle, settings);
+        }
+    });
+
+    Ok(())
+}
+
+/// 启用/关闭鸟巢并持久化
+pub fn set_nest_enabled<R: Runtime>(app: &AppHandle<R>, enabled: bool) -> tauri::Result<()> {
+    let mut settings = load_settings(app);
+    settings.enabled = enabled;
+    save_settings(app, settings);
+
+    if enabled {
+        open_nest(app)
+    } else if let Some(window) = app.get_webview_window(NEST_LABEL) {
+        window.close()
+    } else {
+        Ok(())
+    }
+}
+
+/// 切换鸟巢显示（托盘菜单使用）
+pub fn toggle_nest<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    let enabled = load_settings(app).enabled;
+    set_nest_enabled(app, !enabled)
+}
+
+/// 启动时按持久化设置恢复鸟巢
+pub fn restore_nest<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    if load_settings(app).enabled {
+        open_nest(app)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_nest_visible<R: Runtime>(app: AppHandle<R>, visible: bool) -> Result<(), String> {
+    set_nest_enabled(&app, visible).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_nest_settings<R: Runtime>(app: AppHandle<R>) -> NestSettings {
+    load_settings(&app)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NestSettings;
+
+    #[test]
+    fn position_should_require_both_coordinates() {
+        let mut settings = NestSettings::default();
+        assert_eq!(settings.position(), None);
+        settings.x = Some(10);
+        assert_eq!(settings.position(), None);
+        settings.y = Some(-20);
+        assert_eq!(settings.position(), Some((10, -20)));
+    }
+
+    #[test]
+    fn settings_should_tolerate_missing_fields() {
+        let settings: NestSettings = serde_json::from_str(r#"{ "enabled": true }"#).unwrap();
+        assert!(settings.enabled);
+        assert_eq!(settings.position(), None);
+    }
+}
@@ -0,0 +1,221 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
+
+/// 进度事件名（广播给所有窗口，任意窗口都可展示进度）
+pub const PROGRESS_EVENT: &str = "op:progress";
+/// 操作被取消时命令返回的错误信息（前端据此区分取消与失败）
+pub const CANCELLED: &str = "操作已取消";
+
+/// 操作状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationState {
+    Running,
+    Done,
+    Cancelled,
+    Failed,
+}
+
+/// `op:progress` 事件负载
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationProgress {
+    pub id: String,
+    /// 操作类型（如 "report"），前端据此选择展示文案
+    pub kind: &'static str,
+    pub state: OperationState,
+    pub done: u64,
+    pub total: u64,
+    pub message: Option<String>,
+}
+
+/// 进行中操作的登记表（id → 取消标记）
+#[derive(Default)]
+pub struct Operations {
+    running: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    next_id: AtomicU64,
+}
+
+impl Operations {
+    /// 登记一个操作；前端未指定 id 时自动生成
+    fn register(
+        &self,
+        id: Option<String>,
+        kind: &str,
+    ) -> Result<(String, Arc<AtomicBool>), String> {
+        let id = id
+            .unwrap_or_else(|| format!("{kind}-{}", self.next_id.fetch_add(1, Ordering::Relaxed)));
+        let mut running = self.running.lock().map_err(|e| e.to_string())?;
+        if running.contains_key(&id) {
+            return Err(format!("操作 {id} 正在进行中"));
+        }
+        let flag = Arc::new(AtomicBool::new(false));
+        running.insert(id.clone(), Arc::clone(&flag));
+        Ok((id, flag))
+    }
+
+    fn unregister(&self, id: &str) {
+        if let Ok(mut running) = self.running.lock() {
+            running.remove(id);
+        }
+    }
+
+    /// 请求取消；操作不存在（已结束或 id 错误）时返回 false
+    pub fn cancel(&self, id: &str) -> bool {
+        let Ok(running) = self.running.lock() else {
+            return false;
+        };
+        match running.get(id) {
+            Some(flag) => {
+                flag.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// 结束时的最终状态：已经完成的操作即使随后收到取消请求也算完成
+fn final_state<T>(result: &Result<T, String>, cancelled: bool) -> OperationState {
+    match result {
+        Ok(_) => OperationState::Done,
+        Err(_) if cancelled => OperationState::Cancelled,
+        Err(_) => OperationState::Failed,
+    }
+}
+
+/// 单个长任务的句柄：上报进度、检查取消，drop 时自动注销
+pub struct Operation<R: Runtime> {
+    app: AppHandle<R>,
+    id: String,
+    kind: &'static str,
+    cancelled: Arc<AtomicBool>,
+    done: u64,
+    total: u64,
+}
+
+impl<R: Runtime> Operation<R> {
+    /// 开始一个操作并广播初始进度；`total` 为总步数
+    pub fn begin(
+        app: &AppHandle<R>,
+        id: Option<String>,
+        kind: &'static str,
+        total: u64,
+    ) -> Result<Self, String> {
+        let (id, cancelled) = app.state::<Arc<Operations>>().register(id, kind)?;
+        let op = Operation {
+            app: app.clone(),
+            id,
+            kind,
+            cancelled,
+            done: 0,
+            total,
+        };
+        op.emit(OperationState::Running, None);
+        Ok(op)
+    }
+
+    /// 已请求取消时返回 Err(CANCELLED)，长任务在每一步之间调用
+    pub fn check(&self) -> Result<(), String> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Err(CANCELLED.to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 完成一步并广播进度；已请求取消时返回 Err(CANCELLED)
+    pub fn step(&mut self, message: &str) -> Result<(), String> {
+        self.check()?;
+        self.done = (self.done + 1).min(self.total);
+        self.emit(OperationState::Running, Some(message.to_string()));
+        Ok(())
+    }
+
+    /// 结束操作并广播最终状态，原样返回结果（取消时统一为 Err(CANCELLED)）
+    pub fn finish<T>(mut self, result: Result<T, String>) -> Result<T, String> {
+        match final_state(&result, self.cancelled.load(Ordering::Relaxed)) {
+            OperationState::Cancelled => {
+                self.emit(OperationState::Cancelled, None);
+                Err(CANCELLED.to_string())
+            }
+            state => {
+                if state == OperationState::Done {
+                    self.done = self.total;
+                }
+                self.emit(state, result.as_ref().err().cloned());
+                result
+            }
+        }
+    }
+
+    fn emit(&self, state: OperationState, message: Option<String>) {
+        let _ = self.app.emit(
+            PROGRESS_EVENT,
+            OperationProgress {
+                id: self.id.clone(),
+                kind: self.kind,
+                state,
+                done: self.done,
+                total: self.total,
+                message,
+            },
+        );
+    }
+}
+
+impl<R: Runtime> Drop for Operation<R> {
+    fn drop(&mut self) {
+        self.app.state::<Arc<Operations>>().unregister(&self.id);
+    }
+}
+
+/// 请求取消长任务；任务会在下一步开始前停止
+#[tauri::command]
+pub fn cancel_operation(operations: State<'_, Arc<Operations>>, id: String) -> bool {
+    operations.cancel(&id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{final_state, OperationState, Operations};
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn register_should_generate_ids_and_reject_duplicates() {
+        let ops = Operations::default();
+        let (a, _) = ops.register(None, "report").unwrap();
+        let (b, _) = ops.register(None, "report").unwrap();
+        assert_ne!(a, b);
+        assert!(ops.register(Some("x".into()), "report").is_ok());
+        assert!(ops.register(Some("x".into()), "report").is_err());
+
+        ops.unregister("x");
+        assert!(ops.register(Some("x".into()), "report").is_ok());
+    }
+
+    #[test]
+    fn cancel_should_flag_running_operation_only() {
+        let ops = Operations::default();
+        let (id, flag) = ops.register(Some("op-1".into()), "report").unwrap();
+        assert!(ops.cancel(&id));
+        assert!(flag.load(Ordering::Relaxed));
+
+        ops.unregister(&id);
+        assert!(!ops.cancel(&id));
+        assert!(!ops.cancel("missing"));
+    }
+
+    #[test]
+    fn final_state_should_only_mark_failed_runs_as_cancelled() {
+        let ok: Result<(), String> = Ok(());
+        let err: Result<(), String> = Err("boom".into());
+        assert_eq!(final_state(&ok, false), OperationState::Done);
+        assert_eq!(final_state(&err, false), OperationState::Failed);
+        assert_eq!(final_state(&err, true), OperationState::Cancelled);
+        assert_eq!(final_state(&ok, true), OperationState::Done);
+    }
+}
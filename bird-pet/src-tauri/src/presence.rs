@@ -0,0 +1,362 @@
+use crate::user_status::UserStatus;
+use memo_core::store::{PREFERENCES_KEY, STORE_FILE};
+use serde::Deserialize;
+use serde_json::json;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Runtime, State};
+use tauri_plugin_store::StoreExt;
+
+/// 网络连接与读写超时
+const IO_TIMEOUT: Duration = Duration::from_secs(3);
+/// MQTT 连接保活时长（秒）；每次发布都是短连接，只需覆盖一次往返
+const MQTT_KEEP_ALIVE_SECS: u16 = 60;
+/// Discord IPC 操作码
+const OP_HANDSHAKE: u32 = 0;
+const OP_FRAME: u32 = 1;
+/// Discord IPC 单帧上限；握手与 SET_ACTIVITY 的回包都只有几 KB
+const MAX_FRAME_LEN: usize = 64 * 1024;
+
+/// MQTT 发布目标（前端 UserPreferences.statusMqtt）
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttConfig {
+    pub host: String,
+    pub port: u16,
+    pub topic: String,
+}
+
+/// 前端 UserPreferences 中的相关字段
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct PresencePrefs {
+    status_mqtt: Option<MqttConfig>,
+    status_discord_client_id: Option<String>,
+}
+
+fn load_prefs<R: Runtime>(app: &AppHandle<R>) -> PresencePrefs {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(PREFERENCES_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// 状态的对外文本（与前端 UserStatus 一致）
+fn status_key(status: UserStatus) -> &'static str {
+    match status {
+        UserStatus::InMeeting => "in_meeting",
+        UserStatus::Focused => "focused",
+        UserStatus::Away => "away",
+        UserStatus::WindingDown => "winding_down",
+        UserStatus::Available => "available",
+    }
+}
+
+/// Discord 个人状态中显示的文字
+fn status_label(status: UserStatus) -> &'static str {
+    match status {
+        UserStatus::InMeeting => "会议中",
+        UserStatus::Focused => "专注中",
+        UserStatus::Away => "暂时离开",
+        UserStatus::WindingDown => "准备收工",
+        UserStatus::Available => "有空",
+    }
+}
+
+// ─── MQTT 3.1.1（QoS 0，保留消息） ───
+
+/// 剩余长度字段：每字节 7 位，最高位表示后面还有字节
+pub fn encode_remaining_length(mut len: usize, out: &mut Vec<u8>) {
+    loop {
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+}
+
+/// UTF-8 字符串：u16 长度前缀，超长时拒绝而不是截断长度
+fn push_str(out: &mut Vec<u8>, s: &str) -> Result<(), String> {
+    let len = u16::try_from(s.len()).map_err(|_| format!("MQTT 字段过长（{} 字节）", s.len()))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+fn packet(header: u8, body: &[u8]) -> Vec<u8> {
+    let mut out = vec![header];
+    encode_remaining_length(body.len(), &mut out);
+    out.extend_from_slice(body);
+    out
+}
+
+/// CONNECT：clean session，无用户名密码
+pub fn encode_connect(client_id: &str) -> Result<Vec<u8>, String> {
+    let mut body = Vec::new();
+    push_str(&mut body, "MQTT")?;
+    body.push(4); // 协议级别 3.1.1
+    body.push(0x02); // clean session
+    body.extend_from_slice(&MQTT_KEEP_ALIVE_SECS.to_be_bytes());
+    push_str(&mut body, client_id)?;
+    Ok(packet(0x10, &body))
+}
+
+/// PUBLISH：QoS 0 + retain，订阅方随时连上都能拿到最新状态
+pub fn encode_publish(topic: &str, payload: &[u8]) -> Result<Vec<u8>, String> {
+    let mut body = Vec::new();
+    push_str(&mut body, topic)?;
+    body.extend_from_slice(payload);
+    Ok(packet(0x31, &body))
+}
+
+fn publish_mqtt(config: &MqttConfig, payload: &[u8]) -> Result<(), String> {
+    let addr = (config.host.as_str(), config.port)
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or_else(|| format!("无法解析 MQTT 地址 {}", config.host))?;
+    let mut stream = TcpStream::connect_timeout(&addr, IO_TIMEOUT).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(IO_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    stream
+        .set_write_timeout(Some(IO_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+
+    let client_id = format!("bird-pet-{}", std::process::id());
+    stream
+        .write_all(&encode_connect(&client_id)?)
+        .map_err(|e| e.to_string())?;
+    let mut connack = [0u8; 4];
+    stream.read_exact(&mut connack).map_err(|e| e.to_string())?;
+    if connack[0] != 0x20 || connack[3] != 0 {
+        return Err(format!("MQTT 连接被拒绝（返回码 {}）", connack[3]));
+    }
+    stream
+        .write_all(&encode_publish(&config.topic, payload)?)
+        .map_err(|e| e.to_string())?;
+    stream.write_all(&[0xE0, 0x00]).map_err(|e| e.to_string())
+}
+
+// ─── Discord Rich Presence（本地 IPC） ───
+
+/// IPC 帧：操作码与长度均为小端 u32，后接 JSON
+pub fn encode_frame(op: u32, payload: &serde_json::Value) -> Vec<u8> {
+    let body = payload.to_string();
+    let mut out = Vec::with_capacity(8 + body.len());
+    out.extend_from_slice(&op.to_le_bytes());
+    out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    out.extend_from_slice(body.as_bytes());
+    out
+}
+
+/// 读取一帧；长度字段超过 MAX_FRAME_LEN 时直接报错，不按对端声明的长度分配内存
+fn read_frame(stream: &mut impl Read) -> Result<serde_json::Value, String> {
+    let mut header = [0u8; 8];
+    stream.read_exact(&mut header).map_err(|e| e.to_string())?;
+    let len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(format!("Discord IPC 帧过大（{len} 字节）"));
+    }
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body).map_err(|e| e.to_string())?;
+    serde_json::from_slice(&body).map_err(|e| e.to_string())
+}
+
+trait IpcStream: Read + Write + Send {}
+impl<T: Read + Write + Send> IpcStream for T {}
+
+#[cfg(unix)]
+fn open_ipc(index: u8) -> Option<Box<dyn IpcStream>> {
+    let dir = ["XDG_RUNTIME_DIR", "TMPDIR", "TMP", "TEMP"]
+        .iter()
+        .find_map(|key| std::env::var(key).ok())
+        .unwrap_or_else(|| "/tmp".to_string());
+    let path = std::path::Path::new(&dir).join(format!("discord-ipc-{index}"));
+    let stream = std::os::unix::net::UnixStream::connect(path).ok()?;
+    stream.set_read_timeout(Some(IO_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(IO_TIMEOUT)).ok()?;
+    Some(Box::new(stream))
+}
+
+#[cfg(windows)]
+fn open_ipc(index: u8) -> Option<Box<dyn IpcStream>> {
+    let path = format!(r"\\?\pipe\discord-ipc-{index}");
+    let pipe = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .ok()?;
+    Some(Box::new(pipe))
+}
+
+/// 与 Discord 客户端的连接；Discord 在连接断开时清除个人状态，因此需要一直保持。
+/// Windows 命名管道无法设置超时，读写期间连接从锁中取出，卡住的 Discord 不会阻塞其他调用
+#[derive(Default)]
+pub struct DiscordPresence {
+    conn: Mutex<Option<(String, Box<dyn IpcStream>)>>,
+}
+
+impl DiscordPresence {
+    fn connect(client_id: &str) -> Result<Box<dyn IpcStream>, String> {
+        let mut stream = (0..10)
+            .find_map(open_ipc)
+            .ok_or_else(|| "未检测到正在运行的 Discord".to_string())?;
+        stream
+            .write_all(&encode_frame(
+                OP_HANDSHAKE,
+                &json!({ "v": 1, "client_id": client_id }),
+            ))
+            .map_err(|e| e.to_string())?;
+        read_frame(&mut stream)?;
+        Ok(stream)
+    }
+
+    fn set_activity(&self, client_id: &str, status: UserStatus) -> Result<(), String> {
+        let taken = self
+            .conn
+            .lock()
+            .expect("failed to lock discord presence")
+            .take();
+        // 更换应用 ID 后重新握手
+        let mut stream = match taken {
+            Some((id, stream)) if id == client_id => stream,
+            _ => Self::connect(client_id)?,
+        };
+        let frame = encode_frame(
+            OP_FRAME,
+            &json!({
+                "cmd": "SET_ACTIVITY",
+                "args": {
+                    "pid": std::process::id(),
+                    "activity": { "details": status_label(status) },
+                },
+                "nonce": status_key(status),
+            }),
+        );
+        stream
+            .write_all(&frame)
+            .map_err(|e| e.to_string())
+            .and_then(|_| read_frame(&mut stream))?;
+        // 出错时连接随之丢弃（Discord 重启后旧连接失效），下次发布时重连；
+        // 并发发布时后完成的一次覆盖先完成的
+        *self.conn.lock().expect("failed to lock discord presence") =
+            Some((client_id.to_string(), stream));
+        Ok(())
+    }
+
+    /// 断开连接（Discord 随之清除个人状态）
+    fn clear(&self) {
+        *self.conn.lock().expect("failed to lock discord presence") = None;
+    }
+}
+
+/// 发布状态到 MQTT（仅主窗口）；未配置时不做任何事
+#[tauri::command]
+pub async fn publish_status_mqtt<R: Runtime>(
+    app: AppHandle<R>,
+    status: UserStatus,
+) -> Result<(), String> {
+    let Some(config) = load_prefs(&app).status_mqtt else {
+        return Ok(());
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        publish_mqtt(&config, status_key(status).as_bytes())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 发布状态到 Discord 个人状态（仅主窗口）；未配置应用 ID 时清除已发布的状态
+#[tauri::command]
+pub async fn publish_status_discord<R: Runtime>(
+    app: AppHandle<R>,
+    presence: State<'_, std::sync::Arc<DiscordPresence>>,
+    status: UserStatus,
+) -> Result<(), String> {
+    let presence = std::sync::Arc::clone(presence.inner());
+    let client_id = load_prefs(&app)
+        .status_discord_client_id
+        .filter(|id| !id.trim().is_empty());
+    tauri::async_runtime::spawn_blocking(move || match client_id {
+        Some(id) => presence.set_activity(id.trim(), status),
+        None => {
+            presence.clear();
+            Ok(())
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_connect, encode_frame, encode_publish, encode_remaining_length, read_frame,
+        MAX_FRAME_LEN,
+    };
+
+    fn remaining(len: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_remaining_length(len, &mut out);
+        out
+    }
+
+    #[test]
+    fn remaining_length_should_use_continuation_bits() {
+        assert_eq!(remaining(0), [0x00]);
+        assert_eq!(remaining(127), [0x7F]);
+        assert_eq!(remaining(128), [0x80, 0x01]);
+        assert_eq!(remaining(16_383), [0xFF, 0x7F]);
+        assert_eq!(remaining(16_384), [0x80, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn mqtt_packets_should_match_spec_layout() {
+        let connect = encode_connect("bird").unwrap();
+        assert_eq!(
+            connect,
+            [
+                0x10, 16, 0, 4, b'M', b'Q', b'T', b'T', 4, 0x02, 0, 60, 0, 4, b'b', b'i', b'r',
+                b'd'
+            ]
+        );
+        let publish = encode_publish("me/status", b"focused").unwrap();
+        assert_eq!(&publish[..4], [0x31, 18, 0, 9]);
+        assert_eq!(&publish[4..13], b"me/status");
+        assert_eq!(&publish[13..], b"focused");
+    }
+
+    #[test]
+    fn discord_frame_should_prefix_little_endian_op_and_length() {
+        let frame = encode_frame(1, &serde_json::json!({ "v": 1 }));
+        assert_eq!(&frame[..4], [1, 0, 0, 0]);
+        assert_eq!(&frame[4..8], [7, 0, 0, 0]);
+        assert_eq!(&frame[8..], br#"{"v":1}"#);
+    }
+
+    #[test]
+    fn mqtt_should_reject_strings_longer_than_u16() {
+        let topic = "t".repeat(usize::from(u16::MAX) + 1);
+        assert!(encode_publish(&topic, b"focused").is_err());
+        assert!(encode_publish(&topic[1..], b"focused").is_ok());
+    }
+
+    #[test]
+    fn discord_frame_should_reject_oversized_length() {
+        let mut header = Vec::new();
+        header.extend_from_slice(&1u32.to_le_bytes());
+        header.extend_from_slice(&(MAX_FRAME_LEN as u32 + 1).to_le_bytes());
+        assert!(read_frame(&mut header.as_slice()).is_err());
+        let frame = encode_frame(1, &serde_json::json!({ "v": 1 }));
+        assert_eq!(read_frame(&mut frame.as_slice()).unwrap()["v"], 1);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::disk_guard::DiskGuard;
+use crate::operations::Operation;
+use chrono::Local;
+use memo_core::recap::{render_recap_svg, MonthlyRecap};
+use memo_core::report::{render_html, ReportPeriod, UserProfile};
+use memo_core::store::{PROFILE_KEY, STORE_FILE};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_store::StoreExt;
+
+/// 导出格式（PDF 需要无头打印管线，暂不提供；可导出 HTML 后用浏览器打印）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    Html,
+}
+
+impl ReportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+        }
+    }
+}
+
+/// 渲染报告并写入文件，返回实际写入的路径
+///
+/// `path` 为空时写入系统下载目录，文件名带生成日期；
+/// 指定时只能写到下载目录或应用数据目录下（见 [`output_path`]）。
+/// 数据来自前端记忆系统持久化的用户画像（调用前应先保存记忆）。
+/// 导出过程通过 `op:progress` 上报进度，可用 `cancel_operation(op_id)` 取消。
+#[tauri::command]
+pub async fn render_report<R: Runtime>(
+    app: AppHandle<R>,
+    period: ReportPeriod,
+    format: ReportFormat,
+    path: Option<String>,
+    op_id: Option<String>,
+) -> Result<String, String> {
+    if app.state::<Arc<DiskGuard>>().is_low() {
+        return Err("磁盘空间不足，已暂停导出报告".to_string());
+    }
+
+    let mut op = Operation::begin(&app, op_id, "report", 3)?;
+    let result = write_report(&app, &mut op, period, format, path);
+    op.finish(result)
+}
+
+fn write_report<R: Runtime>(
+    app: &AppHandle<R>,
+    op: &mut Operation<R>,
+    period: ReportPeriod,
+    format: ReportFormat,
+    path: Option<String>,
+) -> Result<String, String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let profile: UserProfile = store
+        .get(PROFILE_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default();
+    op.step("读取记忆")?;
+
+    let now = Local::now();
+    let default_name = format!(
+        "{}-{}.{}",
+        period.file_stem(),
+        now.format("%Y-%m-%d"),
+        format.extension()
+    );
+    let target = output_path(app, path, &default_name, format.extension())?;
+
+    let html = render_html(period, &profile, &now.format("%Y-%m-%d %H:%M").to_string());
+    op.step("渲染报告")?;
+    std::fs::write(&target, html).map_err(|e| e.to_string())?;
+    Ok(target.to_string_lossy().into_owned())
+}
+
+/// 将月度回顾渲染为 SVG 图片并写入文件，返回实际写入的路径
+///
+/// `path` 为空时写入系统下载目录，文件名带回顾月份；指定时的限制同 [`render_report`]。
+#[tauri::command]
+pub fn render_recap<R: Runtime>(
+    app: AppHandle<R>,
+    recap: MonthlyRecap,
+    path: Option<String>,
+) -> Result<String, String> {
+    if app.state::<Arc<DiskGuard>>().is_low() {
+        return Err("磁盘空间不足，已暂停导出图片".to_string());
+    }
+    let default_name = format!("birdpet-recap-{}.svg", recap.month);
+    let target = output_path(&app, path, &default_name, "svg")?;
+    std::fs::write(&target, render_recap_svg(&recap)).map_err(|e| e.to_string())?;
+    Ok(target.to_string_lossy().into_owned())
+}
+
+/// 解析导出路径
+///
+/// 路径由 webview 传入，不能信任：缺省时写入下载目录下的 `default_name`；
+/// 指定时只接受下载目录或应用数据目录（含子目录）下、扩展名为 `extension` 的文件，
+/// 避免被借来覆盖任意位置的文件。
+fn output_path<R: Runtime>(
+    app: &AppHandle<R>,
+    path: Option<String>,
+    default_name: &str,
+    extension: &str,
+) -> Result<PathBuf, String> {
+    let download_dir = app.path().download_dir().map_err(|e| e.to_string())?;
+    let Some(path) = path else {
+        return Ok(download_dir.join(default_name));
+    };
+    let roots: Vec<PathBuf> = [Ok(download_dir), app.path().app_data_dir()]
+        .into_iter()
+        .filter_map(|dir| dir.ok()?.canonicalize().ok())
+        .collect();
+    confine(Path::new(&path), &roots, extension)
+}
+
+/// 校验 `path` 位于 `roots`（已规范化）之一下且扩展名匹配，返回规范化后的写入路径
+///
+/// 父目录先规范化再比较，`..` 与指向目录外的符号链接目录都会被识别；
+/// 目标本身是符号链接时拒绝，写入不会跟随它落到目录外。
+fn confine(path: &Path, roots: &[PathBuf], extension: &str) -> Result<PathBuf, String> {
+    if path.extension().and_then(|ext| ext.to_str()) != Some(extension) {
+        return Err(format!("导出文件的扩展名必须是 .{extension}"));
+    }
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        return Err("导出路径无效".to_string());
+    };
+    let parent = parent
+        .canonicalize()
+        .map_err(|e| format!("导出目录不可用：{e}"))?;
+    if !roots.iter().any(|root| parent.starts_with(root)) {
+        return Err("只能导出到下载目录或应用数据目录".to_string());
+    }
+    let target = parent.join(name);
+    if target
+        .symlink_metadata()
+        .is_ok_and(|meta| meta.file_type().is_symlink())
+    {
+        return Err("导出路径不能是符号链接".to_string());
+    }
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(name: &str) -> (PathBuf, PathBuf) {
+        let base = std::env::temp_dir().join(format!("bird-pet-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let root = base.join("downloads");
+        std::fs::create_dir_all(root.join("reports")).unwrap();
+        std::fs::create_dir_all(base.join("outside")).unwrap();
+        (base.canonicalize().unwrap(), root.canonicalize().unwrap())
+    }
+
+    #[test]
+    fn accepts_files_under_a_root() {
+        let (base, root) = setup("confine-ok");
+        let roots = [root.clone()];
+
+        let target = confine(&root.join("week.html"), &roots, "html").unwrap();
+        assert_eq!(target, root.join("week.html"));
+        let nested = confine(&root.join("reports/week.html"), &roots, "html").unwrap();
+        assert_eq!(nested, root.join("reports/week.html"));
+
+        std::fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn rejects_paths_outside_roots_or_with_other_extensions() {
+        let (base, root) = setup("confine-reject");
+        let roots = [root.clone()];
+
+        assert!(confine(&base.join("outside/week.html"), &roots, "html").is_err());
+        assert!(confine(&root.join("../outside/week.html"), &roots, "html").is_err());
+        assert!(confine(&root.join("week.bat"), &roots, "html").is_err());
+        assert!(confine(&root.join("missing/week.html"), &roots, "html").is_err());
+        assert!(confine(Path::new("week.html"), &roots, "html").is_err());
+
+        std::fs::remove_dir_all(base).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlinks_leading_outside() {
+        let (base, root) = setup("confine-symlink");
+        let roots = [root.clone()];
+        std::os::unix::fs::symlink(base.join("outside"), root.join("escape")).unwrap();
+        std::os::unix::fs::symlink(base.join("outside/x.html"), root.join("link.html")).unwrap();
+
+        assert!(confine(&root.join("escape/week.html"), &roots, "html").is_err());
+        assert!(confine(&root.join("link.html"), &roots, "html").is_err());
+
+        std::fs::remove_dir_all(base).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+use serde::Serialize;
+use tauri::ipc::Invoke;
+use tauri::{AppHandle, Emitter, EventTarget, Runtime};
+
+/// 主窗口 label
+pub const MAIN_WINDOW: &str = "main";
+/// 设置窗口 label（展示授权说明并由用户确认）
+pub const SETTINGS_WINDOW: &str = "settings";
+
+/// 命令 → 允许调用的窗口；未列出的命令一律拒绝，新增命令时须在此登记
+const COMMAND_PERMISSIONS: &[(&str, &[&str])] = &[
+    ("get_system_stats", &[MAIN_WINDOW]),
+    ("get_active_window_info", &[MAIN_WINDOW]),
+    ("publish_user_status", &[MAIN_WINDOW]),
+    ("get_user_status", &[MAIN_WINDOW]),
+    ("publish_status_mqtt", &[MAIN_WINDOW]),
+    ("publish_status_discord", &[MAIN_WINDOW]),
+    ("animate_window_to", &[MAIN_WINDOW]),
+    ("stop_window_animation", &[MAIN_WINDOW]),
+    ("set_nest_visible", &[MAIN_WINDOW]),
+    ("get_nest_settings", &[MAIN_WINDOW]),
+    ("get_timeline", &[MAIN_WINDOW]),
+    ("render_report", &[MAIN_WINDOW]),
+    ("render_recap", &[MAIN_WINDOW]),
+    ("get_consents", &[MAIN_WINDOW, SETTINGS_WINDOW]),
+    ("grant_consent", &[MAIN_WINDOW, SETTINGS_WINDOW]),
+    ("revoke_consent", &[MAIN_WINDOW, SETTINGS_WINDOW]),
+    ("is_dev_mode", &[MAIN_WINDOW]),
+    ("get_daylight", &[MAIN_WINDOW]),
+    ("get_disk_status", &[MAIN_WINDOW]),
+    ("get_session_status", &[MAIN_WINDOW]),
+    ("find_invalid_repos", &[SETTINGS_WINDOW]),
+    ("get_subsystem_health", &[MAIN_WINDOW]),
+    ("cancel_operation", &[MAIN_WINDOW]),
+    ("get_machine_info", &[MAIN_WINDOW]),
+    ("take_cli_memories", &[MAIN_WINDOW]),
+    ("backup_data", &[MAIN_WINDOW]),
+];
+
+/// 后端事件 → 接收窗口；未列出的事件广播给所有窗口
+const EVENT_ROUTES: &[(&str, &[&str])] = &[
+    ("app:request-quit", &[MAIN_WINDOW]),
+    ("tray:open-memories", &[MAIN_WINDOW]),
+    ("tray:toggle-autostart", &[MAIN_WINDOW]),
+    ("daylight:phase-changed", &[MAIN_WINDOW]),
+    ("disk:space-changed", &[MAIN_WINDOW]),
+    ("git:activity", &[MAIN_WINDOW]),
+    ("session:changed", &[MAIN_WINDOW]),
+    ("supervisor:subsystem-stopped", &[MAIN_WINDOW]),
+];
+
+/// 某窗口是否可以调用该命令
+pub fn is_allowed(command: &str, label: &str) -> bool {
+    match COMMAND_PERMISSIONS
+        .iter()
+        .find(|(name, _)| *name == command)
+    {
+        Some((_, labels)) => labels.contains(&label),
+        None => false,
+    }
+}
+
+/// 包装 `generate_handler!` 生成的处理器，在分发前统一按权限表检查调用方窗口，
+/// 各命令不再自行校验
+pub fn guard_invoke<R: Runtime>(
+    handler: impl Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        let message = &invoke.message;
+        if is_allowed(message.command(), message.webview_ref().label()) {
+            return handler(invoke);
+        }
+        let error = format!(
+            "窗口 {} 无权调用 {}",
+            message.webview_ref().label(),
+            message.command()
+        );
+        invoke.resolver.reject(error);
+        true
+    }
+}
+
+/// 事件的接收窗口；None 表示广播
+pub fn targets(event: &str) -> Option<&'static [&'static str]> {
+    EVENT_ROUTES
+        .iter()
+        .find(|(name, _)| *name == event)
+        .map(|&(_, labels)| labels)
+}
+
+/// 按路由表发送后端事件：有路由的只发给对应窗口，否则广播
+pub fn emit_routed<R: Runtime, S: Serialize + Clone>(
+    app: &AppHandle<R>,
+    event: &str,
+    payload: S,
+) -> tauri::Result<()> {
+    match targets(event) {
+        Some(labels) => {
+            for label in labels {
+                app.emit_to(EventTarget::webview_window(*label), event, payload.clone())?;
+            }
+            Ok(())
+        }
+        None => app.emit(event, payload),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_allowed, targets, MAIN_WINDOW, SETTINGS_WINDOW};
+
+    #[test]
+    fn privileged_commands_should_be_limited_to_listed_windows() {
+        assert!(is_allowed("grant_consent", MAIN_WINDOW));
+        assert!(is_allowed("grant_consent", SETTINGS_WINDOW));
+        assert!(!is_allowed("grant_consent", "nest"));
+        assert!(!is_allowed("render_report", "memory-panel"));
+        assert!(!is_allowed("render_report", SETTINGS_WINDOW));
+    }
+
+    #[test]
+    fn unlisted_commands_and_windows_should_be_denied() {
+        assert!(is_allowed("get_disk_status", MAIN_WINDOW));
+        assert!(!is_allowed("get_disk_status", "nest"));
+        assert!(!is_allowed("animate_window_to", "bubble"));
+        assert!(!is_allowed("unregistered_command", MAIN_WINDOW));
+    }
+
+    #[test]
+    fn routed_events_should_target_main_and_others_broadcast() {
+        assert_eq!(targets("tray:open-memories"), Some(&[MAIN_WINDOW][..]));
+        assert_eq!(targets("status:changed"), None);
+    }
+}
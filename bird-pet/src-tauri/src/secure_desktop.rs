@@ -0,0 +1,140 @@
+use serde::Serialize;
+
+/// 读不到前台窗口的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UntrackedReason {
+    /// 安全桌面（UAC 提示、锁屏、Ctrl+Alt+Del 界面）
+    SecureDesktop,
+    /// 前台是以管理员身份运行的窗口，普通权限读不到它的进程信息
+    Elevated,
+}
+
+/// 输入桌面名称是否为安全桌面（普通桌面名为 "Default"，UAC / 锁屏为 "Winlogon"）
+pub fn is_secure_desktop_name(name: &str) -> bool {
+    !name.eq_ignore_ascii_case("Default")
+}
+
+/// 当前是否处于无法追踪的系统界面；非 Windows 平台始终返回 None
+#[cfg(windows)]
+pub fn untracked_reason() -> Option<UntrackedReason> {
+    if win::on_secure_desktop() {
+        Some(UntrackedReason::SecureDesktop)
+    } else if win::foreground_is_elevated() {
+        Some(UntrackedReason::Elevated)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(windows))]
+pub fn untracked_reason() -> Option<UntrackedReason> {
+    None
+}
+
+#[cfg(windows)]
+mod win {
+    use super::is_secure_desktop_name;
+    use std::ffi::c_void;
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::Security::{
+        GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY,
+    };
+    use windows_sys::Win32::System::StationsAndDesktops::{
+        CloseDesktop, GetUserObjectInformationW, OpenInputDesktop, DESKTOP_READOBJECTS, UOI_NAME,
+    };
+    use windows_sys::Win32::System::Threading::{
+        GetCurrentProcess, OpenProcess, OpenProcessToken, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowThreadProcessId,
+    };
+
+    /// 安全桌面激活时普通进程打不开输入桌面，能打开时再按名称确认
+    pub fn on_secure_desktop() -> bool {
+        // SAFETY: 句柄在本函数内打开并关闭，缓冲区长度按字节传入
+        unsafe {
+            let desktop = OpenInputDesktop(0, 0, DESKTOP_READOBJECTS);
+            if desktop.is_null() {
+                return true;
+            }
+            let mut name = [0u16; 64];
+            let mut needed = 0u32;
+            let ok = GetUserObjectInformationW(
+                desktop,
+                UOI_NAME,
+                name.as_mut_ptr().cast::<c_void>(),
+                std::mem::size_of_val(&name) as u32,
+                &mut needed,
+            );
+            CloseDesktop(desktop);
+            if ok == 0 {
+                return false;
+            }
+            let len = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+            is_secure_desktop_name(&String::from_utf16_lossy(&name[..len]))
+        }
+    }
+
+    /// 进程是否已提权；读不到令牌时返回 None
+    fn is_elevated(process: HANDLE) -> Option<bool> {
+        // SAFETY: 令牌句柄在本函数内打开并关闭，TOKEN_ELEVATION 按大小传入
+        unsafe {
+            let mut token: HANDLE = std::ptr::null_mut();
+            if OpenProcessToken(process, TOKEN_QUERY, &mut token) == 0 {
+                return None;
+            }
+            let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+            let mut len = 0u32;
+            let ok = GetTokenInformation(
+                token,
+                TokenElevation,
+                (&mut elevation as *mut TOKEN_ELEVATION).cast::<c_void>(),
+                std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+                &mut len,
+            );
+            CloseHandle(token);
+            (ok != 0).then_some(elevation.TokenIsElevated != 0)
+        }
+    }
+
+    /// 前台窗口确认属于提权进程（自身已提权时能正常读取，不算；查询失败视为未知，不算）
+    pub fn foreground_is_elevated() -> bool {
+        // SAFETY: 进程句柄在本函数内打开并关闭
+        unsafe {
+            if is_elevated(GetCurrentProcess()) == Some(true) {
+                return false;
+            }
+            let window = GetForegroundWindow();
+            if window.is_null() {
+                return false;
+            }
+            let mut pid = 0u32;
+            GetWindowThreadProcessId(window, &mut pid);
+            if pid == 0 {
+                return false;
+            }
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+            if process.is_null() {
+                // 查询失败只说明无法判断，不当作提权，照常采集
+                return false;
+            }
+            let elevated = is_elevated(process).unwrap_or(false);
+            CloseHandle(process);
+            elevated
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_secure_desktop_name;
+
+    #[test]
+    fn only_default_desktop_should_be_trackable() {
+        assert!(!is_secure_desktop_name("Default"));
+        assert!(!is_secure_desktop_name("default"));
+        assert!(is_secure_desktop_name("Winlogon"));
+        assert!(is_secure_desktop_name("Screen-saver"));
+    }
+}
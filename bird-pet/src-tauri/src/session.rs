@@ -0,0 +1,122 @@
+#[cfg(windows)]
+use crate::{routing, supervisor};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(windows)]
+use std::time::Duration;
+use tauri::State;
+#[cfg(windows)]
+use tauri::{AppHandle, Manager, Runtime};
+
+/// 后台检查间隔
+#[cfg(windows)]
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 用户会话状态（快速切换用户 / 远程桌面断开时为非活动）
+#[derive(Default)]
+pub struct SessionState {
+    inactive: AtomicBool,
+}
+
+impl SessionState {
+    /// 会话是否处于活动状态；后台监视和窗口移动在非活动时暂停
+    pub fn is_active(&self) -> bool {
+        !self.inactive.load(Ordering::Relaxed)
+    }
+
+    /// 按最新检测结果更新状态，返回是否发生变化
+    fn update(&self, active: bool) -> bool {
+        self.inactive.swap(!active, Ordering::Relaxed) == active
+    }
+}
+
+/// 返回给前端 / 随事件推送的会话状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionStatus {
+    pub active: bool,
+}
+
+/// 当前会话是否连接在本机控制台或远程桌面上；无法判断时返回 None
+#[cfg(windows)]
+pub fn current_session_active() -> Option<bool> {
+    use windows_sys::Win32::System::RemoteDesktop::{
+        WTSActive, WTSConnectState, WTSFreeMemory, WTSQuerySessionInformationW,
+        WTS_CONNECTSTATE_CLASS, WTS_CURRENT_SERVER_HANDLE, WTS_CURRENT_SESSION,
+    };
+
+    // SAFETY: 缓冲区由 WTS 分配，读取后立即释放
+    unsafe {
+        let mut buffer: *mut u16 = std::ptr::null_mut();
+        let mut len = 0u32;
+        let ok = WTSQuerySessionInformationW(
+            WTS_CURRENT_SERVER_HANDLE,
+            WTS_CURRENT_SESSION,
+            WTSConnectState,
+            &mut buffer,
+            &mut len,
+        );
+        if ok == 0 || buffer.is_null() {
+            return None;
+        }
+        let state = *buffer.cast::<WTS_CONNECTSTATE_CLASS>();
+        WTSFreeMemory(buffer.cast());
+        Some(state == WTSActive)
+    }
+}
+
+#[cfg(not(windows))]
+pub fn current_session_active() -> Option<bool> {
+    None
+}
+
+fn check(state: &SessionState) -> (SessionStatus, bool) {
+    // 无法判断时保持原状态
+    let changed = current_session_active().is_some_and(|active| state.update(active));
+    let status = SessionStatus {
+        active: state.is_active(),
+    };
+    (status, changed)
+}
+
+#[tauri::command]
+pub fn get_session_status(state: State<'_, Arc<SessionState>>) -> SessionStatus {
+    check(&state).0
+}
+
+/// 启动后台线程：定期检查会话连接状态，断开 / 重连时向主窗口推送 `session:changed`
+///
+/// 只有 Windows 能检测会话状态，其他平台不启动线程，会话始终视为活动。
+#[cfg(windows)]
+pub fn spawn_watcher<R: Runtime>(app: AppHandle<R>) {
+    let state = app.state::<Arc<SessionState>>().inner().clone();
+    let handle = app.clone();
+    supervisor::spawn(&app, "session", CHECK_INTERVAL, move || {
+        let app = handle.clone();
+        let state = Arc::clone(&state);
+        move || {
+            let (status, changed) = check(&state);
+            if changed {
+                let _ = routing::emit_routed(&app, "session:changed", status);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SessionState;
+
+    #[test]
+    fn update_should_report_only_transitions() {
+        let state = SessionState::default();
+        assert!(state.is_active());
+        assert!(!state.update(true));
+        assert!(state.update(false));
+        assert!(!state.is_active());
+        assert!(!state.update(false));
+        assert!(state.update(true));
+        assert!(state.is_active());
+    }
+}
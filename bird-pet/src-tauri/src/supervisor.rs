@@ -0,0 +1,367 @@
+//! 后台子系统监管
+//!
+//! 只监管 Rust 端由 `spawn` 启动的后台监视线程（daylight / disk_guard / git_watch / session）：
+//! 按子系统统计线程 CPU 时间并节流、崩溃后重启。前端的任务队列等 WebView 内逻辑不在监管范围内。
+//! 这些线程共享同一个堆，内存无法按子系统归属，只按整个后端进程统计，超限时放慢所有子系统。
+//! 无法读取线程 CPU 时间的平台（如 macOS）不做 CPU 节流，只在健康状况中标记为未统计。
+
+use crate::routing;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::{AppHandle, Manager, Runtime, State};
+
+/// 各子系统的 CPU 预算（占单核的比例，按统计窗口平均）
+const CPU_BUDGETS: &[(&str, f64)] = &[
+    ("daylight", 0.001),
+    ("disk_guard", 0.005),
+    ("git_watch", 0.01),
+    ("session", 0.002),
+];
+/// 未登记子系统的 CPU 预算
+const DEFAULT_CPU_BUDGET: f64 = 0.005;
+/// 后端进程常驻内存预算（256 MiB）；线程共享堆，只能按整个进程统计
+pub const MEMORY_BUDGET_BYTES: u64 = 256 * 1024 * 1024;
+/// 统计窗口：每个窗口结束时按平均占用调整节流倍数
+const WINDOW: Duration = Duration::from_secs(60);
+/// 最大节流倍数（检查间隔最多放慢到 8 倍）
+pub const MAX_THROTTLE: u32 = 8;
+/// 子系统崩溃后最多重启次数，超过后停止运行
+pub const MAX_RESTARTS: u32 = 5;
+/// 距上次崩溃持续健康运行这么久后，重启计数清零
+pub const HEALTHY_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// 子系统运行状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubsystemState {
+    Running,
+    /// 超出预算，检查间隔已放慢
+    Throttled,
+    /// 重启次数用尽，已停止
+    Stopped,
+}
+
+/// 单个子系统的健康状况（`get_subsystem_health` 返回）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubsystemHealth {
+    pub name: &'static str,
+    pub state: SubsystemState,
+    /// 启动以来累计 CPU 时间（毫秒）
+    pub cpu_ms: u64,
+    /// 上一个统计窗口的平均 CPU 占用（单核比例）
+    pub cpu_share: f64,
+    /// 本平台能否读取线程 CPU 时间；不能时 CPU 相关字段为 0 且不节流
+    pub cpu_measured: bool,
+    pub cpu_budget: f64,
+    /// 当前节流倍数，1 表示按原间隔运行
+    pub throttle: u32,
+    pub restarts: u32,
+}
+
+/// 后端整体健康状况
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupervisorHealth {
+    /// 后端进程常驻内存；读取失败时为 None
+    pub memory_bytes: Option<u64>,
+    pub memory_budget_bytes: u64,
+    pub subsystems: Vec<SubsystemHealth>,
+}
+
+/// 子系统 CPU 预算
+pub fn cpu_budget(name: &str) -> f64 {
+    CPU_BUDGETS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map_or(DEFAULT_CPU_BUDGET, |&(_, budget)| budget)
+}
+
+/// 距上次崩溃已持续健康运行 HEALTHY_PERIOD 后，重启计数清零
+pub fn decayed_restarts(restarts: u32, since_crash: Duration) -> u32 {
+    if since_crash >= HEALTHY_PERIOD {
+        0
+    } else {
+        restarts
+    }
+}
+
+/// 按窗口平均占用计算下一个节流倍数：超出预算（或进程内存超限）翻倍，
+/// 低于预算一半时减半，之间保持不变，避免在预算附近来回切换
+pub fn next_throttle(current: u32, share: f64, budget: f64, memory_over: bool) -> u32 {
+    if share > budget || memory_over {
+        (current * 2).min(MAX_THROTTLE)
+    } else if share < budget / 2.0 {
+        (current / 2).max(1)
+    } else {
+        current
+    }
+}
+
+struct Subsystem {
+    cpu_total: Duration,
+    window_cpu: Duration,
+    window_started: Instant,
+    last_share: f64,
+    throttle: u32,
+    restarts: u32,
+    last_crash: Option<Instant>,
+    stopped: bool,
+}
+
+impl Subsystem {
+    fn new(now: Instant) -> Self {
+        Self {
+            cpu_total: Duration::ZERO,
+            window_cpu: Duration::ZERO,
+            window_started: now,
+            last_share: 0.0,
+            throttle: 1,
+            restarts: 0,
+            last_crash: None,
+            stopped: false,
+        }
+    }
+}
+
+/// 后台子系统监管：统计各子系统 CPU 时间，超出预算时放慢检查间隔，崩溃时重启
+#[derive(Default)]
+pub struct Supervisor {
+    subsystems: Mutex<BTreeMap<&'static str, Subsystem>>,
+    /// 最近一次读取的进程内存（读取时间, 字节数）
+    memory: Mutex<Option<(Instant, Option<u64>)>>,
+}
+
+impl Supervisor {
+    fn register(&self, name: &'static str, now: Instant) {
+        let mut subsystems = self.subsystems.lock().expect("failed to lock supervisor");
+        subsystems.insert(name, Subsystem::new(now));
+    }
+
+    /// 记录一轮运行消耗的 CPU 时间（None 表示本平台无法统计），返回下一轮的节流倍数
+    fn record(&self, name: &'static str, cpu: Option<Duration>, now: Instant) -> u32 {
+        let mut subsystems = self.subsystems.lock().expect("failed to lock supervisor");
+        let Some(sub) = subsystems.get_mut(name) else {
+            return 1;
+        };
+        if let Some(at) = sub.last_crash {
+            sub.restarts = decayed_restarts(sub.restarts, now.saturating_duration_since(at));
+            if sub.restarts == 0 {
+                sub.last_crash = None;
+            }
+        }
+        // 墙钟时间会把阻塞等待也算成 CPU，统计不到时不节流
+        let Some(cpu) = cpu else {
+            return 1;
+        };
+        sub.cpu_total += cpu;
+        sub.window_cpu += cpu;
+        let wall = now.saturating_duration_since(sub.window_started);
+        if wall >= WINDOW {
+            sub.last_share = sub.window_cpu.as_secs_f64() / wall.as_secs_f64();
+            let memory_over = self
+                .memory_bytes(now)
+                .is_some_and(|m| m > MEMORY_BUDGET_BYTES);
+            sub.throttle =
+                next_throttle(sub.throttle, sub.last_share, cpu_budget(name), memory_over);
+            sub.window_cpu = Duration::ZERO;
+            sub.window_started = now;
+        }
+        sub.throttle
+    }
+
+    /// 记录一次崩溃；返回 false 表示重启次数已用尽，子系统应停止
+    fn restart(&self, name: &'static str, now: Instant) -> bool {
+        let mut subsystems = self.subsystems.lock().expect("failed to lock supervisor");
+        let Some(sub) = subsystems.get_mut(name) else {
+            return false;
+        };
+        sub.restarts += 1;
+        sub.last_crash = Some(now);
+        sub.stopped = sub.restarts > MAX_RESTARTS;
+        !sub.stopped
+    }
+
+    /// 后端进程常驻内存，每个统计窗口最多读取一次
+    fn memory_bytes(&self, now: Instant) -> Option<u64> {
+        let mut cached = self
+            .memory
+            .lock()
+            .expect("failed to lock supervisor memory");
+        if let Some((at, bytes)) = *cached {
+            if now.saturating_duration_since(at) < WINDOW {
+                return bytes;
+            }
+        }
+        let bytes = process_memory();
+        *cached = Some((now, bytes));
+        bytes
+    }
+
+    pub fn health(&self) -> SupervisorHealth {
+        let memory_bytes = self.memory_bytes(Instant::now());
+        let subsystems = self.subsystems.lock().expect("failed to lock supervisor");
+        SupervisorHealth {
+            memory_bytes,
+            memory_budget_bytes: MEMORY_BUDGET_BYTES,
+            subsystems: subsystems
+                .iter()
+                .map(|(&name, sub)| SubsystemHealth {
+                    name,
+                    state: if sub.stopped {
+                        SubsystemState::Stopped
+                    } else if sub.throttle > 1 {
+                        SubsystemState::Throttled
+                    } else {
+                        SubsystemState::Running
+                    },
+                    cpu_ms: sub.cpu_total.as_millis() as u64,
+                    cpu_share: sub.last_share,
+                    cpu_measured: CPU_MEASURED,
+                    cpu_budget: cpu_budget(name),
+                    throttle: sub.throttle,
+                    restarts: sub.restarts,
+                })
+                .collect(),
+        }
+    }
+}
+
+fn process_memory() -> Option<u64> {
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::new().with_memory(),
+    );
+    sys.process(pid).map(|p| p.memory())
+}
+
+/// 解析 `/proc/thread-self/stat`，返回线程累计 CPU 时间（utime + stime）
+///
+/// 第 2 个字段是可能含空格的线程名，因此从最后一个 ')' 之后开始计数；
+/// 时间单位为时钟滴答，Linux 用户态固定为每秒 100 个。
+pub fn parse_thread_stat(stat: &str) -> Option<Duration> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let mut fields = rest.split_whitespace().skip(11);
+    let utime: u64 = fields.next()?.parse().ok()?;
+    let stime: u64 = fields.next()?.parse().ok()?;
+    Some(Duration::from_millis((utime + stime) * 10))
+}
+
+/// 本平台能否读取线程 CPU 时间
+const CPU_MEASURED: bool = cfg!(any(target_os = "linux", windows));
+
+/// 当前线程累计 CPU 时间；不支持的平台返回 None（不计入预算、不节流）
+#[cfg(target_os = "linux")]
+fn thread_cpu_time() -> Option<Duration> {
+    parse_thread_stat(&std::fs::read_to_string("/proc/thread-self/stat").ok()?)
+}
+
+#[cfg(windows)]
+fn thread_cpu_time() -> Option<Duration> {
+    use windows_sys::Win32::Foundation::FILETIME;
+    use windows_sys::Win32::System::Threading::{GetCurrentThread, GetThreadTimes};
+
+    let ticks = |t: FILETIME| (u64::from(t.dwHighDateTime) << 32) | u64::from(t.dwLowDateTime);
+    let zero = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let (mut creation, mut exit, mut kernel, mut user) = (zero, zero, zero, zero);
+    // SAFETY: 当前线程伪句柄无需关闭，四个输出参数均为有效的 FILETIME
+    let ok = unsafe {
+        GetThreadTimes(
+            GetCurrentThread(),
+            &mut creation,
+            &mut exit,
+            &mut kernel,
+            &mut user,
+        )
+    };
+    // FILETIME 单位为 100 纳秒
+    (ok != 0).then(|| Duration::from_nanos((ticks(kernel) + ticks(user)) * 100))
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// 在受监管的后台线程中按 interval 循环运行子系统
+///
+/// make 创建子系统的一轮执行体（可在闭包中保存跨轮状态）。执行体 panic 时丢弃其状态、
+/// 用 make 重新创建，HEALTHY_PERIOD 内崩溃超过 MAX_RESTARTS 次后停止并向主窗口推送 `supervisor:subsystem-stopped`
+/// （负载为子系统名）；超出 CPU 预算时按节流倍数放慢间隔。
+pub fn spawn<R, F, B>(app: &AppHandle<R>, name: &'static str, interval: Duration, make: F)
+where
+    R: Runtime,
+    F: Fn() -> B + Send + 'static,
+    B: FnMut(),
+{
+    let supervisor = app.state::<Arc<Supervisor>>().inner().clone();
+    supervisor.register(name, Instant::now());
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let mut body = make();
+        loop {
+            let cpu_before = thread_cpu_time();
+            let result = panic::catch_unwind(AssertUnwindSafe(&mut body));
+            let cpu = match (cpu_before, thread_cpu_time()) {
+                (Some(before), Some(after)) => Some(after.saturating_sub(before)),
+                _ => None,
+            };
+            let throttle = supervisor.record(name, cpu, Instant::now());
+            if result.is_err() {
+                if !supervisor.restart(name, Instant::now()) {
+                    let _ = routing::emit_routed(&app, "supervisor:subsystem-stopped", name);
+                    return;
+                }
+                body = make();
+            }
+            std::thread::sleep(interval * throttle);
+        }
+    });
+}
+
+#[tauri::command]
+pub fn get_subsystem_health(supervisor: State<'_, Arc<Supervisor>>) -> SupervisorHealth {
+    supervisor.health()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decayed_restarts, next_throttle, parse_thread_stat, HEALTHY_PERIOD, MAX_THROTTLE};
+    use std::time::Duration;
+
+    #[test]
+    fn throttle_should_double_over_budget_and_halve_well_under() {
+        assert_eq!(next_throttle(1, 0.02, 0.01, false), 2);
+        assert_eq!(next_throttle(MAX_THROTTLE, 0.5, 0.01, false), MAX_THROTTLE);
+        // 预算一半到预算之间保持不变
+        assert_eq!(next_throttle(4, 0.008, 0.01, false), 4);
+        assert_eq!(next_throttle(4, 0.001, 0.01, false), 2);
+        assert_eq!(next_throttle(1, 0.0, 0.01, false), 1);
+        // 进程内存超限时即使 CPU 很低也放慢
+        assert_eq!(next_throttle(1, 0.0, 0.01, true), 2);
+    }
+
+    #[test]
+    fn restarts_should_reset_after_healthy_period() {
+        assert_eq!(decayed_restarts(4, Duration::from_secs(60)), 4);
+        assert_eq!(decayed_restarts(4, HEALTHY_PERIOD), 0);
+    }
+
+    #[test]
+    fn thread_stat_should_skip_names_with_spaces() {
+        let stat = "4242 (tokio worker) 9) S 1 4242 4242 0 -1 4194560 120 0 0 0 37 13 0 0 \
+                    20 0 12 0 1000 0 0";
+        assert_eq!(parse_thread_stat(stat), Some(Duration::from_millis(500)));
+        assert_eq!(parse_thread_stat("4242 (bird) S 1"), None);
+    }
+}
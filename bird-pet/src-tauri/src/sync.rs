@@ -0,0 +1,446 @@
+//! 多设备同步核心（端到端加密 + 冲突合并）
+//!
+//! - 加密：口令经 PBKDF2 派生主密钥；每台设备用 HKDF(主密钥, 设备 ID) 派生自己的加密密钥，
+//!   记录以 ChaCha20-Poly1305 加密后才交给任何远端。持有口令的设备可按信封中的设备 ID
+//!   派生出对应密钥解密，远端只能看到记录 ID 与密文。
+//! - 合并：记录按字段做 last-writer-wins（时间戳相同时按设备 ID 决胜，写入戳也相同时
+//!   按值的序列化结果决胜），删除以墓碑表示；合并满足交换律、结合律与幂等，
+//!   任意顺序、重复收到的数据最终一致。
+//!
+//! 范围说明：本模块只是协议核心（合并规则 + 信封加解密），多设备加密同步功能尚未完成：
+//! - 没有传输层（上传 / 拉取），也没有把回忆、宠物状态映射成 `SyncDocument` 的代码；
+//!   应用内没有任何调用方，因此整个模块在非测试构建中允许 dead_code。
+//! - 设备密钥全部由主密钥派生，只起密钥隔离作用（各设备用不同密钥加密，随机 nonce
+//!   不会跨设备撞上同一密钥），**不能**按设备吊销，也不能证明信封出自哪台设备：
+//!   持有口令即可派生任意设备的密钥。移除设备只能更换口令并重新加密全部记录。
+#![cfg_attr(not(test), allow(dead_code))]
+
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::{hkdf, pbkdf2};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+
+/// PBKDF2 迭代次数
+const PBKDF2_ITERATIONS: u32 = 210_000;
+/// HKDF salt（协议版本标识，升级协议时更换）
+const HKDF_SALT: &[u8] = b"birdpet-sync-v1";
+/// 主密钥长度
+const KEY_LEN: usize = 32;
+
+// ─── 冲突合并 ───
+
+/// 写入戳：先比时间，再比设备 ID，保证全序
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Stamp {
+    /// 写入时间（毫秒）
+    pub at: u64,
+    /// 写入设备
+    pub device: String,
+}
+
+impl Stamp {
+    pub fn new(at: u64, device: impl Into<String>) -> Self {
+        Self {
+            at,
+            device: device.into(),
+        }
+    }
+}
+
+/// 带写入戳的字段值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldValue {
+    pub value: serde_json::Value,
+    pub stamp: Stamp,
+}
+
+impl FieldValue {
+    /// 是否应胜过另一个值：先比写入戳；写入戳相同（同一设备同一毫秒写了不同值）时
+    /// 比较值的序列化结果，保证合并结果与顺序无关
+    fn wins_over(&self, other: &FieldValue) -> bool {
+        match self.stamp.cmp(&other.stamp) {
+            std::cmp::Ordering::Equal => value_key(&self.value) >= value_key(&other.value),
+            ordering => ordering.is_gt(),
+        }
+    }
+}
+
+/// 值的确定性排序键（serde_json 的对象键有序，序列化结果稳定）
+fn value_key(value: &serde_json::Value) -> String {
+    value.to_string()
+}
+
+/// 一条同步记录（如一段回忆、一项宠物状态）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub fields: BTreeMap<String, FieldValue>,
+    /// 删除墓碑；晚于墓碑的字段写入会让记录重新可见
+    pub tombstone: Option<Stamp>,
+}
+
+impl SyncRecord {
+    /// 记录当前是否可见（未删除，或删除后又有更新的写入）
+    pub fn is_visible(&self) -> bool {
+        match &self.tombstone {
+            None => !self.fields.is_empty(),
+            Some(deleted) => self.fields.values().any(|f| f.stamp > *deleted),
+        }
+    }
+
+    /// 可见字段（早于墓碑的字段视为已删除）
+    pub fn visible_fields(&self) -> BTreeMap<&str, &serde_json::Value> {
+        self.fields
+            .iter()
+            .filter(|(_, f)| match &self.tombstone {
+                Some(deleted) => f.stamp > *deleted,
+                None => true,
+            })
+            .map(|(k, f)| (k.as_str(), &f.value))
+            .collect()
+    }
+
+    /// 合并另一副本：字段逐个取较新者，墓碑取较新者
+    pub fn merge(&mut self, other: &SyncRecord) {
+        for (key, theirs) in &other.fields {
+            match self.fields.get(key) {
+                Some(ours) if ours.wins_over(theirs) => {}
+                _ => {
+                    self.fields.insert(key.clone(), theirs.clone());
+                }
+            }
+        }
+        if other.tombstone > self.tombstone {
+            self.tombstone = other.tombstone.clone();
+        }
+    }
+}
+
+/// 同步文档：记录 ID → 记录
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncDocument {
+    pub records: BTreeMap<String, SyncRecord>,
+}
+
+impl SyncDocument {
+    /// 本地写入一个字段
+    pub fn set_field(&mut self, id: &str, field: &str, value: serde_json::Value, stamp: Stamp) {
+        let update = SyncRecord {
+            fields: BTreeMap::from([(field.to_string(), FieldValue { value, stamp })]),
+            tombstone: None,
+        };
+        self.merge_record(id, &update);
+    }
+
+    /// 本地删除一条记录（写入墓碑）
+    pub fn delete(&mut self, id: &str, stamp: Stamp) {
+        let update = SyncRecord {
+            fields: BTreeMap::new(),
+            tombstone: Some(stamp),
+        };
+        self.merge_record(id, &update);
+    }
+
+    /// 合并单条远端记录
+    pub fn merge_record(&mut self, id: &str, record: &SyncRecord) {
+        self.records
+            .entry(id.to_string())
+            .or_default()
+            .merge(record);
+    }
+
+    /// 合并另一份文档
+    pub fn merge(&mut self, other: &SyncDocument) {
+        for (id, record) in &other.records {
+            self.merge_record(id, record);
+        }
+    }
+
+    /// 当前可见的记录 ID
+    pub fn visible_ids(&self) -> Vec<&str> {
+        self.records
+            .iter()
+            .filter(|(_, r)| r.is_visible())
+            .map(|(id, _)| id.as_str())
+            .collect()
+    }
+}
+
+// ─── 加密 ───
+
+/// 同步加密错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncCryptoError {
+    /// 随机数生成失败
+    Random,
+    /// 密钥派生失败
+    KeyDerivation,
+    /// 加密失败
+    Encrypt,
+    /// 解密失败（口令错误或数据被篡改）
+    Decrypt,
+    /// 信封格式错误
+    Malformed(String),
+}
+
+impl std::fmt::Display for SyncCryptoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncCryptoError::Random => write!(f, "random generator failure"),
+            SyncCryptoError::KeyDerivation => write!(f, "key derivation failure"),
+            SyncCryptoError::Encrypt => write!(f, "encryption failed"),
+            SyncCryptoError::Decrypt => write!(f, "decryption failed"),
+            SyncCryptoError::Malformed(reason) => write!(f, "malformed envelope: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SyncCryptoError {}
+
+/// 由口令派生的主密钥（仅存在于本机内存）
+pub struct MasterKey([u8; KEY_LEN]);
+
+impl MasterKey {
+    /// 由口令与账户级 salt 派生主密钥；salt 需随账户保存并在各设备间共享
+    pub fn derive(passphrase: &str, salt: &[u8]) -> Self {
+        let mut key = [0u8; KEY_LEN];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            NonZeroU32::new(PBKDF2_ITERATIONS).expect("iterations must be non-zero"),
+            salt,
+            passphrase.as_bytes(),
+            &mut key,
+        );
+        Self(key)
+    }
+
+    /// 生成新的随机 salt（首次开启同步时调用）
+    pub fn generate_salt() -> Result<[u8; 16], SyncCryptoError> {
+        let mut salt = [0u8; 16];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .map_err(|_| SyncCryptoError::Random)?;
+        Ok(salt)
+    }
+
+    /// 派生指定设备的加密密钥（仅做密钥隔离，不提供按设备吊销）
+    fn device_key(&self, device: &str) -> Result<LessSafeKey, SyncCryptoError> {
+        let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, HKDF_SALT).extract(&self.0);
+        let info = [device.as_bytes()];
+        let okm = prk
+            .expand(&info, &CHACHA20_POLY1305)
+            .map_err(|_| SyncCryptoError::KeyDerivation)?;
+        Ok(LessSafeKey::new(UnboundKey::from(okm)))
+    }
+}
+
+/// 上传到远端的加密信封
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Envelope {
+    /// 记录 ID（明文，远端据此路由/去重）
+    pub record_id: String,
+    /// 加密设备 ID（接收方据此派生密钥）
+    pub device: String,
+    /// 随机 nonce（hex）
+    pub nonce: String,
+    /// 密文 + 认证标签（hex）
+    pub ciphertext: String,
+}
+
+/// 关联数据：把记录 ID 与设备 ID 绑定进认证标签，防止信封被挪用到其他记录
+fn associated_data(record_id: &str, device: &str) -> Vec<u8> {
+    format!("{record_id}\u{0}{device}").into_bytes()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(text: &str) -> Result<Vec<u8>, SyncCryptoError> {
+    let invalid = || SyncCryptoError::Malformed("invalid hex".into());
+    let digit = |c: u8| {
+        (c as char)
+            .to_digit(16)
+            .map(|d| d as u8)
+            .ok_or_else(invalid)
+    };
+    text.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [hi, lo] => Ok((digit(*hi)? << 4) | digit(*lo)?),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+/// 用本设备密钥加密一条记录
+pub fn seal(
+    master: &MasterKey,
+    device: &str,
+    record_id: &str,
+    record: &SyncRecord,
+) -> Result<Envelope, SyncCryptoError> {
+    let key = master.device_key(device)?;
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| SyncCryptoError::Random)?;
+
+    let mut data =
+        serde_json::to_vec(record).map_err(|e| SyncCryptoError::Malformed(e.to_string()))?;
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(associated_data(record_id, device)),
+        &mut data,
+    )
+    .map_err(|_| SyncCryptoError::Encrypt)?;
+
+    Ok(Envelope {
+        record_id: record_id.to_string(),
+        device: device.to_string(),
+        nonce: to_hex(&nonce),
+        ciphertext: to_hex(&data),
+    })
+}
+
+/// 解密信封（密钥按信封中的设备 ID 派生）
+pub fn open(master: &MasterKey, envelope: &Envelope) -> Result<SyncRecord, SyncCryptoError> {
+    let key = master.device_key(&envelope.device)?;
+    let nonce: [u8; NONCE_LEN] = from_hex(&envelope.nonce)?
+        .try_into()
+        .map_err(|_| SyncCryptoError::Malformed("invalid nonce length".into()))?;
+    let mut data = from_hex(&envelope.ciphertext)?;
+
+    let plain = key
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(associated_data(&envelope.record_id, &envelope.device)),
+            &mut data,
+        )
+        .map_err(|_| SyncCryptoError::Decrypt)?;
+    serde_json::from_slice(plain).map_err(|e| SyncCryptoError::Malformed(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open, seal, MasterKey, Stamp, SyncCryptoError, SyncDocument};
+    use serde_json::json;
+
+    fn doc_a() -> SyncDocument {
+        let mut doc = SyncDocument::default();
+        doc.set_field("m1", "title", json!("第一次见面"), Stamp::new(10, "pc"));
+        doc.set_field("m1", "mood", json!("happy"), Stamp::new(12, "pc"));
+        doc.set_field("m2", "title", json!("番茄 10 个"), Stamp::new(15, "pc"));
+        doc
+    }
+
+    fn doc_b() -> SyncDocument {
+        let mut doc = SyncDocument::default();
+        doc.set_field("m1", "title", json!("初次相遇"), Stamp::new(11, "laptop"));
+        doc.set_field("m1", "mood", json!("calm"), Stamp::new(9, "laptop"));
+        doc.delete("m2", Stamp::new(20, "laptop"));
+        doc
+    }
+
+    #[test]
+    fn merge_should_pick_latest_writer_per_field() {
+        let mut doc = doc_a();
+        doc.merge(&doc_b());
+
+        let fields = doc.records["m1"].visible_fields();
+        assert_eq!(fields["title"], &json!("初次相遇"));
+        assert_eq!(fields["mood"], &json!("happy"));
+    }
+
+    #[test]
+    fn merge_should_be_commutative_and_idempotent() {
+        let mut ab = doc_a();
+        ab.merge(&doc_b());
+        let mut ba = doc_b();
+        ba.merge(&doc_a());
+        assert_eq!(ab, ba);
+
+        let snapshot = ab.clone();
+        ab.merge(&doc_b());
+        ab.merge(&doc_a());
+        assert_eq!(ab, snapshot);
+    }
+
+    #[test]
+    fn equal_timestamps_should_break_ties_by_device() {
+        let mut a = SyncDocument::default();
+        a.set_field("m", "f", json!(1), Stamp::new(5, "a"));
+        let mut b = SyncDocument::default();
+        b.set_field("m", "f", json!(2), Stamp::new(5, "b"));
+
+        a.merge(&b);
+        assert_eq!(a.records["m"].visible_fields()["f"], &json!(2));
+    }
+
+    #[test]
+    fn identical_stamps_should_break_ties_by_value() {
+        let mut a = SyncDocument::default();
+        a.set_field("m", "f", json!("x"), Stamp::new(5, "pc"));
+        let mut b = SyncDocument::default();
+        b.set_field("m", "f", json!("y"), Stamp::new(5, "pc"));
+
+        let mut ab = a.clone();
+        ab.merge(&b);
+        let mut ba = b.clone();
+        ba.merge(&a);
+        assert_eq!(ab, ba);
+        assert_eq!(ab.records["m"].visible_fields()["f"], &json!("y"));
+    }
+
+    #[test]
+    fn tombstone_should_hide_record_until_newer_write() {
+        let mut doc = doc_a();
+        doc.merge(&doc_b());
+        assert_eq!(doc.visible_ids(), vec!["m1"]);
+
+        doc.set_field("m2", "title", json!("复活"), Stamp::new(25, "pc"));
+        assert_eq!(doc.visible_ids(), vec!["m1", "m2"]);
+        assert_eq!(doc.records["m2"].visible_fields().len(), 1);
+    }
+
+    #[test]
+    fn stale_tombstone_should_not_delete_newer_fields() {
+        let mut doc = SyncDocument::default();
+        doc.set_field("m", "f", json!(1), Stamp::new(30, "pc"));
+        doc.delete("m", Stamp::new(20, "laptop"));
+        assert!(doc.records["m"].is_visible());
+    }
+
+    #[test]
+    fn sealed_record_should_round_trip_across_devices() {
+        let salt = MasterKey::generate_salt().unwrap();
+        let doc = doc_a();
+        let sender = MasterKey::derive("correct horse", &salt);
+        let envelope = seal(&sender, "pc", "m1", &doc.records["m1"]).unwrap();
+        assert!(!envelope.ciphertext.contains("第一次见面"));
+
+        // 另一台设备用同一口令派生主密钥即可解密
+        let receiver = MasterKey::derive("correct horse", &salt);
+        assert_eq!(open(&receiver, &envelope).unwrap(), doc.records["m1"]);
+    }
+
+    #[test]
+    fn open_should_reject_wrong_passphrase_or_tampering() {
+        let salt = MasterKey::generate_salt().unwrap();
+        let master = MasterKey::derive("secret", &salt);
+        let envelope = seal(&master, "pc", "m1", &doc_a().records["m1"]).unwrap();
+
+        let wrong = MasterKey::derive("guess", &salt);
+        assert_eq!(open(&wrong, &envelope), Err(SyncCryptoError::Decrypt));
+
+        // 把信封挪给另一条记录，认证失败
+        let mut moved = envelope.clone();
+        moved.record_id = "m2".into();
+        assert_eq!(open(&master, &moved), Err(SyncCryptoError::Decrypt));
+
+        let mut spoofed = envelope;
+        spoofed.device = "laptop".into();
+        assert_eq!(open(&master, &spoofed), Err(SyncCryptoError::Decrypt));
+    }
+}
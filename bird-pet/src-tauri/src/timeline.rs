@@ -0,0 +1,22 @@
+use chrono::Local;
+use memo_core::store::{EVENTS_KEY, STORE_FILE};
+use memo_core::timeline::{timeline_for_day, MemoryEvent, TimelineSegment};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+/// 获取指定本地日期的时间线
+///
+/// 读取前端记忆系统写入存储的事件（调用前应先保存记忆），当天的片段截止到当前时刻。
+/// 拼装逻辑在 memo-core，与 memo-cli 共用。
+#[tauri::command]
+pub fn get_timeline<R: Runtime>(
+    app: AppHandle<R>,
+    day: String,
+) -> Result<Vec<TimelineSegment>, String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let events: Vec<MemoryEvent> = store
+        .get(EVENTS_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default();
+    timeline_for_day(events, &day, Local::now().timestamp_millis())
+}
@@ -0,0 +1,81 @@
+use crate::routing;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime, State};
+
+/// 用户状态（由前端 StatusPublisher 推导后写入）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserStatus {
+    InMeeting,
+    Focused,
+    Away,
+    WindingDown,
+    #[default]
+    Available,
+}
+
+/// 后端持有的当前用户状态，供任意窗口查询
+#[derive(Default)]
+pub struct UserStatusState {
+    current: Mutex<UserStatus>,
+}
+
+impl UserStatusState {
+    /// 更新状态，返回是否发生变化
+    pub fn set(&self, status: UserStatus) -> bool {
+        let mut current = self.current.lock().expect("failed to lock user status");
+        if *current == status {
+            return false;
+        }
+        *current = status;
+        true
+    }
+
+    pub fn get(&self) -> UserStatus {
+        *self.current.lock().expect("failed to lock user status")
+    }
+}
+
+/// 写入用户状态（仅主窗口）；变化时向所有窗口广播 `status:changed`
+#[tauri::command]
+pub fn publish_user_status<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, UserStatusState>,
+    status: UserStatus,
+) -> Result<(), String> {
+    if state.set(status) {
+        let _ = routing::emit_routed(&app, "status:changed", status);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_user_status(state: State<'_, UserStatusState>) -> UserStatus {
+    state.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UserStatus, UserStatusState};
+
+    #[test]
+    fn default_status_should_be_available() {
+        let state = UserStatusState::default();
+        assert_eq!(state.get(), UserStatus::Available);
+    }
+
+    #[test]
+    fn set_should_report_changes_only() {
+        let state = UserStatusState::default();
+        assert!(state.set(UserStatus::Focused));
+        assert!(!state.set(UserStatus::Focused));
+        assert_eq!(state.get(), UserStatus::Focused);
+    }
+
+    #[test]
+    fn status_should_serialize_as_snake_case() {
+        let json = serde_json::to_string(&UserStatus::WindingDown).unwrap();
+        assert_eq!(json, "\"winding_down\"");
+    }
+}
@@ -0,0 +1,242 @@
+use crate::session::SessionState;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{PhysicalPosition, Runtime, State, WebviewWindow};
+
+/// 动画帧间隔（约 60fps）
+const TICK: Duration = Duration::from_millis(16);
+/// 单次动画最长时长，防止误传超大值导致线程长期占用
+const MAX_DURATION_MS: u64 = 10_000;
+
+/// 缓动曲线
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Easing {
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    #[default]
+    EaseInOutQuad,
+    EaseOutCubic,
+    EaseInOutCubic,
+}
+
+impl Easing {
+    /// 将线性进度 t（0..=1）映射为缓动后的进度
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => t * (2.0 - t),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::EaseOutCubic => {
+                let u = t - 1.0;
+                u * u * u + 1.0
+            }
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let u = 2.0 * t - 2.0;
+                    0.5 * u * u * u + 1.0
+                }
+            }
+        }
+    }
+}
+
+/// 按进度在起点与终点之间插值（物理像素）
+pub fn interpolate(from: (i32, i32), to: (i32, i32), progress: f64) -> (i32, i32) {
+    let lerp = |a: i32, b: i32| (a as f64 + (b - a) as f64 * progress).round() as i32;
+    (lerp(from.0, to.0), lerp(from.1, to.1))
+}
+
+/// 各窗口的动画代数：新动画开始或显式取消时递增，旧动画线程发现代数变化后自行退出
+#[derive(Default)]
+pub struct WindowTweens {
+    generations: Mutex<HashMap<String, u64>>,
+}
+
+impl WindowTweens {
+    /// 开始新动画，返回其代数（同时使该窗口上的旧动画失效）
+    pub fn begin(&self, label: &str) -> u64 {
+        let mut generations = self.generations.lock().expect("failed to lock window tweens");
+        let generation = generations.entry(label.to_string()).or_insert(0);
+        *generation += 1;
+        *generation
+    }
+
+    /// 指定代数是否仍是该窗口的当前动画
+    pub fn is_current(&self, label: &str, generation: u64) -> bool {
+        let generations = self.generations.lock().expect("failed to lock window tweens");
+        generations.get(label).copied() == Some(generation)
+    }
+}
+
+/// 将调用方窗口以缓动动画移动到 (x, y)（物理像素）
+///
+/// 动画在独立线程按固定节拍驱动，不依赖 webview 主线程的渲染节奏。
+/// 返回 true 表示动画完整结束，false 表示被新动画或 stop 命令打断。
+/// 用户会话断开期间（快速切换用户 / 远程桌面断开）不移动窗口，直接返回 false。
+#[tauri::command]
+pub async fn animate_window_to<R: Runtime>(
+    window: WebviewWindow<R>,
+    tweens: State<'_, Arc<WindowTweens>>,
+    session: State<'_, Arc<SessionState>>,
+    x: i32,
+    y: i32,
+    duration_ms: u64,
+    easing: Option<Easing>,
+) -> Result<bool, String> {
+    if !session.is_active() {
+        return Ok(false);
+    }
+    let start = window.outer_position().map_err(|e| e.to_string())?;
+    let tweens = Arc::clone(tweens.inner());
+    let session = Arc::clone(session.inner());
+    let label = window.label().to_string();
+    let generation = tweens.begin(&label);
+    let duration = Duration::from_millis(duration_ms.min(MAX_DURATION_MS));
+    let easing = easing.unwrap_or_default();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        run_tween(
+            &window,
+            &tweens,
+            &session,
+            &label,
+            generation,
+            (start.x, start.y),
+            (x, y),
+            duration,
+            easing,
+        )
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// 取消调用方窗口上正在进行的动画（窗口停留在当前位置）
+#[tauri::command]
+pub fn stop_window_animation<R: Runtime>(
+    window: WebviewWindow<R>,
+    tweens: State<'_, Arc<WindowTweens>>,
+) {
+    tweens.begin(window.label());
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_tween<R: Runtime>(
+    window: &WebviewWindow<R>,
+    tweens: &WindowTweens,
+    session: &SessionState,
+    label: &str,
+    generation: u64,
+    from: (i32, i32),
+    to: (i32, i32),
+    duration: Duration,
+    easing: Easing,
+) -> bool {
+    let started_at = Instant::now();
+    let mut next_tick = started_at;
+
+    loop {
+        // 会话中途断开时停在当前位置，避免按断开期间的显示器布局落点
+        if !tweens.is_current(label, generation) || !session.is_active() {
+            return false;
+        }
+
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (started_at.elapsed().as_secs_f64() / duration.as_secs_f64()).min(1.0)
+        };
+        let (px, py) = interpolate(from, to, easing.apply(progress));
+        let _ = window.set_position(PhysicalPosition::new(px, py));
+
+        if progress >= 1.0 {
+            return true;
+        }
+
+        // 按绝对节拍休眠；落后时不追帧，直接从当前时刻继续
+        next_tick += TICK;
+        let now = Instant::now();
+        if next_tick > now {
+            std::thread::sleep(next_tick - now);
+        } else {
+            next_tick = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate, Easing, WindowTweens};
+
+    const ALL_EASINGS: [Easing; 6] = [
+        Easing::Linear,
+        Easing::EaseInQuad,
+        Easing::EaseOutQuad,
+        Easing::EaseInOutQuad,
+        Easing::EaseOutCubic,
+        Easing::EaseInOutCubic,
+    ];
+
+    #[test]
+    fn easing_should_hit_both_endpoints() {
+        for easing in ALL_EASINGS {
+            assert!(easing.apply(0.0).abs() < 1e-9, "{easing:?} at 0");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-9, "{easing:?} at 1");
+        }
+    }
+
+    #[test]
+    fn easing_should_clamp_out_of_range_progress() {
+        for easing in ALL_EASINGS {
+            assert_eq!(easing.apply(-0.5), easing.apply(0.0));
+            assert_eq!(easing.apply(1.5), easing.apply(1.0));
+        }
+    }
+
+    #[test]
+    fn ease_in_out_should_be_symmetric_at_midpoint() {
+        assert!((Easing::EaseInOutQuad.apply(0.5) - 0.5).abs() < 1e-9);
+        assert!((Easing::EaseInOutCubic.apply(0.5) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interpolate_should_round_to_nearest_pixel() {
+        assert_eq!(interpolate((0, 0), (100, -50), 0.0), (0, 0));
+        assert_eq!(interpolate((0, 0), (100, -50), 0.5), (50, -25));
+        assert_eq!(interpolate((0, 0), (100, -50), 1.0), (100, -50));
+        assert_eq!(interpolate((10, 10), (11, 11), 0.6), (11, 11));
+    }
+
+    #[test]
+    fn begin_should_invalidate_previous_generation() {
+        let tweens = WindowTweens::default();
+        let first = tweens.begin("main");
+        assert!(tweens.is_current("main", first));
+
+        let second = tweens.begin("main");
+        assert!(!tweens.is_current("main", first));
+        assert!(tweens.is_current("main", second));
+    }
+
+    #[test]
+    fn generations_should_be_tracked_per_window() {
+        let tweens = WindowTweens::default();
+        let main = tweens.begin("main");
+        tweens.begin("bubble");
+        assert!(tweens.is_current("main", main));
+    }
+}
@@ -1,5 +1,7 @@
 import type { CoreModules } from '@/app/types';
 import type { DialogueScene } from '@/features/dialogue-engine';
+import { formatGitActivity } from '@/features/git-celebration';
+import { formatRecap } from '@/features/monthly-recap';
 
 export function bindBusinessEvents(core: CoreModules): void {
   core.bus.on('pet:clicked', () => {
@@ -29,6 +31,40 @@ export function bindBusinessEvents(core: CoreModules): void {
     }
   });
 
+  core.bus.on('disk:space-changed', ({ low }) => {
+    core.jobs.setPaused(low);
+    if (low) {
+      core.bubble.say({
+        text: '硬盘快满了！我先少记一点东西、暂停后台任务，等你腾出空间再恢复 💾',
+        priority: 'high',
+        duration: 8000,
+      });
+    }
+  });
+
+  core.bus.on('memory:auto-recorded', ({ message }) => {
+    core.bubble.say({ text: `📌 我记下了：${message}`, priority: 'normal', duration: 6000 });
+  });
+
+  core.bus.on('git:activity', (activity) => {
+    const pushed = activity.kind === 'push';
+    core.bubble.say({
+      text: formatGitActivity(activity),
+      priority: pushed ? 'high' : 'normal',
+      duration: 6000,
+    });
+    if (pushed) {
+      core.effects.playConfetti();
+    } else {
+      core.effects.playSparks();
+    }
+  });
+
+  core.bus.on('recap:ready', (recap) => {
+    core.bubble.say({ text: formatRecap(recap), priority: 'high', duration: 8000 });
+    core.effects.playConfetti();
+  });
+
   core.bus.on('memory:milestone', ({ message }) => {
     core.bubble.say({ text: `🏆 ${message}`, priority: 'high', duration: 6000 });
     core.effects.playConfetti();
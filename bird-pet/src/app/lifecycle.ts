@@ -39,7 +39,20 @@ export function initLifecycle(core: CoreModules, features: FeatureModules): Life
       await features.pomodoro.stop();
       features.systemMonitor.stop();
       features.contextAwareness.dispose();
+      features.morningRoutine.stop();
+      features.statusPublisher.stop();
+      features.tips.stop();
+      features.autoMemories.stop();
+      features.gitCelebration.stop();
+      features.monthlyRecap.stop();
       core.quietMode.stop();
+      core.daylight.stop();
+      core.diskGuard.stop();
+      core.sessionGuard.stop();
+      core.backup.stop();
+      core.jobs.stop();
+      core.operations.stop();
+      core.inspector.stop();
       core.memory.stop();
       core.animation.stop();
       features.memoryCard.dispose();
@@ -1,4 +1,8 @@
 import { exit } from '@tauri-apps/plugin-process';
+import { FIDELITY_SETTINGS, nextFidelity, type ActivityFidelity } from '@/core/activity-fidelity';
+import { formatProgress, isCancelledError } from '@/core/operations';
+import { openSettings } from '@/core/settings-window';
+import { formatRecap } from '@/features/monthly-recap';
 import type { MenuItem } from '@/core/menu';
 import type { CoreModules, FeatureModules } from '@/app/types';
 
@@ -49,6 +53,90 @@ export function createMenuItems(
         }
       },
     },
+    {
+      type: 'command',
+      id: 'export-report',
+      label: '📤 导出周报',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          const opId = core.operations.createId('report');
+          const path = await core.memory.exportReport('week', 'html', undefined, opId);
+          core.bubble.say({ text: `周报已保存到 ${path} 📄`, priority: 'normal', duration: 5000 });
+        } catch (e) {
+          if (isCancelledError(e)) {
+            core.bubble.say({ text: '好的，周报先不导出了', priority: 'normal', duration: 3000 });
+            return;
+          }
+          console.warn('导出周报失败:', e);
+          core.bubble.say({ text: '周报导出失败了…稍后再试试吧', priority: 'normal', duration: 3000 });
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'monthly-recap',
+      label: '🗓 月度回顾',
+      handler: async () => {
+        await core.menu.closeMenu();
+        const recap = features.monthlyRecap.getLatest();
+        if (!recap) {
+          core.bubble.say({
+            text: '第一份月度回顾会在月底生成，到时候一起看看吧 🗓',
+            priority: 'normal',
+            duration: 4000,
+          });
+          return;
+        }
+        try {
+          const path = await features.monthlyRecap.exportImage(recap);
+          core.bubble.say({
+            text: `${formatRecap(recap)}。图片已保存到 ${path} 🖼`,
+            priority: 'normal',
+            duration: 8000,
+          });
+        } catch (e) {
+          console.warn('导出月度回顾失败:', e);
+          core.bubble.say({ text: formatRecap(recap), priority: 'normal', duration: 6000 });
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'cancel-operations',
+      label: '⏹ 取消后台任务',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          const count = await core.operations.cancelAll();
+          core.bubble.say({
+            text: count > 0 ? `已请求取消 ${count} 个任务` : '现在没有进行中的任务哦',
+            priority: 'normal',
+            duration: 3000,
+          });
+        } catch (e) {
+          console.warn('取消后台任务失败:', e);
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'backup',
+      label: '💾 立即备份',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          const queued = await core.backup.backupNow();
+          core.bubble.say({
+            text: queued ? '好的，马上帮你备份数据 💾' : '备份已经在排队啦，稍等一下～',
+            priority: 'normal',
+            duration: 3000,
+          });
+        } catch (e) {
+          console.warn('加入备份任务失败:', e);
+        }
+      },
+    },
     { type: 'separator', id: 'sep-tools' },
     {
       type: 'command',
@@ -59,6 +147,97 @@ export function createMenuItems(
         await core.updater.check(true);
       },
     },
+    {
+      type: 'command',
+      id: 'consent-titles',
+      label: '🔒 窗口标题感知授权',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          // 在设置窗口中展示完整说明，由用户确认后才登记授权
+          await openSettings('consent');
+        } catch (e) {
+          console.warn('打开授权设置失败:', e);
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'activity-fidelity',
+      label: getFidelityLabel('high'),
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          const prefs = await core.storage.getPreferences();
+          const fidelity = nextFidelity(prefs.activityFidelity);
+          await core.storage.setPreferences({ activityFidelity: fidelity });
+          core.memory.setFidelity(fidelity);
+          await features.contextAwareness.start();
+          core.bubble.say({
+            text: `好的，之后我${FIDELITY_HINTS[fidelity]} 📊`,
+            priority: 'normal',
+            duration: 4000,
+          });
+        } catch (e) {
+          console.warn('切换采样精细度失败:', e);
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'auto-memories',
+      label: '📌 自动记录瞬间',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          const enabled = await features.autoMemories.toggle();
+          core.bubble.say({
+            text: enabled
+              ? '好～换新电脑、系统升级、接新屏幕这些时刻，我都会帮你记在回忆里 📌'
+              : '好的，我不再自动记录了，之前记下的还留着哦',
+            priority: 'normal',
+            duration: 4000,
+          });
+        } catch (e) {
+          console.warn('切换自动回忆失败:', e);
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'git-celebration',
+      label: '🎉 代码提交庆祝',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          const { enabled, repoCount } = await features.gitCelebration.toggle();
+          let text = '好的，不再盯着你的仓库啦';
+          if (enabled) {
+            text =
+              repoCount > 0
+                ? `好～${repoCount} 个仓库有新提交或推送时，我都会为你欢呼 🎉`
+                : '已开启！在设置里填上仓库目录，我就能为你的提交欢呼啦';
+          }
+          core.bubble.say({ text, priority: 'normal', duration: 5000 });
+          if (enabled && repoCount === 0) await openSettings('git');
+        } catch (e) {
+          console.warn('切换代码提交庆祝失败:', e);
+        }
+      },
+    },
+    {
+      type: 'command',
+      id: 'settings',
+      label: '⚙ 设置',
+      handler: async () => {
+        await core.menu.closeMenu();
+        try {
+          await openSettings();
+        } catch (e) {
+          console.warn('打开设置窗口失败:', e);
+        }
+      },
+    },
     {
       type: 'command',
       id: 'toggle-through',
@@ -80,3 +259,40 @@ export function createMenuItems(
     },
   ];
 }
+
+/** 切换精细度后的提示 */
+const FIDELITY_HINTS: Record<ActivityFidelity, string> = {
+  high: '会记下你每一次切换应用',
+  medium: '每分钟只记一次你在做什么',
+  low: '每小时只记一笔，也不再记应用名',
+};
+
+/** 采样精细度菜单项的文本（v1.3.0） */
+export function getFidelityLabel(fidelity: ActivityFidelity): string {
+  return `📊 记录精细度：${FIDELITY_SETTINGS[fidelity].label}`;
+}
+
+/** 取消后台任务菜单项的文本：有进行中的任务时显示第一个任务的进度（v1.3.0） */
+export function getCancelOperationsLabel(core: CoreModules): string {
+  const [first, ...rest] = core.operations.active;
+  if (!first) return '⏹ 取消后台任务';
+  const more = rest.length > 0 ? ` 等 ${rest.length + 1} 个` : '';
+  return `⏹ 取消后台任务（${formatProgress(first)}${more}）`;
+}
+
+/** 开发者模式下追加的检查器入口（v1.3.0） */
+export function createInspectorMenuItem(core: CoreModules): MenuItem {
+  return {
+    type: 'command',
+    id: 'inspector',
+    label: '🛠 事件检查器',
+    handler: async () => {
+      await core.menu.closeMenu();
+      try {
+        await core.inspector.open();
+      } catch (e) {
+        console.warn('打开事件检查器失败:', e);
+      }
+    },
+  };
+}
@@ -6,7 +6,11 @@ import type { CoreModules, FeatureModules } from '@/app/types';
 export async function restoreWindowPosition(storage: StorageService): Promise<void> {
   const savedPos = await storage.getWindowPosition();
   if (!savedPos) return;
+  await moveWindowIfVisible(savedPos);
+}
 
+/** 把主窗口移到 savedPos（物理像素）；该位置不在任何显示器可见区域内时跳过 */
+export async function moveWindowIfVisible(savedPos: { x: number; y: number }): Promise<void> {
   try {
     const mainWindow = getCurrentWindow();
     const { PhysicalPosition } = await import('@tauri-apps/api/dpi');
@@ -51,10 +55,23 @@ export async function syncAutoStart(storage: StorageService): Promise<void> {
 
 export async function startModules(core: CoreModules, features: FeatureModules): Promise<void> {
   core.animation.start();
+  core.memory.setFidelity((await core.storage.getPreferences()).activityFidelity);
   await core.memory.start();
   await core.quietMode.start();
+  await core.daylight.start();
+  await core.jobs.start();
+  await core.backup.start();
+  core.operations.start();
+  await core.diskGuard.start();
+  await core.sessionGuard.start();
   features.idleCare.start();
   await features.hourlyChime.start();
   await features.systemMonitor.start();
   await features.contextAwareness.start();
+  await features.morningRoutine.start();
+  features.statusPublisher.start();
+  await features.tips.start();
+  await features.autoMemories.start();
+  await features.gitCelebration.start();
+  await features.monthlyRecap.start();
 }
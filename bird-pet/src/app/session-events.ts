@@ -0,0 +1,70 @@
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import { moveWindowIfVisible } from '@/app/runtime';
+import type { CoreModules, FeatureModules } from '@/app/types';
+
+/** 会话断开时可暂停的模块 */
+interface Pausable {
+  isRunning(): boolean;
+  start(): void | Promise<void>;
+  stop(): void;
+}
+
+/**
+ * 会话断开时暂停动画、轮询与定时提醒，重连后恢复
+ *
+ * 断开期间系统可能按其他用户的显示器布局挪动窗口，重连后移回断开前的位置；
+ * 只重新 start 断开时正在运行的模块，用户关闭或从未启动的功能保持原样。
+ * 定时器按当前时间重新对齐，不会补发断开期间积压的提醒。
+ * 番茄钟例外：暂停计时，重连后从断开时的剩余时间继续。
+ */
+export function bindSessionEvents(core: CoreModules, features: FeatureModules): void {
+  let savedPosition: { x: number; y: number } | null = null;
+  let paused: Pausable[] = [];
+
+  core.bus.on('session:changed', ({ active }) => {
+    void (active ? resume() : pause());
+  });
+
+  async function pause(): Promise<void> {
+    try {
+      const pos = await getCurrentWindow().outerPosition();
+      savedPosition = { x: pos.x, y: pos.y };
+    } catch (e) {
+      console.warn('会话断开：保存窗口位置失败:', e);
+    }
+    const pausable: Pausable[] = [
+      core.animation,
+      features.contextAwareness,
+      features.systemMonitor,
+      features.autoMemories,
+      features.idleCare,
+      features.hourlyChime,
+      features.tips,
+      features.morningRoutine,
+      features.statusPublisher,
+      features.gitCelebration,
+      features.monthlyRecap,
+    ];
+    // 重复收到断开通知时保留第一次记下的列表
+    paused = [...paused, ...pausable.filter((m) => m.isRunning())];
+    for (const m of paused) m.stop();
+    features.pomodoro.pause();
+  }
+
+  async function resume(): Promise<void> {
+    if (savedPosition) {
+      await moveWindowIfVisible(savedPosition);
+      savedPosition = null;
+    }
+    features.pomodoro.resume();
+    const modules = paused;
+    paused = [];
+    for (const m of modules) {
+      try {
+        await m.start();
+      } catch (e) {
+        console.warn('会话恢复：重启功能模块失败:', e);
+      }
+    }
+  }
+}
@@ -0,0 +1,66 @@
+import { getConsents } from '@/core/consent';
+import { onSettingsSaved, serveJobs } from '@/core/settings-window';
+import type { CoreModules, FeatureModules } from '@/app/types';
+
+/**
+ * 设置窗口保存后重新应用对应区块的配置，并响应后台任务的列表 / 重试请求
+ *
+ * 设置窗口直接写偏好，这里只负责让已在运行的模块读到新值。
+ */
+export function bindSettingsEvents(core: CoreModules, features: FeatureModules): void {
+  void onSettingsSaved((section) => {
+    switch (section) {
+      case 'status':
+        // 发布目标在后端按偏好解析，重推一次当前状态即可让新目标立即生效
+        features.statusPublisher.republish();
+        break;
+      case 'location':
+        void core.daylight.refresh();
+        break;
+      case 'consent':
+        void announceTitleConsent(core);
+        break;
+      case 'alerts':
+        // 引擎在 start 时按偏好重建；未在运行（已关闭或会话暂停中）的等下次启动再读取
+        if (features.systemMonitor.isRunning()) void features.systemMonitor.start();
+        break;
+      case 'git':
+        // 后端每次检查都重新读取仓库列表，新列表下一轮即生效
+        void announceGitRepos(core, features);
+        break;
+    }
+  });
+  void serveJobs(core.jobs);
+}
+
+/** 窗口标题授权变化后让小鸟回应一句（授权在下一次采集时即生效） */
+async function announceTitleConsent(core: CoreModules): Promise<void> {
+  try {
+    const status = (await getConsents()).find((c) => c.kind === 'activity_titles');
+    core.bubble.say({
+      text: status?.granted
+        ? '谢谢信任～我会看看窗口标题，更懂你在忙什么（只在本机使用）🔓'
+        : '好的，我不会再读取窗口标题了 🔒',
+      priority: 'normal',
+      duration: 4000,
+    });
+  } catch (e) {
+    console.warn('读取授权状态失败:', e);
+  }
+}
+
+/** 仓库列表保存后提示是否已生效（庆祝需在右键菜单中开启） */
+async function announceGitRepos(core: CoreModules, features: FeatureModules): Promise<void> {
+  try {
+    const { gitWatchRepos } = await core.storage.getPreferences();
+    core.bubble.say({
+      text: features.gitCelebration.isEnabled
+        ? `记住啦，${gitWatchRepos.length} 个仓库有新提交时我会为你欢呼 🎉`
+        : '仓库记下了～在右键菜单开启「代码提交庆祝」后就会生效',
+      priority: 'normal',
+      duration: 4000,
+    });
+  } catch (e) {
+    console.warn('读取仓库设置失败:', e);
+  }
+}
@@ -1,3 +1,5 @@
+import { getConsents, needsConsentPrompt, type ConsentKind } from '@/core/consent';
+import { openSettings } from '@/core/settings-window';
 import { STORE_KEYS } from '@/core/storage';
 import { getLocalDateKey } from '@/utils';
 import type { CoreModules, FeatureModules } from '@/app/types';
@@ -34,6 +36,11 @@ export async function runDailyStartupFlow(
   } catch (e) {
     console.warn('执行问候启动流程失败:', e);
   }
+  try {
+    await promptTitleConsent(core, lastActiveDate !== '');
+  } catch (e) {
+    console.warn('提示窗口标题授权失败:', e);
+  }
   if (isFirstLaunchToday) {
     await delay(3000);
     try {
@@ -43,3 +50,31 @@ export async function runDailyStartupFlow(
     }
   }
 }
+
+/**
+ * 窗口标题感知需要授权后，提示还没对当前说明做过决定的用户（每个说明版本只提示一次）
+ *
+ * 老用户升级后标题识别会停用，直接打开设置窗口展示说明；新用户只用气泡提一句。
+ */
+async function promptTitleConsent(core: CoreModules, isExistingUser: boolean): Promise<void> {
+  const status = (await getConsents()).find((c) => c.kind === 'activity_titles');
+  if (!status) return;
+  const prompted = await core.storage.get<Partial<Record<ConsentKind, number>>>(
+    STORE_KEYS.CONSENT_PROMPTS,
+    {},
+  );
+  if (!needsConsentPrompt(status, prompted.activity_titles ?? 0)) return;
+
+  await core.storage.set(STORE_KEYS.CONSENT_PROMPTS, {
+    ...prompted,
+    activity_titles: status.textVersion,
+  });
+  core.bubble.say({
+    text: isExistingUser
+      ? '现在读取窗口标题需要你先看过说明并同意～我把说明打开了，不同意也完全没关系'
+      : '想让我更懂你在忙什么，可以在右键菜单「🔒 窗口标题感知授权」里看看说明哦',
+    priority: 'normal',
+    duration: 6000,
+  });
+  if (isExistingUser) await openSettings('consent');
+}
@@ -3,21 +3,34 @@ import type { AppEvents } from '@/types';
 import type { AnimationEngine } from '@/core/animation';
 import type { BubbleManager } from '@/core/bubble-manager';
 import type { ClickThroughManager } from '@/core/click-through';
+import type { DaylightTracker } from '@/core/daylight';
+import type { DiskGuard } from '@/core/disk-guard';
+import type { DevInspector } from '@/core/dev-inspector';
 import type { EffectsManager } from '@/core/effects';
 import type { MenuController } from '@/core/menu';
 import type { MemorySystem } from '@/core/memory';
+import type { JobQueue } from '@/core/job-queue';
+import type { DataBackup } from '@/core/data-backup';
+import type { OperationTracker } from '@/core/operations';
+import type { SessionGuard } from '@/core/session-guard';
 import type { StorageService, PetOwnerProfile } from '@/core/storage';
 import type { UpdateController } from '@/core/updater';
+import type { AutoMemoryRecorder } from '@/features/auto-memories';
 import type { ContextAwareness } from '@/features/context-awareness';
 import type { DialogueEngine } from '@/features/dialogue-engine';
+import type { GitCelebration } from '@/features/git-celebration';
 import type { GreetingManager } from '@/features/greeting';
 import type { HourlyChime } from '@/features/hourly-chime';
 import type { IdleCareScheduler } from '@/features/idle-care';
 import type { MemoryCardManager } from '@/features/memory-card';
+import type { MonthlyRecapGenerator } from '@/features/monthly-recap';
 import type { MemoryPanelManager } from '@/features/memory-panel';
+import type { MorningRoutine } from '@/features/morning-routine';
 import type { PomodoroTimer } from '@/features/pomodoro';
 import type { QuietModeManager } from '@/features/quiet-mode';
 import type { SpecialDateManager } from '@/features/special-dates';
+import type { StatusPublisher } from '@/features/status-publisher';
+import type { TipsEngine } from '@/features/tips';
 import type { SystemMonitor } from '@/features/system-monitor';
 
 export interface CoreModules {
@@ -33,6 +46,13 @@ export interface CoreModules {
   effects: EffectsManager;
   memory: MemorySystem;
   quietMode: QuietModeManager;
+  daylight: DaylightTracker;
+  diskGuard: DiskGuard;
+  sessionGuard: SessionGuard;
+  jobs: JobQueue;
+  backup: DataBackup;
+  operations: OperationTracker;
+  inspector: DevInspector;
   updater: UpdateController;
   petOwner: PetOwnerProfile;
 }
@@ -47,6 +67,12 @@ export interface FeatureModules {
   greeting: GreetingManager;
   memoryCard: MemoryCardManager;
   memoryPanel: MemoryPanelManager;
+  morningRoutine: MorningRoutine;
+  statusPublisher: StatusPublisher;
+  tips: TipsEngine;
+  autoMemories: AutoMemoryRecorder;
+  gitCelebration: GitCelebration;
+  monthlyRecap: MonthlyRecapGenerator;
 }
 
 export interface LifecycleController {
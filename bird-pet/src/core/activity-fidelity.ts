@@ -0,0 +1,137 @@
+/**
+ * 活动采样精细度（v1.3.0）
+ *
+ * 在采集端控制行为数据的细致程度，注重隐私或想省电的用户无需关闭统计：
+ * - high：每 15 秒检测前台应用，每次切换都记录（含应用名）
+ * - medium：每分钟检测一次，同一分钟内的切换合并为一条
+ * - low：每 5 分钟检测一次，每小时只保留一条记录，且不记录应用名
+ *
+ * 合并后的记录使用桶内第一次切换的真实时间，保留其来源和最后一次切换的去向，
+ * 并在 durations 中累计桶内各上下文的停留时长（毫秒），汇总时按实际时长统计。
+ */
+import type { MemoryEvent } from '../types';
+import type { AppContext } from '../features/dialogue-engine';
+
+/** 采样精细度 */
+export type ActivityFidelity = 'high' | 'medium' | 'low';
+
+/** 各精细度的采集参数 */
+export interface FidelitySettings {
+  /** 前台应用检测间隔（毫秒） */
+  pollMs: number;
+  /** 记录合并时间桶（毫秒），0 表示不合并 */
+  bucketMs: number;
+  /** 是否记录应用名 */
+  keepAppName: boolean;
+  /** 菜单中显示的名称 */
+  label: string;
+}
+
+export const FIDELITY_SETTINGS: Record<ActivityFidelity, FidelitySettings> = {
+  high: { pollMs: 15_000, bucketMs: 0, keepAppName: true, label: '精细' },
+  medium: { pollMs: 60_000, bucketMs: 60_000, keepAppName: true, label: '按分钟' },
+  low: { pollMs: 5 * 60_000, bucketMs: 60 * 60_000, keepAppName: false, label: '按小时' },
+};
+
+/** 菜单切换顺序 */
+const FIDELITY_ORDER: ActivityFidelity[] = ['high', 'medium', 'low'];
+
+/** 读取精细度参数；旧版数据缺字段时按 high 处理 */
+export function getFidelitySettings(fidelity: ActivityFidelity | undefined): FidelitySettings {
+  return FIDELITY_SETTINGS[fidelity ?? 'high'] ?? FIDELITY_SETTINGS.high;
+}
+
+/** 菜单中切换到的下一档 */
+export function nextFidelity(fidelity: ActivityFidelity): ActivityFidelity {
+  const idx = FIDELITY_ORDER.indexOf(fidelity);
+  return FIDELITY_ORDER[(idx + 1) % FIDELITY_ORDER.length];
+}
+
+/**
+ * 按精细度把一次上下文切换写入事件列表（原地修改）
+ *
+ * 与最后一条切换记录处于同一时间桶时合并，否则新增一条。记录保留桶内第一次切换的
+ * 真实时间，不会排到同一桶内更早的暂停 / 恢复事件之前；两者之间隔着暂停或恢复采集
+ * 事件时不合并，保证事件顺序与时间线一致。
+ */
+export function appendContextSwitch(
+  events: MemoryEvent[],
+  change: { from: AppContext; to: AppContext; app?: string },
+  now: number,
+  fidelity: ActivityFidelity,
+): void {
+  const settings = getFidelitySettings(fidelity);
+  const app = settings.keepAppName ? change.app : undefined;
+  const data: Record<string, unknown> = app
+    ? { from: change.from, to: change.to, app }
+    : { from: change.from, to: change.to };
+  appendToBucket(events, { type: 'context_switch', timestamp: now, data }, settings.bucketMs);
+}
+
+/**
+ * 把 before 之前的上下文切换按 low 精细度重新合并（低磁盘模式压缩旧数据，v1.3.0）
+ *
+ * 每小时只留一条且去掉应用名，桶内各上下文的停留时长累加保留；其余事件原样保留。
+ * 返回新列表。
+ */
+export function compactContextSwitches(events: MemoryEvent[], before: number): MemoryEvent[] {
+  const { bucketMs } = FIDELITY_SETTINGS.low;
+  const compacted: MemoryEvent[] = [];
+  for (const event of events) {
+    if (event.type !== 'context_switch' || event.timestamp >= before) {
+      compacted.push(event);
+      continue;
+    }
+    const data = { ...event.data };
+    delete data.app;
+    appendToBucket(compacted, { ...event, data }, bucketMs);
+  }
+  return compacted;
+}
+
+/** 写入一条切换记录：与可合并的上一条处于同一时间桶时并入，否则追加 */
+function appendToBucket(events: MemoryEvent[], incoming: MemoryEvent, bucketMs: number): void {
+  if (bucketMs > 0) {
+    const last = lastMergeableSwitch(events);
+    const bucket = (t: number) => Math.floor(t / bucketMs);
+    if (last && bucket(last.timestamp) === bucket(incoming.timestamp)) {
+      mergeSwitch(last, incoming);
+      return;
+    }
+  }
+  events.push(incoming);
+}
+
+/** 最后一条切换记录；其后出现过暂停 / 恢复采集事件时返回 undefined */
+function lastMergeableSwitch(events: MemoryEvent[]): MemoryEvent | undefined {
+  for (let i = events.length - 1; i >= 0; i--) {
+    const type = events[i].type;
+    if (type === 'context_switch') return events[i];
+    if (type === 'tracking_paused' || type === 'tracking_resumed') return undefined;
+  }
+  return undefined;
+}
+
+/**
+ * 把 incoming 并入同一桶的 target（原地修改）
+ *
+ * target.data.durations 累计桶内每个上下文的停留毫秒数（从进入到桶内下一次切换），
+ * lastAt 为桶内最后一次切换的时间；最后一次切换之后的时长仍由下一条记录界定。
+ */
+function mergeSwitch(target: MemoryEvent, incoming: MemoryEvent): void {
+  const prev = target.data ?? {};
+  const next = incoming.data ?? {};
+  const durations: Record<string, number> = { ...(prev.durations as Record<string, number>) };
+  const lastAt = typeof prev.lastAt === 'number' ? prev.lastAt : target.timestamp;
+  const current = String(prev.to);
+  durations[current] = (durations[current] ?? 0) + Math.max(0, incoming.timestamp - lastAt);
+  for (const [ctx, ms] of Object.entries((next.durations as Record<string, number>) ?? {})) {
+    durations[ctx] = (durations[ctx] ?? 0) + ms;
+  }
+  target.data = {
+    ...next,
+    from: prev.from ?? next.from,
+    durations,
+    lastAt: typeof next.lastAt === 'number' ? next.lastAt : incoming.timestamp,
+  };
+}
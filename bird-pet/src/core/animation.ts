@@ -57,6 +57,11 @@ export class AnimationEngine {
     return this.current;
   }
 
+  /** 动画循环是否在运行 */
+  isRunning(): boolean {
+    return this.running;
+  }
+
   /** 是否处于动作锁定（非循环动画播放期间） */
   isLocked(): boolean {
     return this._actionLock;
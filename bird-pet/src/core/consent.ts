@@ -0,0 +1,54 @@
+/**
+ * 数据采集授权（v1.3.0）
+ *
+ * 封装 Rust 端授权登记命令。授权记录由后端持久化并在每次采集前检查，
+ * 撤回后下一次采集立即生效；说明文字升级版本后旧授权自动失效。
+ * 同意只能在设置窗口中、展示完说明文字后由用户确认，避免记录用户没看过的说明版本。
+ */
+import { invoke } from '@tauri-apps/api/core';
+
+/** 授权类别（与 Rust 端 ConsentKind 一致） */
+export type ConsentKind = 'activity_titles' | 'clipboard' | 'microphone_level' | 'telemetry';
+
+/** 应用当前实际会采集的类别；其余类别对应的功能尚未上线，不在设置中展示 */
+export const CONSENT_KINDS_IN_USE: readonly ConsentKind[] = ['activity_titles'];
+
+/** 授权状态 */
+export interface ConsentStatus {
+  kind: ConsentKind;
+  /** 是否对当前版本的说明有效授权 */
+  granted: boolean;
+  /** 授权说明 */
+  text: string;
+  /** 授权说明版本 */
+  textVersion: number;
+  /** 最近一次同意/撤回记录 */
+  record: { granted: boolean; textVersion: number; updatedAt: number } | null;
+}
+
+/** 获取全部授权状态 */
+export function getConsents(): Promise<ConsentStatus[]> {
+  return invoke<ConsentStatus[]>('get_consents');
+}
+
+/** 同意某项采集（textVersion 为展示给用户的说明版本） */
+export function grantConsent(kind: ConsentKind, textVersion: number): Promise<void> {
+  return invoke('grant_consent', { kind, textVersion });
+}
+
+/** 撤回某项采集授权 */
+export function revokeConsent(kind: ConsentKind): Promise<void> {
+  return invoke('revoke_consent', { kind });
+}
+
+/**
+ * 是否需要提示用户查看某项授权说明
+ *
+ * 未授权且从未对当前版本的说明做过决定（从没操作过，或只同意过旧版说明），
+ * 并且还没有就当前版本提示过时返回 true。用户明确撤回过的不再打扰。
+ * @param promptedVersion 已提示过的说明版本，从未提示为 0
+ */
+export function needsConsentPrompt(status: ConsentStatus, promptedVersion: number): boolean {
+  if (status.granted || promptedVersion >= status.textVersion) return false;
+  return status.record === null || status.record.textVersion < status.textVersion;
+}
@@ -0,0 +1,89 @@
+/**
+ * 自动备份（v1.3.0）
+ *
+ * 每天第一次检查时向任务队列加入一个 backup 任务，由 Rust 端 backup_data
+ * 把 pet-state.json 复制到数据目录的 backups/ 下（保留最近 7 份）。
+ * 备份经任务队列执行：存储正在写入、磁盘不足等瞬时失败会退避重试；
+ * 重试耗尽的任务留在队列里，下次检查时自动重新排队，也可在设置窗口的「后台任务」中手动重试。
+ * 备份进度经 OperationTracker 展示，可与其他长任务一起取消；取消的备份不重试，下次检查时再排队。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import { getLocalDateKey } from '../utils';
+import type { JobQueue } from './job-queue';
+import type { MemorySystem } from './memory';
+import { isCancelledError, type OperationTracker } from './operations';
+import { STORE_KEYS, type StorageService } from './storage';
+
+/** 备份任务类型 */
+export const BACKUP_JOB = 'backup';
+/** 每日备份检查间隔（毫秒）= 1 小时，长时间不退出也能跨天备份 */
+const CHECK_INTERVAL = 60 * 60 * 1000;
+
+export class DataBackup {
+  private jobs: JobQueue;
+  private storage: StorageService;
+  private memory: MemorySystem;
+  private operations: OperationTracker;
+  private timer: number | null = null;
+
+  constructor(
+    jobs: JobQueue,
+    storage: StorageService,
+    memory: MemorySystem,
+    operations: OperationTracker,
+  ) {
+    this.jobs = jobs;
+    this.storage = storage;
+    this.memory = memory;
+    this.operations = operations;
+    jobs.register(BACKUP_JOB, () => this.run());
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+    this.timer = window.setInterval(() => void this.scheduleDaily(), CHECK_INTERVAL);
+    await this.scheduleDaily();
+  }
+
+  stop(): void {
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+  }
+
+  /**
+   * 排队一次备份：已有排队中的备份时不重复加入，重试耗尽的备份重新排队
+   * @returns 是否新加入或重新排队了备份
+   */
+  async backupNow(): Promise<boolean> {
+    const existing = this.jobs.listJobs().find((job) => job.kind === BACKUP_JOB);
+    if (!existing) {
+      await this.jobs.enqueue(BACKUP_JOB, null);
+      return true;
+    }
+    return this.jobs.retryJob(existing.id);
+  }
+
+  /** 今天还没备份成功时排队一次备份 */
+  async scheduleDaily(): Promise<void> {
+    const last = await this.storage.get<string>(STORE_KEYS.LAST_BACKUP_DATE, '');
+    if (last === getLocalDateKey()) return;
+    await this.backupNow();
+  }
+
+  // ─── 内部 ───
+
+  private async run(): Promise<void> {
+    // 先把内存中的修改落盘，备份才包含最新数据
+    await this.memory.save();
+    await this.storage.save();
+    try {
+      await invoke<string>('backup_data', { opId: this.operations.createId(BACKUP_JOB) });
+    } catch (e) {
+      if (isCancelledError(e)) return;
+      throw e;
+    }
+    await this.storage.set(STORE_KEYS.LAST_BACKUP_DATE, getLocalDateKey());
+  }
+}
@@ -0,0 +1,71 @@
+/**
+ * 昼夜相位追踪（v1.3.0）
+ *
+ * Rust 端按偏好中配置的位置计算日出日落，相位变化时向主窗口推送 `daylight:phase-changed`。
+ * 本模块把后端事件转发到内部 EventBus，供深夜降频等逻辑按真实的天黑判断，
+ * 并在 <html data-daylight> 上标记当前相位，样式据此调暗小鸟、切换菜单配色。
+ *
+ * 未配置位置时后端不推送，相位保持 null，各模块回退到固定时段。
+ * 位置在设置窗口中配置，保存后调用 refresh() 立即按新位置重新计算；
+ * 位置被清除时广播 daylight:cleared。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { EventBus } from '../events';
+import type { AppEvents, DaylightInfo, DaylightPhase } from '../types';
+import { listenToCurrentWindow } from './window-events';
+
+export class DaylightTracker {
+  private bus: EventBus<AppEvents>;
+  private current: DaylightInfo | null = null;
+  private unlisten: Promise<() => void> | null = null;
+
+  constructor(bus: EventBus<AppEvents>) {
+    this.bus = bus;
+  }
+
+  /** 当前相位；未配置位置时为 null */
+  get phase(): DaylightPhase | null {
+    return this.current?.phase ?? null;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+    this.unlisten = listenToCurrentWindow<DaylightInfo>('daylight:phase-changed', (e) =>
+      this.apply(e.payload),
+    );
+    await this.refresh();
+  }
+
+  /** 按当前配置的位置重新获取相位（位置修改后调用） */
+  async refresh(): Promise<void> {
+    try {
+      const info = await invoke<DaylightInfo | null>('get_daylight');
+      if (info) this.apply(info);
+      else this.clear();
+    } catch (e) {
+      console.warn('获取昼夜信息失败:', e);
+    }
+  }
+
+  stop(): void {
+    if (this.unlisten) {
+      void this.unlisten.then((fn) => fn()).catch(() => {});
+      this.unlisten = null;
+    }
+  }
+
+  private clear(): void {
+    if (!this.current) return;
+    this.current = null;
+    delete document.documentElement.dataset.daylight;
+    this.bus.emit('daylight:cleared');
+  }
+
+  private apply(info: DaylightInfo): void {
+    const changed = info.phase !== this.current?.phase;
+    this.current = info;
+    if (!changed) return;
+    document.documentElement.dataset.daylight = info.phase;
+    this.bus.emit('daylight:phase-changed', info);
+  }
+}
@@ -0,0 +1,243 @@
+/**
+ * 开发者事件检查器（v1.3.0）
+ *
+ * 仅在开发者模式下启用（debug 构建或设置 BIRD_PET_DEV 环境变量，由 Rust 端 is_dev_mode 判定）。
+ * 采集三类信息并每秒推送给 inspector 子窗口：
+ * - 事件：内部 EventBus 的全部事件 + 后端广播的 Tauri 事件
+ * - 命令耗时：拦截本窗口所有 invoke 调用，统计次数、耗时与失败数
+ * - 监视器状态：各模块通过 addProbe() 注册的状态读取函数，以及 Rust 端后台子系统的健康状况
+ *
+ * 非开发者模式下 start() 直接返回 false，不做任何拦截。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import { emitTo, listen } from '@tauri-apps/api/event';
+import { WebviewWindow } from '@tauri-apps/api/webviewWindow';
+import type { EventBus } from '../events';
+import type { AppEvents } from '../types';
+
+/** 事件来源 */
+export type InspectorEventSource = 'bus' | 'backend';
+
+/** 一条事件记录 */
+export interface InspectorEvent {
+  at: number;
+  source: InspectorEventSource;
+  name: string;
+  data: unknown;
+}
+
+/** 单个命令的耗时统计 */
+export interface CommandStat {
+  name: string;
+  count: number;
+  errors: number;
+  lastMs: number;
+  avgMs: number;
+  maxMs: number;
+}
+
+/** 推送给检查器窗口的快照 */
+export interface InspectorSnapshot {
+  events: InspectorEvent[];
+  commands: CommandStat[];
+  watchers: Record<string, unknown>;
+}
+
+/** 检查器窗口 label */
+const INSPECTOR_LABEL = 'inspector';
+/** 保留的最近事件条数 */
+const MAX_EVENTS = 200;
+/** 快照推送间隔（毫秒） */
+const PUSH_INTERVAL = 1000;
+/** 后端会广播的事件（Tauri 不支持通配监听，需逐个列出） */
+const BACKEND_EVENTS = [
+  'status:changed',
+  'tray:open-memories',
+  'tray:toggle-autostart',
+  'app:request-quit',
+  'op:progress',
+  'supervisor:subsystem-stopped',
+];
+/** 检查器自身推送依赖的插件命令，不计入统计，避免自我刷屏 */
+const IGNORED_COMMAND_PREFIX = 'plugin:event|';
+
+type InvokeFn = (cmd: string, args?: unknown, options?: unknown) => Promise<unknown>;
+type TauriInternals = { invoke: InvokeFn };
+
+export class DevInspector {
+  private bus: EventBus<AppEvents>;
+  private enabled = false;
+  private events: InspectorEvent[] = [];
+  private commands = new Map<string, CommandStat>();
+  private probes = new Map<string, () => unknown>();
+  private unsubscribers: (() => void)[] = [];
+  private backendUnlisteners: Promise<() => void>[] = [];
+  private originalInvoke: InvokeFn | null = null;
+  private pushTimer: number | null = null;
+  /** 最近一次读取的后台子系统健康状况（get_subsystem_health），未读取时为 null */
+  private subsystemHealth: unknown = null;
+
+  constructor(bus: EventBus<AppEvents>) {
+    this.bus = bus;
+  }
+
+  /** 是否处于开发者模式（start() 之后有效） */
+  get isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  /** 注册一个监视器状态读取函数（推送快照时调用） */
+  addProbe(name: string, probe: () => unknown): void {
+    this.probes.set(name, probe);
+  }
+
+  /**
+   * 启动：确认开发者模式后开始采集
+   * @returns 是否已启用
+   */
+  async start(): Promise<boolean> {
+    this.stop();
+    try {
+      this.enabled = await invoke<boolean>('is_dev_mode');
+    } catch {
+      this.enabled = false;
+    }
+    if (!this.enabled) return false;
+
+    this.unsubscribers.push(
+      this.bus.onAny((name, data) => this.recordEvent('bus', String(name), data)),
+    );
+    this.backendUnlisteners = BACKEND_EVENTS.map((name) =>
+      listen(name, (e) => this.recordEvent('backend', name, e.payload)),
+    );
+    this.patchInvoke();
+    this.pushTimer = window.setInterval(() => void this.push(), PUSH_INTERVAL);
+    return true;
+  }
+
+  /** 停止采集并还原 invoke */
+  stop(): void {
+    for (const unsub of this.unsubscribers) unsub();
+    this.unsubscribers = [];
+    for (const unlisten of this.backendUnlisteners) {
+      void unlisten.then((fn) => fn()).catch(() => {});
+    }
+    this.backendUnlisteners = [];
+    if (this.originalInvoke) {
+      const internals = this.getInternals();
+      if (internals) internals.invoke = this.originalInvoke;
+      this.originalInvoke = null;
+    }
+    if (this.pushTimer !== null) {
+      clearInterval(this.pushTimer);
+      this.pushTimer = null;
+    }
+  }
+
+  /** 打开或聚焦检查器窗口 */
+  async open(): Promise<void> {
+    if (!this.enabled) return;
+    const existing = await WebviewWindow.getByLabel(INSPECTOR_LABEL);
+    if (existing) {
+      await existing.show();
+      await existing.setFocus();
+      return;
+    }
+    new WebviewWindow(INSPECTOR_LABEL, {
+      url: '/inspector.html',
+      title: 'BirdPet Inspector',
+      width: 720,
+      height: 560,
+      center: true,
+      resizable: true,
+      decorations: true,
+      transparent: false,
+      focus: true,
+    });
+  }
+
+  /** 记录一条事件 */
+  recordEvent(source: InspectorEventSource, name: string, data: unknown): void {
+    this.events.push({ at: Date.now(), source, name, data });
+    if (this.events.length > MAX_EVENTS) {
+      this.events.splice(0, this.events.length - MAX_EVENTS);
+    }
+  }
+
+  /** 记录一次命令调用 */
+  recordCommand(name: string, durationMs: number, failed: boolean): void {
+    const stat = this.commands.get(name) ?? {
+      name,
+      count: 0,
+      errors: 0,
+      lastMs: 0,
+      avgMs: 0,
+      maxMs: 0,
+    };
+    stat.count++;
+    if (failed) stat.errors++;
+    stat.lastMs = durationMs;
+    stat.avgMs += (durationMs - stat.avgMs) / stat.count;
+    stat.maxMs = Math.max(stat.maxMs, durationMs);
+    this.commands.set(name, stat);
+  }
+
+  /** 当前快照；probe 抛错时记录错误信息而不是中断 */
+  getSnapshot(): InspectorSnapshot {
+    const watchers: Record<string, unknown> = {};
+    for (const [name, probe] of this.probes) {
+      try {
+        watchers[name] = probe();
+      } catch (e) {
+        watchers[name] = `probe error: ${e instanceof Error ? e.message : String(e)}`;
+      }
+    }
+    if (this.subsystemHealth !== null) watchers.subsystems = this.subsystemHealth;
+    return {
+      events: [...this.events],
+      commands: [...this.commands.values()].map((s) => ({ ...s })),
+      watchers,
+    };
+  }
+
+  // ─── 内部 ───
+
+  private getInternals(): TauriInternals | null {
+    const w = window as unknown as { __TAURI_INTERNALS__?: TauriInternals };
+    return w.__TAURI_INTERNALS__ ?? null;
+  }
+
+  /** 包装底层 invoke，所有 @tauri-apps/api 的命令调用都会经过这里 */
+  private patchInvoke(): void {
+    const internals = this.getInternals();
+    if (!internals || this.originalInvoke) return;
+
+    this.originalInvoke = internals.invoke;
+    const original = internals.invoke.bind(internals);
+    internals.invoke = async (cmd, args, options) => {
+      if (cmd.startsWith(IGNORED_COMMAND_PREFIX)) return original(cmd, args, options);
+      const startedAt = performance.now();
+      let failed = false;
+      try {
+        return await original(cmd, args, options);
+      } catch (e) {
+        failed = true;
+        throw e;
+      } finally {
+        this.recordCommand(cmd, performance.now() - startedAt, failed);
+      }
+    };
+  }
+
+  /** 检查器窗口打开时推送快照 */
+  private async push(): Promise<void> {
+    try {
+      const win = await WebviewWindow.getByLabel(INSPECTOR_LABEL);
+      if (!win) return;
+      this.subsystemHealth = await invoke('get_subsystem_health').catch((e) => String(e));
+      await emitTo(INSPECTOR_LABEL, 'inspector:snapshot', this.getSnapshot());
+    } catch {
+      // 窗口正在关闭，忽略
+    }
+  }
+}
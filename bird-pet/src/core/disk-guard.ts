@@ -0,0 +1,60 @@
+/**
+ * 低磁盘守护（v1.3.0）
+ *
+ * Rust 端定期检查数据目录所在磁盘的剩余空间，低于 1 GiB 时进入低磁盘模式
+ * （回升到 1.5 GiB 以上才退出），状态变化时向主窗口推送 `disk:space-changed`。
+ * 本模块把后端事件转发到内部 EventBus，各写入方据此切换到精简模式：
+ * - 记忆系统把今天以前的上下文切换压缩为每小时一条（不含应用名），并立即保存
+ * - 后台任务队列暂停执行，任务保留到空间恢复
+ * - 报告导出由 Rust 端直接拒绝
+ * - 调试输出（debugLog）静默，警告和错误照常输出
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { EventBus } from '../events';
+import type { AppEvents, DiskStatus } from '../types';
+import { setDebugEnabled } from '../utils';
+import { listenToCurrentWindow } from './window-events';
+
+export class DiskGuard {
+  private bus: EventBus<AppEvents>;
+  private status: DiskStatus | null = null;
+  private unlisten: Promise<() => void> | null = null;
+
+  constructor(bus: EventBus<AppEvents>) {
+    this.bus = bus;
+  }
+
+  /** 当前是否处于低磁盘模式 */
+  get isLow(): boolean {
+    return this.status?.low ?? false;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+    this.unlisten = listenToCurrentWindow<DiskStatus>('disk:space-changed', (e) =>
+      this.apply(e.payload),
+    );
+    try {
+      this.apply(await invoke<DiskStatus>('get_disk_status'));
+    } catch (e) {
+      console.warn('获取磁盘状态失败:', e);
+    }
+  }
+
+  stop(): void {
+    if (this.unlisten) {
+      void this.unlisten.then((fn) => fn()).catch(() => {});
+      this.unlisten = null;
+    }
+    setDebugEnabled(true);
+  }
+
+  private apply(status: DiskStatus): void {
+    const changed = status.low !== this.isLow;
+    this.status = status;
+    if (changed) {
+      setDebugEnabled(!status.low);
+      this.bus.emit('disk:space-changed', status);
+    }
+  }
+}
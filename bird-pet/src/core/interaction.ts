@@ -1,5 +1,5 @@
 import { getCurrentWindow } from '@tauri-apps/api/window';
-import { register, unregisterAll } from '@tauri-apps/plugin-global-shortcut';
+import { unregisterAll } from '@tauri-apps/plugin-global-shortcut';
 import { exit } from '@tauri-apps/plugin-process';
 import { CONFIG } from '../config';
 import type { AppEvents } from '../types';
@@ -7,6 +7,7 @@ import type { EventBus } from '../events';
 import type { AnimationEngine } from './animation';
 import type { ClickThroughManager } from './click-through';
 import type { MenuController } from './menu';
+import { ShortcutManager } from './shortcuts';
 import type { QuietModeManager } from '../features/quiet-mode';
 
 /** setupInteraction 所需依赖 */
@@ -106,8 +107,9 @@ export function setupInteraction(deps: InteractionDeps): () => void {
     }
   }, CONFIG.AUTO_ACTION_INTERVAL);
 
-  // ─── 全局快捷键 ───
-  const shortcutsReady = setupShortcuts(clickThrough, onQuit).catch((error) => {
+  // ─── 全局快捷键（v1.3.0 起随键盘布局重新注册） ───
+  const shortcuts = createShortcuts(clickThrough, onQuit);
+  const shortcutsReady = shortcuts.start().catch((error) => {
     console.error('注册全局快捷键失败:', error);
   });
 
@@ -121,33 +123,26 @@ export function setupInteraction(deps: InteractionDeps): () => void {
     app.removeEventListener('pointerdown', onAppPointerDown);
     window.removeEventListener('keydown', onKeydown);
     void shortcutsReady.finally(() => {
-      unregisterAll().catch((error) => {
+      shortcuts.stop().catch((error) => {
         console.warn('清理全局快捷键失败:', error);
       });
     });
   };
 }
 
-async function setupShortcuts(
+function createShortcuts(
   clickThrough: ClickThroughManager,
   onQuit?: () => Promise<void>,
-): Promise<void> {
-  await register('CommandOrControl+Shift+P', () => clickThrough.toggle());
-  try {
-    await register('CommandOrControl+Shift+Q', async () => {
-      if (onQuit) {
-        await onQuit();
-      } else {
-        await unregisterAll();
-        await exit(0);
-      }
-    });
-  } catch (error) {
-    try {
+): ShortcutManager {
+  const shortcuts = new ShortcutManager();
+  shortcuts.add('CommandOrControl+Shift+P', () => clickThrough.toggle());
+  shortcuts.add('CommandOrControl+Shift+Q', async () => {
+    if (onQuit) {
+      await onQuit();
+    } else {
       await unregisterAll();
-    } catch (cleanupError) {
-      console.warn('快捷键注册回滚失败:', cleanupError);
+      await exit(0);
     }
-    throw error;
-  }
+  });
+  return shortcuts;
 }
@@ -0,0 +1,198 @@
+/**
+ * 后台任务队列（v1.3.0）
+ *
+ * 轻量的持久化任务队列：任务入队后写入存储，按到期时间逐个执行，
+ * 失败时指数退避重试，超过最大次数标记为 failed 并保留，供 retryJob() 手动重试。
+ * 应用重启后未完成的任务会被恢复，瞬时失败不会悄悄丢失工作。
+ *
+ * 各模块通过 register(kind, handler) 注册处理器；
+ * 处理器尚未注册的任务保持 pending，直到处理器出现。
+ *
+ * v1.3.0: 低磁盘模式下通过 setPaused(true) 暂停执行，任务保留到空间恢复。
+ */
+import { STORE_KEYS, type StorageService } from './storage';
+
+/** 任务状态 */
+export type JobStatus = 'pending' | 'running' | 'failed';
+
+/** 队列中的任务 */
+export interface Job {
+  id: string;
+  /** 任务类型，对应注册的处理器 */
+  kind: string;
+  /** 任务参数（需可 JSON 序列化） */
+  payload: unknown;
+  status: JobStatus;
+  /** 已执行次数 */
+  attempts: number;
+  /** 最大执行次数，用尽后标记为 failed */
+  maxAttempts: number;
+  /** 下次可执行的时间戳 */
+  nextRunAt: number;
+  /** 最近一次失败原因 */
+  lastError: string | null;
+  createdAt: number;
+}
+
+/** 任务处理器：resolve 视为成功，reject 视为失败 */
+export type JobHandler = (payload: unknown) => Promise<void>;
+
+/** 调度检查间隔（毫秒）= 15 秒 */
+const TICK_INTERVAL = 15_000;
+/** 首次重试延迟（毫秒）= 30 秒，之后逐次翻倍 */
+const BASE_RETRY_DELAY = 30_000;
+/** 重试延迟上限（毫秒）= 30 分钟 */
+const MAX_RETRY_DELAY = 30 * 60 * 1000;
+/** 默认最大执行次数 */
+const DEFAULT_MAX_ATTEMPTS = 5;
+
+/** 第 attempts 次失败后的重试延迟 */
+export function getRetryDelay(attempts: number): number {
+  return Math.min(BASE_RETRY_DELAY * 2 ** Math.max(0, attempts - 1), MAX_RETRY_DELAY);
+}
+
+export class JobQueue {
+  private storage: StorageService;
+  private handlers = new Map<string, JobHandler>();
+  private jobs: Job[] = [];
+  private timer: number | null = null;
+  /** 当前 tick 的执行链，保证同一时刻只执行一个任务 */
+  private running: Promise<void> | null = null;
+  private seq = 0;
+  private paused = false;
+
+  constructor(storage: StorageService) {
+    this.storage = storage;
+  }
+
+  /** 注册任务处理器 */
+  register(kind: string, handler: JobHandler): void {
+    this.handlers.set(kind, handler);
+  }
+
+  /** 启动：恢复持久化的任务并开始调度 */
+  async start(): Promise<void> {
+    this.stop();
+
+    const stored = await this.storage.get<Job[]>(STORE_KEYS.JOB_QUEUE, []);
+    // 上次退出时正在执行的任务视为中断，重新排队
+    this.jobs = stored.map((job) =>
+      job.status === 'running' ? { ...job, status: 'pending' as const } : job,
+    );
+
+    this.timer = window.setInterval(() => void this.tick(), TICK_INTERVAL);
+    void this.tick();
+  }
+
+  /** 停止调度（正在执行的任务会跑完） */
+  stop(): void {
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+  }
+
+  /** 入队一个任务并立即尝试执行 */
+  async enqueue(
+    kind: string,
+    payload: unknown,
+    options: { maxAttempts?: number } = {},
+  ): Promise<Job> {
+    const now = Date.now();
+    const job: Job = {
+      id: `${now.toString(36)}-${(this.seq++).toString(36)}`,
+      kind,
+      payload,
+      status: 'pending',
+      attempts: 0,
+      maxAttempts: Math.max(1, options.maxAttempts ?? DEFAULT_MAX_ATTEMPTS),
+      nextRunAt: now,
+      lastError: null,
+      createdAt: now,
+    };
+    this.jobs.push(job);
+    await this.persist();
+    if (this.timer !== null) void this.tick();
+    return job;
+  }
+
+  /** 暂停/恢复执行（不影响入队）；恢复时立即补跑到期任务 */
+  setPaused(paused: boolean): void {
+    this.paused = paused;
+    if (!paused && this.timer !== null) void this.tick();
+  }
+
+  /** 列出队列中的任务（pending / running / failed） */
+  listJobs(): readonly Job[] {
+    return this.jobs.map((job) => ({ ...job }));
+  }
+
+  /**
+   * 手动重试一个失败的任务（重置执行次数并立即排队）
+   * @returns 是否找到可重试的任务
+   */
+  async retryJob(id: string): Promise<boolean> {
+    const job = this.jobs.find((j) => j.id === id);
+    if (!job || job.status !== 'failed') return false;
+
+    job.status = 'pending';
+    job.attempts = 0;
+    job.nextRunAt = Date.now();
+    await this.persist();
+    if (this.timer !== null) void this.tick();
+    return true;
+  }
+
+  /** 执行所有已到期的任务（串行）；已有执行链时复用，避免并发 */
+  tick(): Promise<void> {
+    if (!this.running) {
+      this.running = this.drain().finally(() => {
+        this.running = null;
+      });
+    }
+    return this.running;
+  }
+
+  // ─── 内部 ───
+
+  private async drain(): Promise<void> {
+    for (;;) {
+      if (this.paused) return;
+      const now = Date.now();
+      const job = this.jobs.find(
+        (j) => j.status === 'pending' && j.nextRunAt <= now && this.handlers.has(j.kind),
+      );
+      if (!job) return;
+      await this.runJob(job, this.handlers.get(job.kind)!);
+    }
+  }
+
+  private async runJob(job: Job, handler: JobHandler): Promise<void> {
+    job.status = 'running';
+    job.attempts++;
+    await this.persist();
+
+    try {
+      await handler(job.payload);
+      this.jobs = this.jobs.filter((j) => j !== job);
+    } catch (err) {
+      job.lastError = err instanceof Error ? err.message : String(err);
+      if (job.attempts >= job.maxAttempts) {
+        job.status = 'failed';
+        console.warn(`任务 ${job.kind}(${job.id}) 重试耗尽:`, err);
+      } else {
+        job.status = 'pending';
+        job.nextRunAt = Date.now() + getRetryDelay(job.attempts);
+      }
+    }
+    await this.persist();
+  }
+
+  private async persist(): Promise<void> {
+    try {
+      await this.storage.set(STORE_KEYS.JOB_QUEUE, this.jobs);
+    } catch (e) {
+      console.warn('任务队列持久化失败:', e);
+    }
+  }
+}
@@ -8,10 +8,26 @@
  *
  * 隐私：所有数据仅存储在本地 pet-state.json，不会上传。
  */
+import { invoke } from '@tauri-apps/api/core';
 import type { EventBus } from '../events';
-import type { AppEvents, MemoryEvent, DailySummary, UserProfile, MemorySnapshot } from '../types';
+import type {
+  AppEvents,
+  MemoryEvent,
+  DailySummary,
+  UserProfile,
+  MemorySnapshot,
+  TimelineSegment,
+  ReportPeriod,
+  ReportFormat,
+} from '../types';
 import type { AppContext } from '../features/dialogue-engine';
 import { StorageService, STORE_KEYS } from './storage';
+import { DEFAULT_TRAITS, evolveTraits, type PetTraits } from './traits';
+import {
+  appendContextSwitch,
+  compactContextSwitches,
+  type ActivityFidelity,
+} from './activity-fidelity';
 import { AFFINITY_THRESHOLDS } from '../constants';
 import { getDatesBetween, getLocalDateKey } from '../utils';
 
@@ -31,8 +47,13 @@ export class MemorySystem {
   private storage: StorageService;
   private events: MemoryEvent[] = [];
   private profile: UserProfile = { ...DEFAULT_PROFILE };
+  private traits: PetTraits = { ...DEFAULT_TRAITS };
   private unsubscribers: (() => void)[] = [];
   private startupInsightTimer: number | null = null;
+  /** 低磁盘模式（v1.3.0） */
+  private lowDiskMode = false;
+  /** 上下文切换的采样精细度（v1.3.0） */
+  private fidelity: ActivityFidelity = 'high';
 
   constructor(bus: EventBus<AppEvents>, storage: StorageService) {
     this.bus = bus;
@@ -48,6 +69,9 @@ export class MemorySystem {
     this.profile = await this.storage.get<UserProfile>(STORE_KEYS.USER_PROFILE, {
       ...DEFAULT_PROFILE,
     });
+    this.traits = await this.storage.get<PetTraits>(STORE_KEYS.PET_TRAITS, {
+      ...DEFAULT_TRAITS,
+    });
 
     // 执行日终汇总（检查是否跨天）
     this.summarizeDay();
@@ -63,16 +87,25 @@ export class MemorySystem {
       this.bus.on('pet:dragged', () =>
         this.recordEvent({ type: 'interaction', timestamp: Date.now() }),
       ),
-      this.bus.on('context:changed', ({ from, to }) =>
-        this.recordEvent({
-          type: 'context_switch',
-          timestamp: Date.now(),
-          data: { from, to },
-        }),
+      this.bus.on('context:changed', ({ from, to, app }) =>
+        appendContextSwitch(this.events, { from, to, app }, Date.now(), this.fidelity),
       ),
       this.bus.on('pomodoro:break', () =>
         this.recordEvent({ type: 'pomodoro_complete', timestamp: Date.now() }),
       ),
+      this.bus.on('tracking:paused', ({ reason }) =>
+        this.recordEvent({ type: 'tracking_paused', timestamp: Date.now(), data: { reason } }),
+      ),
+      this.bus.on('tracking:resumed', () =>
+        this.recordEvent({ type: 'tracking_resumed', timestamp: Date.now() }),
+      ),
+      this.bus.on('disk:space-changed', ({ low }) => {
+        this.lowDiskMode = low;
+        // 进入低磁盘模式时立即压缩存储
+        if (low) {
+          this.save().catch((e) => console.warn('低磁盘模式压缩记忆失败:', e));
+        }
+      }),
     );
   }
 
@@ -81,6 +114,7 @@ export class MemorySystem {
     this.pruneOldEvents();
     await this.storage.set(STORE_KEYS.MEMORY_EVENTS, this.events);
     await this.storage.set(STORE_KEYS.USER_PROFILE, this.profile);
+    await this.storage.set(STORE_KEYS.PET_TRAITS, this.traits);
   }
 
   /** 停止：解除事件监听 */
@@ -95,6 +129,11 @@ export class MemorySystem {
     }
   }
 
+  /** 设置上下文切换的采样精细度（v1.3.0），只影响之后的记录 */
+  setFidelity(fidelity: ActivityFidelity): void {
+    this.fidelity = fidelity;
+  }
+
   // ─── 模式分析 API ───
 
   /**
@@ -186,9 +225,50 @@ export class MemorySystem {
       dominantApp: this.getDominantApp(),
       streak: this.getStreak(),
       workloadTrend: this.getWorkloadTrend(),
+      trust: this.traits.trust,
+      familiarity: this.traits.familiarity,
     };
   }
 
+  /**
+   * 获取指定日期的时间线（v1.3.0）
+   *
+   * 先把内存中的事件写入存储，再由 Rust 端拼装活动/闲置/专注/休息片段。
+   * @param day 本地日期 YYYY-MM-DD，默认今天
+   */
+  async getTimeline(day = getLocalDateKey()): Promise<TimelineSegment[]> {
+    await this.save();
+    return invoke<TimelineSegment[]>('get_timeline', { day });
+  }
+
+  /**
+   * 导出报告（v1.3.0）
+   *
+   * 先保存记忆，再由 Rust 端渲染为独立 HTML 文件。
+   * @param path 目标文件路径，缺省时写入系统下载目录；只能位于下载目录或应用数据目录下
+   * @param opId 操作 id，用于跟踪进度和取消（见 OperationTracker）
+   * @returns 实际写入的文件路径
+   */
+  async exportReport(
+    period: ReportPeriod = 'week',
+    format: ReportFormat = 'html',
+    path?: string,
+    opId?: string,
+  ): Promise<string> {
+    await this.save();
+    return invoke<string>('render_report', {
+      period,
+      format,
+      path: path ?? null,
+      opId: opId ?? null,
+    });
+  }
+
+  /** 获取长期性格特质（只读，v1.3.0） */
+  getTraits(): Readonly<PetTraits> {
+    return this.traits;
+  }
+
   /** 获取用户画像（只读） */
   getProfile(): Readonly<UserProfile> {
     return this.profile;
@@ -270,10 +350,14 @@ export class MemorySystem {
           const d = getLocalDateKey(new Date(e.timestamp));
           return d === date;
         });
-        if (dayEvents.length > 0) {
-          const summary = this.buildDailySummary(date, dayEvents);
+        const summary = dayEvents.length > 0 ? this.buildDailySummary(date, dayEvents) : null;
+        if (summary) {
           this.profile.dailySummaries.push(summary);
         }
+        // v1.3.0: 每个过去的日子（包括没见面的日子）都让性格特质演化一步
+        if (date > this.traits.updatedDate) {
+          this.traits = evolveTraits(this.traits, date, summary);
+        }
       }
 
       // 保持最多 ROLLING_WINDOW_DAYS 条
@@ -304,12 +388,17 @@ export class MemorySystem {
     const interactions = events.filter((e) => e.type === 'interaction').length;
     const pomodoros = events.filter((e) => e.type === 'pomodoro_complete').length;
 
-    // 统计上下文持续时长（简化：按切换次数估算每段 15 分钟）
+    // 统计上下文持续时长（简化：按切换次数估算每段 15 分钟；
+    // 按分钟 / 小时合并的记录另外计入桶内各上下文的实际停留时长）
     const contextDurations: Partial<Record<AppContext, number>> = {};
     const contextSwitches = events.filter((e) => e.type === 'context_switch' && e.data?.to);
     for (const ev of contextSwitches) {
       const ctx = ev.data!.to as AppContext;
       contextDurations[ctx] = (contextDurations[ctx] ?? 0) + 15;
+      const merged = (ev.data!.durations ?? {}) as Partial<Record<AppContext, number>>;
+      for (const [inner, ms] of Object.entries(merged) as [AppContext, number][]) {
+        contextDurations[inner] = (contextDurations[inner] ?? 0) + Math.round(ms / 60_000);
+      }
     }
 
     // 确定主要上下文
@@ -332,9 +421,14 @@ export class MemorySystem {
     };
   }
 
-  /** 清理超过 7 天的原始事件 */
+  /** 清理超过 7 天的原始事件；低磁盘模式下把今天以前的上下文切换压缩为每小时一条 */
   private pruneOldEvents(): void {
     const cutoff = Date.now() - ROLLING_WINDOW_DAYS * 24 * 60 * 60 * 1000;
     this.events = this.events.filter((e) => e.timestamp >= cutoff);
+    if (this.lowDiskMode) {
+      const todayStart = new Date();
+      todayStart.setHours(0, 0, 0, 0);
+      this.events = compactContextSwitches(this.events, todayStart.getTime());
+    }
   }
 }
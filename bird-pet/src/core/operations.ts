@@ -0,0 +1,92 @@
+/**
+ * 长任务进度与取消（v1.3.0）
+ *
+ * 导出等耗时命令统一接受一个操作 id（opId），执行过程中由 Rust 端广播 `op:progress`，
+ * 可随时通过 `cancel_operation(id)` 请求取消，命令随后以 OPERATION_CANCELLED 错误结束。
+ * 本模块把进度事件转发到内部 EventBus，并维护进行中的操作列表，供菜单统一展示与取消。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import type { EventBus } from '../events';
+import type { AppEvents, OperationProgress } from '../types';
+
+/** 操作被取消时命令返回的错误信息（与 Rust 端 operations::CANCELLED 一致） */
+export const OPERATION_CANCELLED = '操作已取消';
+
+/** 各类操作的展示名 */
+const KIND_LABELS: Record<string, string> = {
+  report: '导出报告',
+  backup: '数据备份',
+};
+
+/** 命令失败是否因为被取消 */
+export function isCancelledError(error: unknown): boolean {
+  return String(error) === OPERATION_CANCELLED;
+}
+
+/** 进度的展示文本，如「导出报告 2/3」 */
+export function formatProgress(progress: OperationProgress): string {
+  const label = KIND_LABELS[progress.kind] ?? progress.kind;
+  return progress.total > 0 ? `${label} ${progress.done}/${progress.total}` : label;
+}
+
+export class OperationTracker {
+  private bus: EventBus<AppEvents>;
+  private running = new Map<string, OperationProgress>();
+  private unlisten: Promise<() => void> | null = null;
+  private seq = 0;
+
+  constructor(bus: EventBus<AppEvents>) {
+    this.bus = bus;
+  }
+
+  /** 进行中的操作 */
+  get active(): OperationProgress[] {
+    return [...this.running.values()];
+  }
+
+  start(): void {
+    this.stop();
+    this.unlisten = listen<OperationProgress>('op:progress', (e) => this.apply(e.payload));
+  }
+
+  stop(): void {
+    if (this.unlisten) {
+      void this.unlisten.then((fn) => fn()).catch(() => {});
+      this.unlisten = null;
+    }
+  }
+
+  /** 生成新的操作 id（调用命令前生成，以便命令返回前即可取消） */
+  createId(kind: string): string {
+    this.seq++;
+    return `${kind}-${Date.now().toString(36)}-${this.seq}`;
+  }
+
+  /**
+   * 请求取消一个操作
+   * @returns 操作仍在进行且已收到取消请求
+   */
+  async cancel(id: string): Promise<boolean> {
+    return invoke<boolean>('cancel_operation', { id });
+  }
+
+  /**
+   * 取消全部进行中的操作
+   * @returns 收到取消请求的操作数
+   */
+  async cancelAll(): Promise<number> {
+    const results = await Promise.all(this.active.map((op) => this.cancel(op.id)));
+    return results.filter(Boolean).length;
+  }
+
+  /** 处理一条进度事件（也供测试直接调用） */
+  apply(progress: OperationProgress): void {
+    if (progress.state === 'running') {
+      this.running.set(progress.id, progress);
+    } else {
+      this.running.delete(progress.id);
+    }
+    this.bus.emit('op:progress', progress);
+  }
+}
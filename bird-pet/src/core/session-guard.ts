@@ -0,0 +1,51 @@
+/**
+ * 用户会话守护（v1.3.0）
+ *
+ * Rust 端定期检查当前会话的连接状态，快速切换到其他用户或远程桌面断开时视为非活动，
+ * 状态变化时向主窗口推送 `session:changed`。后端在非活动期间暂停仓库监视与窗口动画；
+ * 本模块把事件转发到内部 EventBus，由前端暂停定时器类功能并在重连后恢复窗口位置。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { EventBus } from '../events';
+import type { AppEvents, SessionStatus } from '../types';
+import { listenToCurrentWindow } from './window-events';
+
+export class SessionGuard {
+  private bus: EventBus<AppEvents>;
+  private active = true;
+  private unlisten: Promise<() => void> | null = null;
+
+  constructor(bus: EventBus<AppEvents>) {
+    this.bus = bus;
+  }
+
+  /** 当前会话是否处于活动状态 */
+  get isActive(): boolean {
+    return this.active;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+    this.unlisten = listenToCurrentWindow<SessionStatus>('session:changed', (e) =>
+      this.apply(e.payload),
+    );
+    try {
+      this.apply(await invoke<SessionStatus>('get_session_status'));
+    } catch (e) {
+      console.warn('获取会话状态失败:', e);
+    }
+  }
+
+  stop(): void {
+    if (this.unlisten) {
+      void this.unlisten.then((fn) => fn()).catch(() => {});
+      this.unlisten = null;
+    }
+  }
+
+  private apply(status: SessionStatus): void {
+    if (status.active === this.active) return;
+    this.active = status.active;
+    this.bus.emit('session:changed', status);
+  }
+}
@@ -0,0 +1,97 @@
+/**
+ * 设置窗口（v1.3.0）
+ *
+ * 需要填写文本的配置（状态同步目标等）统一放在独立的设置窗口里，
+ * 菜单只保留开关类操作。设置窗口直接读写 pet-state.json 中的偏好，
+ * 保存后向主窗口发送 settings:saved，主窗口按区块重新应用配置。
+ *
+ * 后台任务队列运行在主窗口，设置窗口通过 jobs:list / jobs:retry 请求列表与重试，
+ * 主窗口以 jobs:snapshot 回传当前任务。
+ */
+import { emitTo, listen } from '@tauri-apps/api/event';
+import { WebviewWindow } from '@tauri-apps/api/webviewWindow';
+import type { Job } from './job-queue';
+
+/** 设置区块 */
+export type SettingsSection = 'status' | 'consent' | 'location' | 'git' | 'alerts';
+
+/** 设置窗口 label */
+const SETTINGS_LABEL = 'settings';
+/** 保存通知事件 */
+const SAVED_EVENT = 'settings:saved';
+/** 已打开的设置窗口滚动到指定区块 */
+const FOCUS_EVENT = 'settings:focus';
+/** 任务列表请求 / 重试请求 / 任务快照事件 */
+const JOBS_LIST_EVENT = 'jobs:list';
+const JOBS_RETRY_EVENT = 'jobs:retry';
+const JOBS_SNAPSHOT_EVENT = 'jobs:snapshot';
+
+/**
+ * 打开或聚焦设置窗口
+ * @param section 打开后滚动到的区块
+ */
+export async function openSettings(section?: SettingsSection): Promise<void> {
+  const existing = await WebviewWindow.getByLabel(SETTINGS_LABEL);
+  if (existing) {
+    await existing.show();
+    await existing.setFocus();
+    if (section) await emitTo(SETTINGS_LABEL, FOCUS_EVENT, { section });
+    return;
+  }
+  new WebviewWindow(SETTINGS_LABEL, {
+    url: section ? `/settings.html#${section}` : '/settings.html',
+    title: '小鸟设置',
+    width: 440,
+    height: 560,
+    center: true,
+    resizable: true,
+    decorations: true,
+    transparent: false,
+    focus: true,
+  });
+}
+
+/** 设置窗口保存某个区块后通知主窗口 */
+export function notifySettingsSaved(section: SettingsSection): Promise<void> {
+  return emitTo('main', SAVED_EVENT, { section });
+}
+
+/** 设置窗口监听滚动到区块的请求 */
+export function onSettingsFocus(handler: (section: SettingsSection) => void): Promise<() => void> {
+  return listen<{ section: SettingsSection }>(FOCUS_EVENT, (e) => handler(e.payload.section));
+}
+
+/** 主窗口监听设置保存 */
+export function onSettingsSaved(handler: (section: SettingsSection) => void): Promise<() => void> {
+  return listen<{ section: SettingsSection }>(SAVED_EVENT, (e) => handler(e.payload.section));
+}
+
+/** 设置窗口请求任务列表（主窗口以 jobs:snapshot 回复） */
+export function listJobs(): Promise<void> {
+  return emitTo('main', JOBS_LIST_EVENT, {});
+}
+
+/** 设置窗口请求重试一个失败的任务（主窗口重试后回复最新快照） */
+export function retryJob(id: string): Promise<void> {
+  return emitTo('main', JOBS_RETRY_EVENT, { id });
+}
+
+/** 设置窗口接收任务快照 */
+export function onJobsSnapshot(handler: (jobs: Job[]) => void): Promise<() => void> {
+  return listen<Job[]>(JOBS_SNAPSHOT_EVENT, (e) => handler(e.payload));
+}
+
+/** 主窗口响应任务列表 / 重试请求 */
+export function serveJobs(jobs: {
+  listJobs(): readonly Job[];
+  retryJob(id: string): Promise<boolean>;
+}): Promise<(() => void)[]> {
+  const reply = () => emitTo(SETTINGS_LABEL, JOBS_SNAPSHOT_EVENT, jobs.listJobs());
+  return Promise.all([
+    listen(JOBS_LIST_EVENT, () => void reply()),
+    listen<{ id: string }>(JOBS_RETRY_EVENT, async (e) => {
+      await jobs.retryJob(e.payload.id);
+      await reply();
+    }),
+  ]);
+}
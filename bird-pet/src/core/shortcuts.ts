@@ -0,0 +1,220 @@
+/**
+ * 全局快捷键管理（v1.3.0）
+ *
+ * 全局快捷键按物理按键注册，而用户看到的快捷键是按键上的字符：
+ * 切换到 AZERTY 等布局后，"Ctrl+Shift+Q" 对应的物理键会变。
+ * ShortcutManager 通过 navigator.keyboard.getLayoutMap() 把快捷键里的字符
+ * 解析为当前布局下产生该字符的物理键（如 KeyA），并在布局变化时重新注册。
+ *
+ * 中文输入法的中文标点只改变输入结果，不改变布局映射，
+ * 按物理键注册后 "Ctrl+Shift+," 在中英文标点间切换也不会失效。
+ * 当前布局中找不到的字符会打印警告，并回退到美式布局的位置。
+ *
+ * 不支持 Keyboard API 的平台（如 macOS WebView）按原样注册。
+ */
+import { register, unregister, type ShortcutEvent } from '@tauri-apps/plugin-global-shortcut';
+
+/** 物理键 code → 该键在当前布局下产生的字符 */
+export type KeyboardLayout = Map<string, string>;
+
+/** 读取当前布局；不支持时返回 null */
+export type LayoutReader = () => Promise<KeyboardLayout | null>;
+
+/** 单个快捷键的解析结果 */
+export interface ResolvedAccelerator {
+  /** 实际注册的快捷键 */
+  accelerator: string;
+  /** 是否在当前布局中找到了对应按键 */
+  mapped: boolean;
+}
+
+interface ShortcutBinding {
+  accelerator: string;
+  handler: () => void | Promise<void>;
+  /** 当前已注册的解析结果（未注册为 null） */
+  registered: string | null;
+}
+
+/** 布局变化检查间隔（毫秒） */
+const LAYOUT_CHECK_INTERVAL = 5000;
+
+/** 美式布局下标点字符对应的物理键，用于回退 */
+const US_PUNCTUATION_CODES: Record<string, string> = {
+  '-': 'Minus',
+  '=': 'Equal',
+  '[': 'BracketLeft',
+  ']': 'BracketRight',
+  '\\': 'Backslash',
+  ';': 'Semicolon',
+  "'": 'Quote',
+  ',': 'Comma',
+  '.': 'Period',
+  '/': 'Slash',
+  '`': 'Backquote',
+};
+
+/** 美式布局下字符对应的物理键（无法对应时返回 null） */
+function usCodeFor(char: string): string | null {
+  if (/^[a-z]$/.test(char)) return `Key${char.toUpperCase()}`;
+  if (/^[0-9]$/.test(char)) return `Digit${char}`;
+  return US_PUNCTUATION_CODES[char] ?? null;
+}
+
+/** 从 navigator.keyboard 读取布局映射 */
+export const readNavigatorLayout: LayoutReader = async () => {
+  const keyboard = (
+    navigator as Navigator & {
+      keyboard?: { getLayoutMap?: () => Promise<Iterable<[string, string]>> };
+    }
+  ).keyboard;
+  if (!keyboard?.getLayoutMap) return null;
+  try {
+    return new Map(await keyboard.getLayoutMap());
+  } catch {
+    return null;
+  }
+};
+
+/**
+ * 将快捷键中的字符键解析为当前布局下的物理键
+ *
+ * 只处理单字符的按键（字母、数字、标点），Escape / F1 / Space 等命名键与布局无关，原样保留。
+ */
+export function resolveAccelerator(
+  accelerator: string,
+  layout: KeyboardLayout | null,
+): ResolvedAccelerator {
+  const parts = accelerator.split('+');
+  const key = parts[parts.length - 1];
+  if (!layout || key.length !== 1) return { accelerator, mapped: true };
+
+  const char = key.toLowerCase();
+  let code: string | null = null;
+  for (const [physical, produced] of layout) {
+    if (produced.toLowerCase() === char) {
+      code = physical;
+      break;
+    }
+  }
+  const mapped = code !== null;
+  if (code === null) code = usCodeFor(char);
+  if (code === null) return { accelerator, mapped: false };
+
+  parts[parts.length - 1] = code;
+  return { accelerator: parts.join('+'), mapped };
+}
+
+/** 布局签名，用于判断布局是否变化 */
+function layoutSignature(layout: KeyboardLayout | null): string {
+  if (!layout) return '';
+  return [...layout.entries()]
+    .sort(([a], [b]) => a.localeCompare(b))
+    .map(([code, char]) => `${code}=${char}`)
+    .join(',');
+}
+
+export class ShortcutManager {
+  private readLayout: LayoutReader;
+  private bindings: ShortcutBinding[] = [];
+  private signature = '';
+  private timer: number | null = null;
+  private refreshing: Promise<void> | null = null;
+  private warnings: string[] = [];
+  private readonly onFocus = () => void this.refresh();
+
+  constructor(readLayout: LayoutReader = readNavigatorLayout) {
+    this.readLayout = readLayout;
+  }
+
+  /** 添加快捷键（start 之前调用）；handler 只在按下时触发一次 */
+  add(accelerator: string, handler: () => void | Promise<void>): void {
+    this.bindings.push({ accelerator, handler, registered: null });
+  }
+
+  /** 按当前布局注册所有快捷键，并开始监听布局变化 */
+  async start(): Promise<void> {
+    const layout = await this.readLayout();
+    this.signature = layoutSignature(layout);
+    await this.registerAll(layout);
+
+    // 布局切换没有事件可监听：定时检查，窗口获得焦点时也检查一次
+    this.timer = window.setInterval(() => void this.refresh(), LAYOUT_CHECK_INTERVAL);
+    window.addEventListener('focus', this.onFocus);
+  }
+
+  /** 停止监听并注销已注册的快捷键 */
+  async stop(): Promise<void> {
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+    window.removeEventListener('focus', this.onFocus);
+    await this.refreshing;
+    await this.unregisterAll();
+  }
+
+  /** 最近一次注册时无法映射的快捷键说明 */
+  getWarnings(): readonly string[] {
+    return this.warnings;
+  }
+
+  /** 布局变化时重新注册；重复调用时复用进行中的刷新 */
+  refresh(): Promise<void> {
+    if (!this.refreshing) {
+      this.refreshing = this.refreshIfChanged()
+        .catch((error) => {
+          console.error('按新键盘布局重新注册快捷键失败:', error);
+        })
+        .finally(() => {
+          this.refreshing = null;
+        });
+    }
+    return this.refreshing;
+  }
+
+  // ─── 内部 ───
+
+  private async refreshIfChanged(): Promise<void> {
+    const layout = await this.readLayout();
+    const signature = layoutSignature(layout);
+    if (signature === this.signature) return;
+
+    this.signature = signature;
+    await this.unregisterAll();
+    await this.registerAll(layout);
+  }
+
+  private async registerAll(layout: KeyboardLayout | null): Promise<void> {
+    this.warnings = [];
+    try {
+      for (const binding of this.bindings) {
+        const resolved = resolveAccelerator(binding.accelerator, layout);
+        if (!resolved.mapped) {
+          const warning = `快捷键 ${binding.accelerator} 在当前键盘布局中找不到对应按键，按美式布局位置注册`;
+          this.warnings.push(warning);
+          console.warn(warning);
+        }
+        await register(resolved.accelerator, (event: ShortcutEvent) => {
+          if (event.state === 'Pressed') void binding.handler();
+        });
+        binding.registered = resolved.accelerator;
+      }
+    } catch (error) {
+      // 部分注册失败时回滚，避免残留半套快捷键
+      await this.unregisterAll();
+      throw error;
+    }
+  }
+
+  private async unregisterAll(): Promise<void> {
+    for (const binding of this.bindings) {
+      if (!binding.registered) continue;
+      try {
+        await unregister(binding.registered);
+      } catch (error) {
+        console.warn(`注销快捷键 ${binding.registered} 失败:`, error);
+      }
+      binding.registered = null;
+    }
+  }
+}
@@ -0,0 +1,87 @@
+/**
+ * 存储后端抽象（v1.3.0）
+ *
+ * StorageService 只依赖 StorageBackend 接口，具体落盘方式可替换：
+ * - LazyStoreBackend：默认实现，基于 tauri-plugin-store 写入 pet-state.json
+ * - MemoryStorageBackend：纯内存实现，用于单元测试或无需持久化的场景
+ *
+ * 后续的加密存储、远程存储只需实现同一接口。
+ */
+import { LazyStore } from '@tauri-apps/plugin-store';
+
+/** 键值存储后端 */
+export interface StorageBackend {
+  /** 读取值，不存在时返回 undefined 或 null */
+  get<T>(key: string): Promise<T | null | undefined>;
+  /** 写入值（是否立即落盘由实现决定） */
+  set(key: string, value: unknown): Promise<void>;
+  /** 将未落盘的修改持久化 */
+  save(): Promise<void>;
+}
+
+/** 默认后端：tauri-plugin-store（与 Rust 端共享同一存储资源） */
+export class LazyStoreBackend implements StorageBackend {
+  private store: LazyStore;
+
+  constructor(file: string) {
+    this.store = new LazyStore(file);
+  }
+
+  get<T>(key: string): Promise<T | null | undefined> {
+    return this.store.get<T>(key);
+  }
+
+  set(key: string, value: unknown): Promise<void> {
+    return this.store.set(key, value);
+  }
+
+  save(): Promise<void> {
+    return this.store.save();
+  }
+}
+
+/**
+ * 内存后端
+ *
+ * 读写都经过 JSON 序列化，模拟真实存储的拷贝语义：
+ * 调用方修改读出的对象不会影响已存储的数据。
+ */
+export class MemoryStorageBackend implements StorageBackend {
+  private data = new Map<string, string>();
+
+  constructor(initial: Record<string, unknown> = {}) {
+    for (const [key, value] of Object.entries(initial)) {
+      this.write(key, value);
+    }
+  }
+
+  async get<T>(key: string): Promise<T | undefined> {
+    const raw = this.data.get(key);
+    return raw === undefined ? undefined : (JSON.parse(raw) as T);
+  }
+
+  async set(key: string, value: unknown): Promise<void> {
+    this.write(key, value);
+  }
+
+  async save(): Promise<void> {
+    // 内存实现无需落盘
+  }
+
+  /** 导出当前全部数据（测试断言用） */
+  snapshot(): Record<string, unknown> {
+    const result: Record<string, unknown> = {};
+    for (const [key, raw] of this.data) {
+      result[key] = JSON.parse(raw);
+    }
+    return result;
+  }
+
+  private write(key: string, value: unknown): void {
+    if (value === undefined) {
+      this.data.delete(key);
+    } else {
+      this.data.set(key, JSON.stringify(value));
+    }
+  }
+}
@@ -1,11 +1,14 @@
 /**
  * 持久化存储服务
  *
- * 基于 tauri-plugin-store，将宠物状态、用户偏好等数据
+ * 默认基于 tauri-plugin-store，将宠物状态、用户偏好等数据
  * 持久化到本地文件（pet-state.json），跨重启保留。
+ * v1.3.0 起底层读写通过 StorageBackend 接口注入，测试可使用内存后端。
  */
-import { LazyStore } from '@tauri-apps/plugin-store';
+import { LazyStoreBackend, type StorageBackend } from './storage-backend';
 import { getLocalDateKey } from '../utils';
+import type { ActivityFidelity } from './activity-fidelity';
+import { DEFAULT_ALERT_THRESHOLDS, type AlertThresholds } from '../features/alert-rules';
 
 /** 存储文件名 */
 const STORE_FILE = 'pet-state.json';
@@ -34,6 +37,26 @@ export const STORE_KEYS = {
   POMODORO_COUNT: 'pomodoroCount',
   /** 番茄计数所属日期 YYYY-MM-DD（v1.2.0） */
   POMODORO_DATE: 'pomodoroDate',
+  /** 晨间例程最近执行日期 YYYY-MM-DD（v1.3.0） */
+  MORNING_ROUTINE_DATE: 'morningRoutineDate',
+  /** 上手小贴士展示记录（v1.3.0） */
+  TIPS_STATE: 'tipsState',
+  /** 鸟巢小窗开关与摆放位置，由 Rust 端读写（v1.3.0） */
+  NEST_WIDGET: 'nestWidget',
+  /** 后台任务队列（v1.3.0） */
+  JOB_QUEUE: 'jobQueue',
+  /** 数据采集授权记录，由 Rust 端读写（v1.3.0） */
+  CONSENTS: 'consents',
+  /** 长期性格特质：信任度与熟悉度（v1.3.0） */
+  PET_TRAITS: 'petTraits',
+  /** 自动回忆及其检测基线（v1.3.0） */
+  AUTO_MEMORIES: 'autoMemories',
+  /** 月度回顾：本月累计数据与往月回顾（v1.3.0） */
+  MONTHLY_RECAP: 'monthlyRecap',
+  /** 最近一次备份成功的日期 YYYY-MM-DD（v1.3.0） */
+  LAST_BACKUP_DATE: 'lastBackupDate',
+  /** 各授权类别已提示过的说明版本（v1.3.0） */
+  CONSENT_PROMPTS: 'consentPrompts',
 } as const;
 
 /** 宠物主人信息 */
@@ -66,6 +89,8 @@ export interface UserPreferences {
   hourlyChimeEnabled: boolean;
   /** 是否启用系统监控 */
   systemMonitorEnabled: boolean;
+  /** 资源报警阈值（v1.3.0，在设置窗口中配置） */
+  alertThresholds: AlertThresholds;
   /** 是否启用行为感知 */
   contextAwarenessEnabled: boolean;
   /** 勿扰时段开始小时（0-23），-1 表示关闭 */
@@ -76,26 +101,79 @@ export interface UserPreferences {
   nightModeEnabled: boolean;
   /** 是否开机自启动 */
   autoStartEnabled: boolean;
+  /** 是否启用晨间例程（v1.3.0） */
+  morningRoutineEnabled: boolean;
+  /** 工作日开始小时（0-23），晨间例程在此时执行 */
+  workdayStartHour: number;
+  /** 晨间例程：发送早安问候 */
+  morningRoutineGreeting: boolean;
+  /** 晨间例程：自动开始一个番茄钟 */
+  morningRoutinePomodoro: boolean;
+  /** 是否启用上手小贴士 */
+  tipsEnabled: boolean;
+  /** 所在位置，用于计算日出日落（v1.3.0，在设置窗口中配置）；null 表示未配置，按固定时段判断深夜 */
+  location: GeoLocation | null;
+  /** 是否自动把新电脑、系统升级等时刻记为回忆（v1.3.0，需用户主动开启） */
+  autoMemoriesEnabled: boolean;
+  /** 活动采样精细度（v1.3.0）：high 逐次记录 / medium 按分钟 / low 按小时 */
+  activityFidelity: ActivityFidelity;
+  /** 是否在提交 / 推送代码时庆祝（v1.3.0，需用户主动开启） */
+  gitWatchEnabled: boolean;
+  /** 监视的 Git 仓库目录（含 .git 的工作区根目录，可在设置窗口中编辑） */
+  gitWatchRepos: string[];
+  /** 状态同步到 MQTT（v1.3.0）；null 表示不同步 */
+  statusMqtt: MqttTarget | null;
+  /** 状态同步到 Discord 个人状态所用的应用 ID（v1.3.0）；null 表示不同步 */
+  statusDiscordClientId: string | null;
+}
+
+/** MQTT 发布目标（保留消息，QoS 0） */
+export interface MqttTarget {
+  host: string;
+  port: number;
+  topic: string;
+}
+
+/** 地理位置（十进制度数） */
+export interface GeoLocation {
+  /** 纬度，北正南负 */
+  latitude: number;
+  /** 经度，东正西负 */
+  longitude: number;
 }
 
 /** 默认偏好 */
-const DEFAULT_PREFERENCES: UserPreferences = {
+export const DEFAULT_PREFERENCES: UserPreferences = {
   hourlyChimeEnabled: true,
   systemMonitorEnabled: true,
+  alertThresholds: DEFAULT_ALERT_THRESHOLDS,
   contextAwarenessEnabled: true,
   quietHoursStart: -1,
   quietHoursEnd: -1,
   nightModeEnabled: true,
   autoStartEnabled: true,
+  morningRoutineEnabled: false,
+  workdayStartHour: 9,
+  morningRoutineGreeting: true,
+  morningRoutinePomodoro: false,
+  tipsEnabled: true,
+  location: null,
+  autoMemoriesEnabled: false,
+  activityFidelity: 'high',
+  gitWatchEnabled: false,
+  gitWatchRepos: [],
+  statusMqtt: null,
+  statusDiscordClientId: null,
 };
 
 export class StorageService {
-  private store: LazyStore;
+  private store: StorageBackend;
   /** incrementInteraction 串行化链，避免并发覆盖写 */
   private _interactionLock: Promise<number> = Promise.resolve(0);
 
-  constructor() {
-    this.store = new LazyStore(STORE_FILE);
+  /** @param backend 存储后端，默认写入 pet-state.json */
+  constructor(backend: StorageBackend = new LazyStoreBackend(STORE_FILE)) {
+    this.store = backend;
   }
 
   /** 读取指定 key 的值 */
@@ -0,0 +1,76 @@
+/**
+ * 长期性格特质（v1.3.0）
+ *
+ * 与按天波动的数据（每日汇总、连续天数）不同，信任度与熟悉度按天缓慢演化：
+ * 每过一天向当天的目标值靠近一小步，需要数周的相处才会明显变化，
+ * 长时间不见面时熟悉度会慢慢回落，但信任不会因为缺席而消失。
+ *
+ * 特质单独持久化在 STORE_KEYS.PET_TRAITS，不随 7 天滚动窗口丢失。
+ */
+import type { DailySummary } from '../types';
+
+/** 小鸟的长期性格特质（取值 0-1） */
+export interface PetTraits {
+  /** 信任度：由健康的作息与专注习惯慢慢积累 */
+  trust: number;
+  /** 熟悉度：由相处的天数与互动量慢慢积累 */
+  familiarity: number;
+  /** 最近一次演化到的日期 YYYY-MM-DD（空字符串表示尚未演化） */
+  updatedDate: string;
+}
+
+/** 初始特质：有一点点信任，还完全不熟 */
+export const DEFAULT_TRAITS: PetTraits = {
+  trust: 0.3,
+  familiarity: 0,
+  updatedDate: '',
+};
+
+/** 熟悉度每日演化步长（约一个月达到目标值的 63%） */
+const FAMILIARITY_RATE = 1 / 30;
+/** 信任度每日演化步长（比熟悉度更慢） */
+const TRUST_RATE = 1 / 45;
+/** 缺席一天时熟悉度的衰减步长（比积累慢，偶尔不见不会前功尽弃） */
+const FAMILIARITY_DECAY_RATE = 1 / 90;
+/** 单日互动达到此次数时熟悉度目标值封顶 */
+const FULL_INTERACTION_COUNT = 30;
+
+function clamp01(value: number): number {
+  return Math.min(1, Math.max(0, value));
+}
+
+/** 当天的信任度目标值：番茄专注加分，熬夜扣分 */
+function getTrustTarget(day: DailySummary): number {
+  let target = 0.5;
+  if (day.pomodoroCount > 0) target += 0.25;
+  if (day.interactionCount > 0) target += 0.25;
+  const latestHour = day.activeHours[1];
+  if (latestHour >= 23 || latestHour <= 2) target -= 0.3;
+  return clamp01(target);
+}
+
+/** 当天的熟悉度目标值：来了就有一半，互动越多越接近满值 */
+function getFamiliarityTarget(day: DailySummary): number {
+  return clamp01(0.5 + (0.5 * day.interactionCount) / FULL_INTERACTION_COUNT);
+}
+
+/**
+ * 按一天的数据演化特质
+ * @param day 当天的汇总；null 表示当天没有见面
+ */
+export function evolveTraits(traits: PetTraits, date: string, day: DailySummary | null): PetTraits {
+  if (!day) {
+    return {
+      trust: traits.trust,
+      familiarity: clamp01(traits.familiarity * (1 - FAMILIARITY_DECAY_RATE)),
+      updatedDate: date,
+    };
+  }
+  return {
+    trust: clamp01(traits.trust + (getTrustTarget(day) - traits.trust) * TRUST_RATE),
+    familiarity: clamp01(
+      traits.familiarity + (getFamiliarityTarget(day) - traits.familiarity) * FAMILIARITY_RATE,
+    ),
+    updatedDate: date,
+  };
+}
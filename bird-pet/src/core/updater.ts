@@ -1,6 +1,6 @@
 import { check, type DownloadEvent } from '@tauri-apps/plugin-updater';
 import { relaunch } from '@tauri-apps/plugin-process';
-import { showHint } from '../utils';
+import { debugLog, showHint } from '../utils';
 
 const IGNORED_VERSION_KEY = 'bird-pet-ignored-version';
 
@@ -47,7 +47,7 @@ export class UpdateController {
       if (!manual) {
         const ignored = localStorage.getItem(IGNORED_VERSION_KEY);
         if (ignored === update.version) {
-          console.log(`版本 ${update.version} 已被用户忽略`);
+          debugLog(`版本 ${update.version} 已被用户忽略`);
           return;
         }
       }
@@ -0,0 +1,18 @@
+/**
+ * 窗口定向事件监听（v1.3.0）
+ *
+ * Rust 端按路由表（routing.rs）把托盘、退出、昼夜、磁盘等事件只发给主窗口，
+ * 而全局 listen() 会接收发往任意窗口的事件。
+ * 主窗口订阅这些事件时统一走 listenToCurrentWindow()，只接收发给自己的事件和广播，
+ * 子窗口即使误订阅同名事件也不会被触发。
+ */
+import type { EventCallback, UnlistenFn } from '@tauri-apps/api/event';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+/** 监听发往当前窗口的后端事件 */
+export function listenToCurrentWindow<T>(
+  event: string,
+  handler: EventCallback<T>,
+): Promise<UnlistenFn> {
+  return getCurrentWebviewWindow().listen<T>(event, handler);
+}
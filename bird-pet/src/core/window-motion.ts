@@ -0,0 +1,41 @@
+/**
+ * 窗口移动动画
+ *
+ * 封装 Rust 端 animate_window_to / stop_window_animation 命令。
+ * 动画由后端独立线程按固定节拍驱动，webview 忙于渲染时也不会卡顿。
+ * 只作用于调用方所在窗口；同一窗口上的新动画会打断旧动画。
+ */
+import { invoke } from '@tauri-apps/api/core';
+
+/** 缓动曲线（与 Rust 端 Easing 一致） */
+export type WindowEasing =
+  | 'linear'
+  | 'ease_in_quad'
+  | 'ease_out_quad'
+  | 'ease_in_out_quad'
+  | 'ease_out_cubic'
+  | 'ease_in_out_cubic';
+
+/**
+ * 以动画方式将当前窗口移动到指定物理坐标
+ *
+ * @returns true = 完整播放；false = 被新动画或 stopWindowAnimation() 打断
+ */
+export function animateWindowTo(
+  x: number,
+  y: number,
+  durationMs: number,
+  easing: WindowEasing = 'ease_in_out_quad',
+): Promise<boolean> {
+  return invoke<boolean>('animate_window_to', {
+    x: Math.round(x),
+    y: Math.round(y),
+    durationMs: Math.max(0, Math.round(durationMs)),
+    easing,
+  });
+}
+
+/** 停止当前窗口上的移动动画（窗口停在当前位置） */
+export function stopWindowAnimation(): Promise<void> {
+  return invoke('stop_window_animation');
+}
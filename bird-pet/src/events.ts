@@ -1,5 +1,6 @@
 type EventMap = Record<string, unknown>;
 type Listener<T> = (data: T) => void;
+type AnyListener<Events> = (event: keyof Events, data: unknown) => void;
 
 /**
  * 类型安全的事件总线
@@ -17,6 +18,7 @@ type Listener<T> = (data: T) => void;
  */
 export class EventBus<Events extends EventMap> {
   private listeners = new Map<keyof Events, Set<Listener<never>>>();
+  private anyListeners = new Set<AnyListener<Events>>();
 
   /** 订阅事件，返回取消订阅函数 */
   on<K extends keyof Events>(event: K, listener: Listener<Events[K]>): () => void {
@@ -31,6 +33,13 @@ export class EventBus<Events extends EventMap> {
   emit<K extends keyof Events>(event: K, ...args: Events[K] extends void ? [] : [Events[K]]): void {
     const data = args[0] as Events[K];
     this.listeners.get(event)?.forEach((fn) => (fn as Listener<Events[K]>)(data));
+    this.anyListeners.forEach((fn) => fn(event, data));
+  }
+
+  /** 订阅所有事件（调试用，如开发者事件检查器），返回取消订阅函数 */
+  onAny(listener: AnyListener<Events>): () => void {
+    this.anyListeners.add(listener);
+    return () => this.anyListeners.delete(listener);
   }
 
   /** 取消订阅 */
@@ -50,5 +59,6 @@ export class EventBus<Events extends EventMap> {
   /** 销毁总线，清除所有监听器 */
   dispose(): void {
     this.listeners.clear();
+    this.anyListeners.clear();
   }
 }
@@ -0,0 +1,164 @@
+/**
+ * 资源报警规则（v1.3.0）
+ *
+ * 每条规则由「触发阈值 + 持续时长 + 解除阈值」组成：
+ * 指标持续高于触发阈值达到指定时长才报警，报警后需回落到解除阈值以下才复位，
+ * 避免编译等短时尖峰反复触发提醒。
+ * 阈值保存在偏好中，可在设置窗口调整。
+ */
+
+/** 规则可观测的指标 */
+export type AlertMetric = 'cpu' | 'memory';
+
+/** 一次采样的指标值（百分比 0-100） */
+export type AlertSample = Record<AlertMetric, number>;
+
+/** 报警规则 */
+export interface AlertRule {
+  /** 规则标识 */
+  id: string;
+  /** 观测指标 */
+  metric: AlertMetric;
+  /** 触发阈值（高于此值开始计时） */
+  triggerAbove: number;
+  /** 需持续超过阈值的时长（毫秒），0 = 立即触发 */
+  sustainMs: number;
+  /** 解除阈值（报警后低于此值才复位，应不高于 triggerAbove） */
+  clearBelow: number;
+}
+
+/** 用户可调的报警阈值（保存在偏好 alertThresholds 中，在设置窗口中编辑） */
+export interface AlertThreshold {
+  /** 触发阈值（%） */
+  triggerAbove: number;
+  /** 需持续超过阈值的分钟数，0 = 立即触发 */
+  sustainMinutes: number;
+  /** 解除阈值（%），不高于触发阈值 */
+  clearBelow: number;
+}
+
+/** 每个指标一组阈值 */
+export type AlertThresholds = Record<AlertMetric, AlertThreshold>;
+
+/** 默认阈值：CPU > 85% 持续 3 分钟，低于 60% 解除；内存 > 90% 持续 1 分钟，低于 80% 解除 */
+export const DEFAULT_ALERT_THRESHOLDS: AlertThresholds = {
+  cpu: { triggerAbove: 85, sustainMinutes: 3, clearBelow: 60 },
+  memory: { triggerAbove: 90, sustainMinutes: 1, clearBelow: 80 },
+};
+
+/** 指标的显示名 */
+export const ALERT_METRIC_LABELS: Record<AlertMetric, string> = {
+  cpu: 'CPU',
+  memory: '内存',
+};
+
+/**
+ * 校验一组阈值
+ * @returns 不合法时返回可直接展示给用户的错误信息，合法时返回 null
+ */
+export function validateAlertThreshold(threshold: AlertThreshold): string | null {
+  const { triggerAbove, sustainMinutes, clearBelow } = threshold;
+  if (!Number.isFinite(triggerAbove) || triggerAbove < 1 || triggerAbove > 100) {
+    return '触发阈值需要在 1-100 之间';
+  }
+  if (!Number.isFinite(clearBelow) || clearBelow < 0 || clearBelow > triggerAbove) {
+    return '解除阈值需要在 0 到触发阈值之间';
+  }
+  if (!Number.isFinite(sustainMinutes) || sustainMinutes < 0) {
+    return '持续时长不能为负数';
+  }
+  return null;
+}
+
+/**
+ * 把偏好中的阈值转换成报警规则
+ *
+ * 缺失或不合法的指标（手改存储文件、旧版本数据）回退到默认阈值，不影响其他指标。
+ */
+export function rulesFromThresholds(thresholds?: Partial<AlertThresholds>): AlertRule[] {
+  return (Object.keys(DEFAULT_ALERT_THRESHOLDS) as AlertMetric[]).map((metric) => {
+    const stored = thresholds?.[metric];
+    const threshold =
+      stored && validateAlertThreshold(stored) === null
+        ? stored
+        : DEFAULT_ALERT_THRESHOLDS[metric];
+    return {
+      id: `${metric}-high`,
+      metric,
+      triggerAbove: threshold.triggerAbove,
+      sustainMs: threshold.sustainMinutes * 60 * 1000,
+      clearBelow: threshold.clearBelow,
+    };
+  });
+}
+
+/** 默认规则（由默认阈值生成） */
+export const DEFAULT_ALERT_RULES: readonly AlertRule[] = rulesFromThresholds(
+  DEFAULT_ALERT_THRESHOLDS,
+);
+
+/** 单条规则的运行状态 */
+interface RuleState {
+  /** 本轮连续超过阈值的起始时间，null = 当前未超过 */
+  aboveSince: number | null;
+  /** 是否处于已报警状态 */
+  active: boolean;
+}
+
+export class AlertRuleEngine {
+  private rules: readonly AlertRule[];
+  private states = new Map<string, RuleState>();
+
+  constructor(rules: readonly AlertRule[] = DEFAULT_ALERT_RULES) {
+    this.rules = rules;
+    this.reset();
+  }
+
+  /**
+   * 输入一次采样，返回本次新进入报警状态的规则（按规则声明顺序）
+   *
+   * 已在报警中的规则不会重复返回，直到指标回落到解除阈值以下。
+   */
+  evaluate(sample: AlertSample, now = Date.now()): AlertRule[] {
+    const fired: AlertRule[] = [];
+
+    for (const rule of this.rules) {
+      const state = this.states.get(rule.id)!;
+      const value = sample[rule.metric];
+
+      if (state.active) {
+        if (value < rule.clearBelow) {
+          state.active = false;
+          state.aboveSince = null;
+        }
+        continue;
+      }
+
+      if (value <= rule.triggerAbove) {
+        state.aboveSince = null;
+        continue;
+      }
+
+      if (state.aboveSince === null) state.aboveSince = now;
+      if (now - state.aboveSince >= rule.sustainMs) {
+        state.active = true;
+        fired.push(rule);
+      }
+    }
+
+    return fired;
+  }
+
+  /** 规则当前是否处于报警状态 */
+  isActive(ruleId: string): boolean {
+    return this.states.get(ruleId)?.active ?? false;
+  }
+
+  /** 清空所有规则的计时与报警状态（监控重启时调用） */
+  reset(): void {
+    this.states.clear();
+    for (const rule of this.rules) {
+      this.states.set(rule.id, { aboveSince: null, active: false });
+    }
+  }
+}
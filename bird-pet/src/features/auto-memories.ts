@@ -0,0 +1,269 @@
+/**
+ * 自动回忆记录器
+ *
+ * v1.3.0 新增，需在右键菜单中主动开启（偏好 autoMemoriesEnabled）。
+ * 把值得纪念、但用户不会自己记下的时刻自动写成回忆（tag 为 'auto'），在回忆面板中单独展示：
+ * - 第一次在一台新电脑上启动
+ * - 操作系统升级
+ * - 接上一块没见过的显示器
+ * - 番茄钟累计完成 100 / 500 / 1000 个（从开启记录后开始计数）
+ *
+ * 首次开启时只记录当前电脑、系统版本与显示器作为基线，不生成回忆。
+ *
+ * 终端 memo-cli 记下的回忆（tag 为 'cli'）在启动时从收件箱取出并入同一列表，不受开关影响。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { EventBus } from '../events';
+import type { AppEvents, AutoMemory, AutoMemoryKind } from '../types';
+import { STORE_KEYS, type StorageService } from '../core/storage';
+
+/** Rust 端 get_machine_info 返回的显示器信息 */
+export interface MonitorInfo {
+  name: string | null;
+  width: number;
+  height: number;
+}
+
+/** Rust 端 get_machine_info 返回的本机信息 */
+export interface MachineInfo {
+  host_name: string | null;
+  os_name: string | null;
+  os_version: string | null;
+  monitors: MonitorInfo[];
+}
+
+/** memo-cli 收件箱中的回忆（Rust 端 take_cli_memories 返回） */
+export interface CliMemory {
+  message: string;
+  at: number;
+}
+
+/** 持久化状态：检测基线 + 已生成的回忆 */
+export interface AutoMemoryState {
+  /** 是否已记录过基线 */
+  initialized: boolean;
+  /** 见过的电脑（主机名） */
+  knownHosts: string[];
+  /** 各电脑上次见到的系统版本 */
+  osVersions: Record<string, string>;
+  /** 见过的显示器（名称@分辨率） */
+  knownMonitors: string[];
+  /** 开启记录后累计完成的番茄数 */
+  pomodoroTotal: number;
+  /** 自动回忆，按时间升序 */
+  memories: AutoMemory[];
+}
+
+/** 番茄钟累计里程碑 */
+const POMODORO_MILESTONES = [100, 500, 1000];
+/** 最多保留的自动回忆条数 */
+const MAX_MEMORIES = 50;
+/** 显示器检查间隔（毫秒）= 10 分钟；显示器插拔没有可监听的事件 */
+const CHECK_INTERVAL = 10 * 60 * 1000;
+
+/** 默认持久化状态（每次新建，避免共享数组被修改） */
+function createDefaultState(): AutoMemoryState {
+  return {
+    initialized: false,
+    knownHosts: [],
+    osVersions: {},
+    knownMonitors: [],
+    pomodoroTotal: 0,
+    memories: [],
+  };
+}
+
+function monitorKey(monitor: MonitorInfo): string {
+  return `${monitor.name ?? '显示器'}@${monitor.width}x${monitor.height}`;
+}
+
+/**
+ * 对比本机信息与基线，返回值得记录的时刻并更新基线
+ *
+ * 新电脑上的显示器和系统版本直接记入基线，只记录「新电脑」一条。
+ */
+export function detectMachineChanges(
+  state: AutoMemoryState,
+  info: MachineInfo,
+): { kind: AutoMemoryKind; message: string }[] {
+  const found: { kind: AutoMemoryKind; message: string }[] = [];
+  const host = info.host_name ?? 'unknown';
+  const osVersion = [info.os_name, info.os_version].filter(Boolean).join(' ');
+  const isNewHost = !state.knownHosts.includes(host);
+
+  if (isNewHost) {
+    if (state.initialized) {
+      found.push({
+        kind: 'new_machine',
+        message: `第一次在「${host}」上见面，新家真不错！`,
+      });
+    }
+    state.knownHosts.push(host);
+  } else if (osVersion && state.osVersions[host] && state.osVersions[host] !== osVersion) {
+    found.push({ kind: 'os_upgrade', message: `电脑升级到了 ${osVersion}，我也跟着焕然一新` });
+  }
+  if (osVersion) state.osVersions[host] = osVersion;
+
+  for (const monitor of info.monitors) {
+    const key = monitorKey(monitor);
+    if (state.knownMonitors.includes(key)) continue;
+    if (state.initialized && !isNewHost) {
+      found.push({
+        kind: 'new_monitor',
+        message: `多了一块 ${monitor.width}×${monitor.height} 的屏幕，我的地盘变大啦`,
+      });
+    }
+    state.knownMonitors.push(key);
+  }
+
+  state.initialized = true;
+  return found;
+}
+
+export class AutoMemoryRecorder {
+  private bus: EventBus<AppEvents>;
+  private storage: StorageService;
+  private readMachine: () => Promise<MachineInfo>;
+  private takeCliMemories: () => Promise<CliMemory[]>;
+
+  private state: AutoMemoryState = createDefaultState();
+  private enabled = false;
+  private timer: number | null = null;
+  private unsubscribers: (() => void)[] = [];
+
+  constructor(
+    bus: EventBus<AppEvents>,
+    storage: StorageService,
+    readMachine: () => Promise<MachineInfo> = () => invoke<MachineInfo>('get_machine_info'),
+    takeCliMemories: () => Promise<CliMemory[]> = () => invoke<CliMemory[]>('take_cli_memories'),
+  ) {
+    this.bus = bus;
+    this.storage = storage;
+    this.readMachine = readMachine;
+    this.takeCliMemories = takeCliMemories;
+  }
+
+  /** 是否已开启 */
+  get isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+
+    const prefs = await this.storage.getPreferences();
+    this.enabled = prefs.autoMemoriesEnabled;
+    const stored = await this.storage.get<Partial<AutoMemoryState>>(
+      STORE_KEYS.AUTO_MEMORIES,
+      {},
+    );
+    this.state = {
+      initialized: stored.initialized ?? false,
+      knownHosts: [...(stored.knownHosts ?? [])],
+      osVersions: { ...stored.osVersions },
+      knownMonitors: [...(stored.knownMonitors ?? [])],
+      pomodoroTotal: stored.pomodoroTotal ?? 0,
+      memories: [...(stored.memories ?? [])],
+    };
+    await this.importCliMemories();
+    if (!this.enabled) return;
+
+    this.unsubscribers.push(this.bus.on('pomodoro:break', () => void this.onPomodoroComplete()));
+    await this.checkMachine();
+    this.timer = window.setInterval(() => void this.checkMachine(), CHECK_INTERVAL);
+  }
+
+  /** 是否在定期检查本机信息 */
+  isRunning(): boolean {
+    return this.timer !== null;
+  }
+
+  stop(): void {
+    for (const unsub of this.unsubscribers) unsub();
+    this.unsubscribers = [];
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+  }
+
+  /**
+   * 开启或关闭自动记录（已有回忆保留）
+   * @returns 切换后的状态
+   */
+  async toggle(): Promise<boolean> {
+    await this.storage.setPreferences({ autoMemoriesEnabled: !this.enabled });
+    await this.start();
+    return this.enabled;
+  }
+
+  /** 自动回忆列表，最新的在前 */
+  getMemories(): AutoMemory[] {
+    return [...this.state.memories].reverse();
+  }
+
+  /** 对比本机信息，记录新电脑 / 系统升级 / 新显示器 */
+  async checkMachine(): Promise<void> {
+    if (!this.enabled) return;
+    let info: MachineInfo;
+    try {
+      info = await this.readMachine();
+    } catch (e) {
+      console.warn('读取本机信息失败:', e);
+      return;
+    }
+    const found = detectMachineChanges(this.state, info);
+    for (const { kind, message } of found) this.record(kind, message);
+    await this.persist();
+  }
+
+  // ─── 内部 ───
+
+  /** 并入 memo-cli 记下的回忆（后端取出即清空收件箱） */
+  private async importCliMemories(): Promise<void> {
+    let notes: CliMemory[];
+    try {
+      notes = (await this.takeCliMemories()) ?? [];
+    } catch (e) {
+      console.warn('读取 memo-cli 回忆失败:', e);
+      return;
+    }
+    if (notes.length === 0) return;
+    for (const { message, at } of notes) {
+      this.state.memories.push({ tag: 'cli', kind: 'note', message, at });
+    }
+    this.state.memories.sort((a, b) => a.at - b.at);
+    this.trim();
+    await this.persist();
+  }
+
+  private async onPomodoroComplete(): Promise<void> {
+    this.state.pomodoroTotal++;
+    const total = this.state.pomodoroTotal;
+    if (POMODORO_MILESTONES.includes(total)) {
+      this.record('pomodoro_total', `一起完成了第 ${total} 个番茄钟 🍅`);
+    }
+    await this.persist();
+  }
+
+  private record(kind: AutoMemoryKind, message: string): void {
+    const memory: AutoMemory = { tag: 'auto', kind, message, at: Date.now() };
+    this.state.memories.push(memory);
+    this.trim();
+    this.bus.emit('memory:auto-recorded', memory);
+  }
+
+  private trim(): void {
+    if (this.state.memories.length > MAX_MEMORIES) {
+      this.state.memories.splice(0, this.state.memories.length - MAX_MEMORIES);
+    }
+  }
+
+  private async persist(): Promise<void> {
+    try {
+      await this.storage.set(STORE_KEYS.AUTO_MEMORIES, this.state);
+    } catch (e) {
+      console.warn('保存自动回忆失败:', e);
+    }
+  }
+}
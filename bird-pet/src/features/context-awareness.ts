@@ -6,26 +6,30 @@
  * 并在上下文切换时通过气泡发送对应的场景台词。
  *
  * 设计要点：
- * - 15 秒轮询间隔（性能友好）
+ * - 15 秒轮询间隔（性能友好）；采样精细度调低后放慢到 1 / 5 分钟（v1.3.0）
  * - 5 分钟台词冷却（防止频繁切换窗口导致的气泡轰炸）
  * - 上下文切换时通过 EventBus 广播，其他模块可响应
+ * - 窗口标题需用户授权（v1.3.0），未授权时后端只返回应用名
+ * - UAC 提示、锁屏、管理员窗口下读不到前台信息（v1.3.0）：广播 tracking:paused 暂停采集，
+ *   离开后广播 tracking:resumed，时间线上显示为「未追踪（系统）」而不是空档
  */
 import { invoke } from '@tauri-apps/api/core';
 import type { EventBus } from '../events';
-import type { AppEvents } from '../types';
+import type { AppEvents, UntrackedReason } from '../types';
 import type { BubbleManager } from '../core/bubble-manager';
 import type { DialogueEngine, AppContext } from './dialogue-engine';
 import type { StorageService } from '../core/storage';
 import type { QuietModeManager } from './quiet-mode';
+import { getFidelitySettings, type ActivityFidelity } from '../core/activity-fidelity';
 
 /** 活跃窗口信息（来自 Rust 后端） */
 interface ActiveWindowInfo {
   app_name: string;
   title: string;
+  /** 处于无法追踪的系统界面时的原因 */
+  untracked?: UntrackedReason | null;
 }
 
-/** 轮询间隔（毫秒）= 15 秒 */
-const POLL_INTERVAL = 15_000;
 /** 台词冷却时间（毫秒）= 5 分钟 */
 const LINE_COOLDOWN = 5 * 60 * 1000;
 
@@ -55,6 +59,7 @@ export class ContextAwareness {
   private timer: number | null = null;
   private _currentContext: AppContext = 'unknown';
   private lastLineTime = 0;
+  private paused = false;
 
   /** 当前识别到的行为上下文 */
   get currentContext(): AppContext {
@@ -77,14 +82,25 @@ export class ContextAwareness {
 
   /** 启动行为感知 */
   async start(): Promise<void> {
+    // 防重入：先清理可能存在的旧定时器
+    this.stop();
+
+    let fidelity: ActivityFidelity | undefined;
     if (this.storage) {
       const prefs = await this.storage.getPreferences();
       if (!prefs.contextAwarenessEnabled) return;
+      fidelity = prefs.activityFidelity;
     }
     // 立即执行一次检测
     this.poll();
-    // 定时轮询
-    this.timer = window.setInterval(() => this.poll(), POLL_INTERVAL);
+    // 定时轮询（间隔随采样精细度变化）
+    const { pollMs } = getFidelitySettings(fidelity);
+    this.timer = window.setInterval(() => this.poll(), pollMs);
+  }
+
+  /** 是否在轮询前台窗口 */
+  isRunning(): boolean {
+    return this.timer !== null;
   }
 
   /** 停止行为感知 */
@@ -107,6 +123,18 @@ export class ContextAwareness {
       const info = await invoke<ActiveWindowInfo | null>('get_active_window_info');
       if (!info) return;
 
+      if (info.untracked) {
+        if (!this.paused) {
+          this.paused = true;
+          this.bus.emit('tracking:paused', { reason: info.untracked });
+        }
+        return;
+      }
+      if (this.paused) {
+        this.paused = false;
+        this.bus.emit('tracking:resumed');
+      }
+
       const newContext = this.classify(info);
 
       // 上下文发生变化
@@ -115,7 +143,7 @@ export class ContextAwareness {
         this._currentContext = newContext;
 
         // 广播上下文变更事件
-        this.bus.emit('context:changed', { from: oldContext, to: newContext });
+        this.bus.emit('context:changed', { from: oldContext, to: newContext, app: info.app_name });
 
         // unknown 仅用于状态复位，不触发上下文气泡
         if (newContext === 'unknown') return;
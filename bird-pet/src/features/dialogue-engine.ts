@@ -45,6 +45,8 @@ export type DialogueScene =
   | 'reflective_streak'
   | 'reflective_affinity'
   | 'reflective_app_habit'
+  // v1.3.0: 长期性格特质
+  | 'reflective_bond'
   // v0.5.0: 特殊日期场景
   | 'special_birthday'
   | 'special_valentine'
@@ -74,6 +76,10 @@ export interface DialogueCondition {
   streak?: { min: number };
   /** 匹配主要使用的应用上下文 */
   dominantApp?: AppContext;
+  /** 匹配长期信任度（>= min，v1.3.0） */
+  trust?: { min: number };
+  /** 匹配长期熟悉度（>= min，v1.3.0） */
+  familiarity?: { min: number };
 }
 
 /** 对话条目 */
@@ -223,6 +229,13 @@ export class DialogueEngine {
     if (cond.dominantApp && ctx.dominantApp) {
       if (ctx.dominantApp !== cond.dominantApp) return false;
     }
+    // 长期性格特质
+    if (cond.trust && ctx.trust !== undefined) {
+      if (ctx.trust < cond.trust.min) return false;
+    }
+    if (cond.familiarity && ctx.familiarity !== undefined) {
+      if (ctx.familiarity < cond.familiarity.min) return false;
+    }
     return true;
   }
 
@@ -0,0 +1,83 @@
+/**
+ * 代码动态庆祝（v1.3.0）
+ *
+ * 需在右键菜单中主动开启（偏好 gitWatchEnabled），并在设置窗口中配置仓库目录（gitWatchRepos）。
+ * Rust 端监视这些仓库 git 目录下的 reflog，发现新提交或推送时向主窗口推送 `git:activity`，
+ * 本模块把它转发到内部 EventBus，由业务事件层让小鸟欢呼。
+ *
+ * 未开启时后端不读取任何仓库；关闭期间产生的提交不会补报。
+ */
+import type { EventBus } from '../events';
+import type { AppEvents, GitActivity } from '../types';
+import type { StorageService } from '../core/storage';
+import { listenToCurrentWindow } from '../core/window-events';
+
+/** 台词中提交说明的最大长度 */
+const MAX_SUMMARY_LENGTH = 40;
+
+function truncate(text: string): string {
+  const chars = [...text];
+  return chars.length > MAX_SUMMARY_LENGTH
+    ? `${chars.slice(0, MAX_SUMMARY_LENGTH).join('')}…`
+    : text;
+}
+
+/** 根据代码动态生成庆祝台词 */
+export function formatGitActivity(activity: GitActivity): string {
+  const summary = activity.summary ? `「${truncate(activity.summary)}」` : '';
+  if (activity.kind === 'push') {
+    const target = activity.branch ? ` → ${activity.branch}` : '';
+    return `🚀 推送成功！${activity.repo}${target} ${summary}终于上路啦`;
+  }
+  return `✅ ${activity.repo} 又提交了一笔${summary}，继续加油！`;
+}
+
+export class GitCelebration {
+  private bus: EventBus<AppEvents>;
+  private storage: StorageService;
+  private enabled = false;
+  private unlisten: Promise<() => void> | null = null;
+
+  constructor(bus: EventBus<AppEvents>, storage: StorageService) {
+    this.bus = bus;
+    this.storage = storage;
+  }
+
+  /** 是否已开启 */
+  get isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+    const prefs = await this.storage.getPreferences();
+    this.enabled = prefs.gitWatchEnabled;
+    if (!this.enabled) return;
+    this.unlisten = listenToCurrentWindow<GitActivity>('git:activity', (e) =>
+      this.bus.emit('git:activity', e.payload),
+    );
+  }
+
+  /** 是否在监听 git 活动 */
+  isRunning(): boolean {
+    return this.unlisten !== null;
+  }
+
+  stop(): void {
+    if (this.unlisten) {
+      void this.unlisten.then((fn) => fn()).catch(() => {});
+      this.unlisten = null;
+    }
+  }
+
+  /**
+   * 开启或关闭（后端按偏好决定是否监视仓库）
+   * @returns 切换后的状态与已配置的仓库数
+   */
+  async toggle(): Promise<{ enabled: boolean; repoCount: number }> {
+    await this.storage.setPreferences({ gitWatchEnabled: !this.enabled });
+    await this.start();
+    const prefs = await this.storage.getPreferences();
+    return { enabled: this.enabled, repoCount: prefs.gitWatchRepos.length };
+  }
+}
@@ -71,21 +71,39 @@ export class GreetingManager {
     if (!matched) return;
     if (this.triggeredPeriods.has(matched.period)) return;
 
-    this.triggeredPeriods.add(matched.period);
+    this.deliver(matched);
+  }
+
+  /**
+   * 发送早安问候（供晨间例程调用，不受当前时段限制）
+   *
+   * 与启动问候共用已触发记录，避免同一时段问候两次。
+   * @returns 是否实际发送
+   */
+  greetMorning(): boolean {
+    const morning = TIME_PERIODS.find((tp) => tp.period === 'morning');
+    if (!morning || this.triggeredPeriods.has('morning')) return false;
+    this.deliver(morning);
+    return true;
+  }
 
-    const line = this.dialogue.getLine(matched.scene);
+  // ─── 内部 ───
+
+  /** 发送时段问候台词与氛围特效 */
+  private deliver(config: PeriodConfig): void {
+    this.triggeredPeriods.add(config.period);
+
+    const line = this.dialogue.getLine(config.scene);
     this.bubble.say({ text: line, priority: 'high', duration: 5000 });
 
     // 时段氛围特效
-    if (matched.period === 'morning') {
+    if (config.period === 'morning') {
       setTimeout(() => this.effects.playSunshine(), 300);
-    } else if (matched.period === 'night' || matched.period === 'latenight') {
+    } else if (config.period === 'night' || config.period === 'latenight') {
       setTimeout(() => this.effects.playZzz(), 300);
     }
   }
 
-  // ─── 内部 ───
-
   /**
    * 判断当前小时落在哪个时段
    * 使用 25 小时制处理跨午夜：22:00-25:00（即 22:00-1:00）
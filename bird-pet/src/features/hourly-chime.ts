@@ -38,6 +38,11 @@ export class HourlyChime {
     this.scheduleNext();
   }
 
+  /** 是否已排定下一次整点报时 */
+  isRunning(): boolean {
+    return this.timer !== null;
+  }
+
   /** 停止整点报时 */
   stop(): void {
     if (this.timer !== null) {
@@ -31,6 +31,7 @@ const REFLECTIVE_SCENES: DialogueScene[] = [
   'reflective_streak',
   'reflective_affinity',
   'reflective_app_habit',
+  'reflective_bond',
 ];
 
 export class IdleCareScheduler {
@@ -132,6 +133,11 @@ export class IdleCareScheduler {
     }, AFFIRMATION_INTERVAL);
   }
 
+  /** 是否在调度久坐与关怀提醒 */
+  isRunning(): boolean {
+    return this.sedentaryTimer !== null;
+  }
+
   /** 停止调度 */
   stop(): void {
     if (this.sedentaryTimer !== null) {
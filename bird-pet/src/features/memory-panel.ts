@@ -4,10 +4,12 @@
  * v1.0.0 新增。
  * 从托盘菜单"查看回忆"打开一个 WebviewWindow，
  * 展示亲密度进度、统计数字、7 天活动热力图和洞察列表。
+ * v1.3.0 起附带自动回忆（新电脑、系统升级等）。
  */
 import { WebviewWindow } from '@tauri-apps/api/webviewWindow';
 import { emitTo, listen } from '@tauri-apps/api/event';
 import type { MemorySystem } from '../core/memory';
+import type { AutoMemoryRecorder } from './auto-memories';
 import { AFFINITY_NAMES, AFFINITY_THRESHOLDS } from '../constants';
 import { calcDaysSinceMet } from '../utils';
 
@@ -18,12 +20,14 @@ const PANEL_HEIGHT = 520;
 export class MemoryPanelManager {
   private memory: MemorySystem;
   private metDate: string;
+  private autoMemories: AutoMemoryRecorder | null;
   private panelWin: WebviewWindow | null = null;
   private creatingPromise: Promise<void> | null = null;
 
-  constructor(memory: MemorySystem, metDate: string) {
+  constructor(memory: MemorySystem, metDate: string, autoMemories?: AutoMemoryRecorder) {
     this.memory = memory;
     this.metDate = metDate;
+    this.autoMemories = autoMemories ?? null;
   }
 
   /** 打开或聚焦回忆面板 */
@@ -132,6 +136,7 @@ export class MemoryPanelManager {
         activeHours: s.activeHours,
       })),
       totalPomodoros,
+      autoMemories: (this.autoMemories?.getMemories() ?? []).slice(0, 5),
     };
 
     try {
@@ -372,6 +372,32 @@ export const DIALOGUE_ENTRIES: DialogueEntry[] = [
     conditions: { dominantApp: 'browsing' },
   },
 
+  // 长期性格特质反思（v1.3.0）
+  {
+    scene: 'reflective_bond',
+    lines: [
+      '和{nickname}待久了，连你敲键盘的节奏我都记住了～',
+      '感觉我们越来越有默契了呢 🐦',
+    ],
+    conditions: { familiarity: { min: 0.5 } },
+  },
+  {
+    scene: 'reflective_bond',
+    lines: [
+      '{nickname}最近作息好规律，我可以安心地在旁边打盹了 😌',
+      '和{nickname}在一起很安心，我都敢把小脑袋靠过来了～',
+    ],
+    conditions: { trust: { min: 0.6 } },
+  },
+  {
+    scene: 'reflective_bond',
+    lines: [
+      '这么多个月一起走过来…{nickname}已经是我的家人了 💗',
+      '不管过多久，我都会记得我们一起度过的每一天 🌟',
+    ],
+    conditions: { trust: { min: 0.7 }, familiarity: { min: 0.7 } },
+  },
+
   // ────────────────────────────────────────
   // 特殊日期台词（v0.5.0）
   // ────────────────────────────────────────
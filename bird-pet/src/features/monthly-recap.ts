@@ -0,0 +1,246 @@
+/**
+ * 月度回顾（v1.3.0）
+ *
+ * 记忆系统只保留最近 7 天的数据，月度回顾需要的统计在本模块按月单独累计：
+ * - 各应用的使用时长（来自 context:changed；采样精细度为 low 时不记应用名，这里也不统计）
+ * - 每天完成的番茄数与互动次数
+ * - 本月达成的里程碑
+ *
+ * 每小时及启动时检查一次月份，跨月后把上月数据与当月回忆亮点汇总成 MonthlyRecap，
+ * 通过 `recap:ready` 广播并保留最近 12 个月。需要图片时由 Rust 端 render_recap 按模板渲染为 SVG。
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { EventBus } from '../events';
+import type { AppEvents, AutoMemory, MonthlyRecap } from '../types';
+import { STORE_KEYS, type StorageService } from '../core/storage';
+import { getFidelitySettings } from '../core/activity-fidelity';
+import { getLocalDateKey } from '../utils';
+
+/** 单月累计数据 */
+export interface MonthTally {
+  /** 月份 YYYY-MM */
+  month: string;
+  /** 各应用累计分钟数（未取整） */
+  appMinutes: Record<string, number>;
+  /** 每天的番茄数与互动次数 */
+  days: Record<string, { pomodoros: number; interactions: number }>;
+  /** 里程碑消息 */
+  milestones: string[];
+}
+
+/** 持久化状态 */
+interface RecapState {
+  tally: MonthTally;
+  recaps: MonthlyRecap[];
+}
+
+/** 检查跨月的间隔（毫秒）= 1 小时 */
+const CHECK_INTERVAL = 60 * 60 * 1000;
+/** 保留的往月回顾数 */
+const MAX_RECAPS = 12;
+/** 回顾中展示的应用数 */
+const TOP_APP_COUNT = 5;
+/** 单段应用使用时长上限（毫秒），休眠 / 关机未收到切换事件时不至于把整夜算进去 */
+const MAX_SPAN_MS = 2 * 60 * 60 * 1000;
+
+/** 本地时区月份 key（YYYY-MM） */
+export function getMonthKey(date: Date = new Date()): string {
+  return getLocalDateKey(date).slice(0, 7);
+}
+
+function createTally(month: string): MonthTally {
+  return { month, appMinutes: {}, days: {}, milestones: [] };
+}
+
+/** 由单月累计数据和当月回忆生成回顾 */
+export function buildRecap(
+  tally: MonthTally,
+  memories: AutoMemory[],
+  generatedAt: number,
+): MonthlyRecap {
+  const topApps = Object.entries(tally.appMinutes)
+    .map(([app, minutes]) => ({ app, minutes: Math.round(minutes) }))
+    .filter(({ minutes }) => minutes > 0)
+    .sort((a, b) => b.minutes - a.minutes)
+    .slice(0, TOP_APP_COUNT);
+
+  let bestFocusDay: MonthlyRecap['bestFocusDay'] = null;
+  let totalPomodoros = 0;
+  let activeDays = 0;
+  for (const [date, { pomodoros, interactions }] of Object.entries(tally.days)) {
+    totalPomodoros += pomodoros;
+    if (pomodoros > 0 || interactions > 0) activeDays++;
+    if (pomodoros > 0 && (!bestFocusDay || pomodoros > bestFocusDay.pomodoros)) {
+      bestFocusDay = { date, pomodoros };
+    }
+  }
+
+  const highlights = memories
+    .filter((m) => getMonthKey(new Date(m.at)) === tally.month)
+    .map((m) => m.message);
+
+  return {
+    month: tally.month,
+    topApps,
+    bestFocusDay,
+    totalPomodoros,
+    activeDays,
+    highlights,
+    milestones: [...tally.milestones],
+    generatedAt,
+  };
+}
+
+/** 气泡中展示的回顾摘要 */
+export function formatRecap(recap: MonthlyRecap): string {
+  const [year, month] = recap.month.split('-');
+  const parts = [`🗓 ${year} 年 ${Number(month)} 月回顾来啦！`];
+  const top = recap.topApps[0];
+  if (top) parts.push(`最常用的是 ${top.app}（${top.minutes} 分钟）`);
+  if (recap.bestFocusDay) {
+    const { date, pomodoros } = recap.bestFocusDay;
+    parts.push(`${date.slice(5)} 最专注，完成了 ${pomodoros} 个番茄`);
+  }
+  parts.push(`这个月一起度过了 ${recap.activeDays} 天`);
+  return parts.join('，');
+}
+
+export class MonthlyRecapGenerator {
+  private bus: EventBus<AppEvents>;
+  private storage: StorageService;
+  private getMemories: () => AutoMemory[];
+
+  private state: RecapState = { tally: createTally(getMonthKey()), recaps: [] };
+  private keepAppName = true;
+  /** 正在使用的应用及开始时间 */
+  private current: { app: string; since: number } | null = null;
+  private timer: number | null = null;
+  private unsubscribers: (() => void)[] = [];
+
+  constructor(
+    bus: EventBus<AppEvents>,
+    storage: StorageService,
+    getMemories: () => AutoMemory[] = () => [],
+  ) {
+    this.bus = bus;
+    this.storage = storage;
+    this.getMemories = getMemories;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+    const prefs = await this.storage.getPreferences();
+    this.keepAppName = getFidelitySettings(prefs.activityFidelity).keepAppName;
+    const stored = await this.storage.get<Partial<RecapState>>(STORE_KEYS.MONTHLY_RECAP, {});
+    this.state = {
+      tally: stored.tally ?? createTally(getMonthKey()),
+      recaps: [...(stored.recaps ?? [])],
+    };
+
+    this.unsubscribers.push(
+      this.bus.on('context:changed', ({ app }) => this.switchApp(app)),
+      this.bus.on('tracking:paused', () => this.switchApp(undefined)),
+      this.bus.on('pomodoro:break', () => this.countDay('pomodoros')),
+      this.bus.on('pet:clicked', () => this.countDay('interactions')),
+      this.bus.on('memory:milestone', ({ message }) => {
+        this.rollover(Date.now());
+        this.state.tally.milestones.push(message);
+        void this.persist();
+      }),
+    );
+    await this.check();
+    this.timer = window.setInterval(() => void this.check(), CHECK_INTERVAL);
+  }
+
+  /** 是否在累计本月统计 */
+  isRunning(): boolean {
+    return this.timer !== null;
+  }
+
+  stop(): void {
+    this.switchApp(undefined);
+    for (const unsub of this.unsubscribers) unsub();
+    this.unsubscribers = [];
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+  }
+
+  /**
+   * 把回顾渲染为 SVG 图片
+   * @param path 保存路径，缺省时写入系统下载目录；只能位于下载目录或应用数据目录下
+   * @returns 实际写入的路径
+   */
+  async exportImage(recap: MonthlyRecap, path?: string): Promise<string> {
+    return invoke<string>('render_recap', { recap, path: path ?? null });
+  }
+
+  /** 最近一次生成的回顾 */
+  getLatest(): MonthlyRecap | null {
+    return this.state.recaps[this.state.recaps.length - 1] ?? null;
+  }
+
+  /** 跨月时生成上月回顾并开始新的累计 */
+  async check(now = Date.now()): Promise<void> {
+    if (this.rollover(now)) await this.persist();
+  }
+
+  // ─── 内部 ───
+
+  /** 月份变化时结算上月，返回是否发生了跨月 */
+  private rollover(now: number): boolean {
+    const month = getMonthKey(new Date(now));
+    if (this.state.tally.month === month) return false;
+
+    // 跨月时把正在使用的应用时长记到上月
+    if (this.current) {
+      this.switchApp(this.current.app, now);
+    }
+    const tally = this.state.tally;
+    const hasData =
+      Object.keys(tally.appMinutes).length > 0 ||
+      Object.keys(tally.days).length > 0 ||
+      tally.milestones.length > 0;
+    this.state.tally = createTally(month);
+    if (hasData) {
+      const recap = buildRecap(tally, this.getMemories(), now);
+      this.state.recaps.push(recap);
+      if (this.state.recaps.length > MAX_RECAPS) {
+        this.state.recaps.splice(0, this.state.recaps.length - MAX_RECAPS);
+      }
+      this.bus.emit('recap:ready', recap);
+    }
+    return true;
+  }
+
+  /** 结束当前应用的计时，并开始统计下一个应用 */
+  private switchApp(app: string | undefined, now = Date.now()): void {
+    if (this.current) {
+      const spent = Math.min(now - this.current.since, MAX_SPAN_MS);
+      if (spent > 0) {
+        const minutes = this.state.tally.appMinutes;
+        minutes[this.current.app] = (minutes[this.current.app] ?? 0) + spent / 60_000;
+        void this.persist();
+      }
+    }
+    this.current = app && this.keepAppName ? { app, since: now } : null;
+  }
+
+  private countDay(field: 'pomodoros' | 'interactions'): void {
+    this.rollover(Date.now());
+    const date = getLocalDateKey();
+    const days = this.state.tally.days;
+    days[date] = days[date] ?? { pomodoros: 0, interactions: 0 };
+    days[date][field]++;
+    void this.persist();
+  }
+
+  private async persist(): Promise<void> {
+    try {
+      await this.storage.set(STORE_KEYS.MONTHLY_RECAP, this.state);
+    } catch (e) {
+      console.warn('保存月度回顾失败:', e);
+    }
+  }
+}
@@ -0,0 +1,154 @@
+/**
+ * 晨间例程
+ *
+ * v1.3.0 新增。
+ * 在工作日的上班时间（偏好 workdayStartHour）自动执行一组启动步骤：
+ * 1. 早安问候（与启动问候共享去重）
+ * 2. 可选：自动开始一个番茄钟
+ *
+ * 每个步骤均可在偏好中单独开关；每天最多执行一次（持久化日期）。
+ * 若应用在上班时间之后才启动，会在启动流程结束后补执行一次。
+ *
+ * 系统监控与行为感知已由 startModules 按各自开关启动，例程不再重复启动。
+ * 调度在前端完成（问候与番茄钟都在前端），后端尚无调度器；
+ * 应用也没有天气或日历数据源，问候暂不包含这两项内容。
+ * 后端编排、天气、日历与启动统计监视尚未完成，见 README「待完成」。
+ */
+import { STORE_KEYS, type StorageService, type UserPreferences } from '../core/storage';
+import type { GreetingManager } from './greeting';
+import type { PomodoroTimer } from './pomodoro';
+import type { QuietModeManager } from './quiet-mode';
+import { getLocalDateKey } from '../utils';
+
+/** 补执行的最晚时间：上班时间后 4 小时内启动才补执行 */
+const CATCH_UP_WINDOW_HOURS = 4;
+/** 补执行延迟（毫秒），排在每日启动流程（特殊日期 / 问候）之后 */
+const CATCH_UP_DELAY = 12_000;
+
+/** 例程步骤 */
+export type MorningRoutineStep = 'greeting' | 'pomodoro';
+
+export class MorningRoutine {
+  private greeting: GreetingManager;
+  private pomodoro: PomodoroTimer;
+  private storage: StorageService;
+  private quietMode: QuietModeManager | null;
+  private timer: number | null = null;
+
+  constructor(
+    greeting: GreetingManager,
+    pomodoro: PomodoroTimer,
+    storage: StorageService,
+    quietMode?: QuietModeManager,
+  ) {
+    this.greeting = greeting;
+    this.pomodoro = pomodoro;
+    this.storage = storage;
+    this.quietMode = quietMode ?? null;
+  }
+
+  /** 启动调度（未启用时直接返回） */
+  async start(): Promise<void> {
+    this.stop();
+
+    const prefs = await this.storage.getPreferences();
+    if (!prefs.morningRoutineEnabled) return;
+
+    const now = new Date();
+    const lastRunDate = await this.storage.get<string>(STORE_KEYS.MORNING_ROUTINE_DATE, '');
+    if (lastRunDate !== getLocalDateKey(now) && this.isWithinCatchUpWindow(now, prefs)) {
+      this.timer = window.setTimeout(() => {
+        this.timer = null;
+        void this.runAndReschedule();
+      }, CATCH_UP_DELAY);
+      return;
+    }
+
+    this.scheduleNext(prefs);
+  }
+
+  /** 是否已排定下一次晨间流程 */
+  isRunning(): boolean {
+    return this.timer !== null;
+  }
+
+  /** 停止调度 */
+  stop(): void {
+    if (this.timer !== null) {
+      clearTimeout(this.timer);
+      this.timer = null;
+    }
+  }
+
+  /**
+   * 立即执行例程（今日已执行则跳过）
+   *
+   * @returns 实际执行的步骤列表
+   */
+  async run(): Promise<MorningRoutineStep[]> {
+    const today = getLocalDateKey();
+    const lastRunDate = await this.storage.get<string>(STORE_KEYS.MORNING_ROUTINE_DATE, '');
+    if (lastRunDate === today) return [];
+    await this.storage.set(STORE_KEYS.MORNING_ROUTINE_DATE, today);
+
+    const prefs = await this.storage.getPreferences();
+    const executed: MorningRoutineStep[] = [];
+
+    if (prefs.morningRoutineGreeting && !this.quietMode?.isFullSilent()) {
+      try {
+        if (this.greeting.greetMorning()) executed.push('greeting');
+      } catch (e) {
+        console.warn('晨间例程：问候失败:', e);
+      }
+    }
+
+    if (prefs.morningRoutinePomodoro && this.pomodoro.state === 'idle') {
+      try {
+        await this.pomodoro.start();
+        executed.push('pomodoro');
+      } catch (e) {
+        console.warn('晨间例程：开始番茄钟失败:', e);
+      }
+    }
+
+    return executed;
+  }
+
+  // ─── 内部 ───
+
+  private async runAndReschedule(): Promise<void> {
+    const prefs = await this.storage.getPreferences();
+    if (!prefs.morningRoutineEnabled) return;
+    if (isWorkday(new Date())) {
+      await this.run();
+    }
+    this.scheduleNext(prefs);
+  }
+
+  /** 调度到下一个工作日的上班时间 */
+  private scheduleNext(prefs: UserPreferences): void {
+    const now = new Date();
+    const next = new Date(now);
+    next.setHours(prefs.workdayStartHour, 0, 0, 0);
+    if (next <= now) next.setDate(next.getDate() + 1);
+    while (!isWorkday(next)) next.setDate(next.getDate() + 1);
+
+    this.timer = window.setTimeout(() => {
+      this.timer = null;
+      void this.runAndReschedule();
+    }, next.getTime() - now.getTime());
+  }
+
+  /** 今天是工作日，且当前处于 [上班时间, 上班时间 + 补执行窗口) 内 */
+  private isWithinCatchUpWindow(now: Date, prefs: UserPreferences): boolean {
+    if (!isWorkday(now)) return false;
+    const hour = now.getHours();
+    return hour >= prefs.workdayStartHour && hour < prefs.workdayStartHour + CATCH_UP_WINDOW_HOURS;
+  }
+}
+
+/** 周一至周五视为工作日 */
+function isWorkday(date: Date): boolean {
+  const day = date.getDay();
+  return day !== 0 && day !== 6;
+}
@@ -31,6 +31,8 @@ export class PomodoroTimer {
   private timer: number | null = null;
   private startedAt = 0;
   private completedCount = 0;
+  /** 暂停时当前阶段的剩余毫秒数，未暂停为 null */
+  private pausedRemaining: number | null = null;
 
   /** 当前状态 */
   get state(): PomodoroState {
@@ -45,6 +47,7 @@ export class PomodoroTimer {
   /** 当前阶段剩余毫秒数 */
   get remainingMs(): number {
     if (this._state === 'idle' || !this.startedAt) return 0;
+    if (this.pausedRemaining !== null) return this.pausedRemaining;
     const duration = this._state === 'focus' ? FOCUS_DURATION : BREAK_DURATION;
     const elapsed = Date.now() - this.startedAt;
     return Math.max(0, duration - elapsed);
@@ -68,6 +71,7 @@ export class PomodoroTimer {
   async start(): Promise<void> {
     await this.loadPersistedCount();
     this.clearTimer();
+    this.pausedRemaining = null;
     this._state = 'focus';
     this.startedAt = Date.now();
     this._bus.emit('pomodoro:focus');
@@ -83,6 +87,7 @@ export class PomodoroTimer {
   /** 停止番茄钟 */
   async stop(): Promise<void> {
     this.clearTimer();
+    this.pausedRemaining = null;
     this._state = 'idle';
     this._bus.emit('pomodoro:stop');
     this.hourlyChime.setEnabled(true); // 恢复整点报时
@@ -90,6 +95,26 @@ export class PomodoroTimer {
     await this.persistState();
   }
 
+  /** 暂停计时（会话断开时），保留当前阶段和剩余时间，不提示 */
+  pause(): void {
+    if (this._state === 'idle' || this.pausedRemaining !== null) return;
+    this.pausedRemaining = this.remainingMs;
+    this.clearTimer();
+  }
+
+  /** 从暂停处继续计时 */
+  resume(): void {
+    if (this.pausedRemaining === null) return;
+    const remaining = this.pausedRemaining;
+    this.pausedRemaining = null;
+    const duration = this._state === 'focus' ? FOCUS_DURATION : BREAK_DURATION;
+    this.startedAt = Date.now() - (duration - remaining);
+    this.timer = setTimeout(
+      () => (this._state === 'focus' ? this.onFocusEnd() : this.onBreakEnd()),
+      remaining,
+    );
+  }
+
   /** 获取状态标签（用于菜单显示） */
   getStatusLabel(): string {
     if (this._state === 'idle') return '🍅 番茄钟';
@@ -4,14 +4,15 @@
  * v1.0.0 新增。
  * 根据用户偏好和当前状态动态控制提醒节奏：
  * 1. 勿扰时段：用户自定义时段内暂停所有主动气泡
- * 2. 深夜降频：23:00-6:00 自动降低打扰频率
+ * 2. 深夜降频：23:00-6:00 自动降低打扰频率；
+ *    v1.3.0 起配置了位置时改为按真实天黑判断（天黑且已过 21 点，直到黎明）
  * 3. 会议静默：检测到会议上下文时自动静音
  * 4. 专注保护：连续编码 >30min 时降低气泡频率
  *
  * 其他模块通过调用 shouldSuppress() 判断是否抑制消息。
  */
 import type { EventBus } from '../events';
-import type { AppEvents } from '../types';
+import type { AppEvents, DaylightPhase } from '../types';
 import type { StorageService, UserPreferences } from '../core/storage';
 
 /** 静默原因 */
@@ -26,6 +27,8 @@ export class QuietModeManager {
 
   /** 当前是否处于会议 */
   private inMeeting = false;
+  /** 当前昼夜相位（未配置位置时为 null） */
+  private daylightPhase: DaylightPhase | null = null;
   /** 连续编码开始时间（ms），0 表示非编码 */
   private codingStartTime = 0;
   /** 取消订阅列表 */
@@ -34,6 +37,8 @@ export class QuietModeManager {
   /** 深夜时段 */
   private readonly NIGHT_START = 23;
   private readonly NIGHT_END = 6;
+  /** 按真实天黑判断时，深夜降频最早从几点开始（避免冬天傍晚就降频） */
+  private readonly DARK_NIGHT_EARLIEST = 21;
   /** 专注保护阈值（ms）：30 分钟 */
   private readonly DEEP_FOCUS_THRESHOLD = 30 * 60 * 1000;
   /** 偏好刷新间隔（ms） */
@@ -62,6 +67,12 @@ export class QuietModeManager {
           this.codingStartTime = 0;
         }
       }),
+      this.bus.on('daylight:phase-changed', ({ phase }) => {
+        this.daylightPhase = phase;
+      }),
+      this.bus.on('daylight:cleared', () => {
+        this.daylightPhase = null;
+      }),
     );
   }
 
@@ -113,10 +124,8 @@ export class QuietModeManager {
     }
 
     // 4. 深夜降频（不完全屏蔽，但标记为 night_mode）
-    if (this.preferences?.nightModeEnabled) {
-      if (hour >= this.NIGHT_START || hour < this.NIGHT_END) {
-        return 'night_mode';
-      }
+    if (this.preferences?.nightModeEnabled && this.isNightHour(hour)) {
+      return 'night_mode';
     }
 
     return null;
@@ -128,7 +137,15 @@ export class QuietModeManager {
   isNightMode(): boolean {
     this.maybeRefreshPreferences();
     if (!this.preferences?.nightModeEnabled) return false;
-    const hour = new Date().getHours();
+    return this.isNightHour(new Date().getHours());
+  }
+
+  /** 是否处于深夜：有昼夜相位时按真实天黑判断，否则按固定时段 */
+  private isNightHour(hour: number): boolean {
+    if (this.daylightPhase) {
+      // 清晨天还没亮也算深夜；傍晚天黑后要到 DARK_NIGHT_EARLIEST 才算
+      return this.daylightPhase === 'night' && (hour >= this.DARK_NIGHT_EARLIEST || hour < 12);
+    }
     return hour >= this.NIGHT_START || hour < this.NIGHT_END;
   }
 
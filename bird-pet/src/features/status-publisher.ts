@@ -0,0 +1,187 @@
+/**
+ * 在线状态发布器
+ *
+ * v1.3.0 新增。
+ * 汇总行为上下文、番茄钟、时段等信号，推导出用户当前状态
+ * （专注 / 会议中 / 离开 / 收尾 / 空闲可打扰），
+ * 状态变化时通过 EventBus 广播 status:changed，并推送给已注册的发布目标。
+ *
+ * 状态推导集中在 resolveStatus()，各发布目标只消费结果，
+ * 新增发布渠道时实现 StatusSink 并 addSink() 即可，无需改动推导规则。
+ *
+ * 优先级：会议中 > 专注 > 离开 > 收尾 > 空闲
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { EventBus } from '../events';
+import type { AppEvents } from '../types';
+import type { AppContext } from './dialogue-engine';
+import type { QuietModeManager } from './quiet-mode';
+
+/** 用户状态 */
+export type UserStatus = 'in_meeting' | 'focused' | 'away' | 'winding_down' | 'available';
+
+/** 状态推导输入 */
+export interface StatusInputs {
+  /** 当前行为上下文 */
+  context: AppContext;
+  /** 番茄钟是否处于专注阶段 */
+  pomodoroFocus: boolean;
+  /** 连续编码时长（毫秒） */
+  codingMs: number;
+  /** 当前小时（0-23） */
+  hour: number;
+  /** 是否处于深夜模式 */
+  nightMode: boolean;
+}
+
+/** 状态发布目标 */
+export interface StatusSink {
+  /** 目标名称（日志用） */
+  name: string;
+  /** 推送新状态 */
+  publish(status: UserStatus): void | Promise<void>;
+}
+
+/** 连续编码超过该时长视为专注（毫秒）= 30 分钟，与 QuietMode 专注保护一致 */
+const FOCUS_CODING_THRESHOLD = 30 * 60 * 1000;
+/** 收尾时段起始小时 */
+const WIND_DOWN_START = 21;
+/** 时间相关状态的复核间隔（毫秒）= 1 分钟 */
+const REEVALUATE_INTERVAL = 60 * 1000;
+
+/** Rust 端状态发布目标：写入后端状态并广播到所有窗口，供 get_user_status 查询 */
+export const backendStatusSink: StatusSink = {
+  name: 'backend',
+  publish: (status) => invoke('publish_user_status', { status }),
+};
+
+/** MQTT 发布目标：以保留消息发布到偏好 statusMqtt 配置的主题，未配置时后端直接返回 */
+export const mqttStatusSink: StatusSink = {
+  name: 'mqtt',
+  publish: (status) => invoke('publish_status_mqtt', { status }),
+};
+
+/** Discord 个人状态：未配置应用 ID 时后端清除已发布的状态 */
+export const discordStatusSink: StatusSink = {
+  name: 'discord',
+  publish: (status) => invoke('publish_status_discord', { status }),
+};
+
+/** 按优先级推导用户状态 */
+export function resolveStatus(inputs: StatusInputs): UserStatus {
+  if (inputs.context === 'meeting') return 'in_meeting';
+  if (inputs.pomodoroFocus || inputs.codingMs >= FOCUS_CODING_THRESHOLD) return 'focused';
+  if (inputs.context === 'idle') return 'away';
+  if (inputs.nightMode || inputs.hour >= WIND_DOWN_START) return 'winding_down';
+  return 'available';
+}
+
+export class StatusPublisher {
+  private bus: EventBus<AppEvents>;
+  private quietMode: QuietModeManager | null;
+  private sinks: StatusSink[] = [];
+  private unsubscribers: (() => void)[] = [];
+  private timer: number | null = null;
+
+  private context: AppContext = 'unknown';
+  private pomodoroFocus = false;
+  /** 连续编码开始时间（ms），0 表示非编码 */
+  private codingStartTime = 0;
+  private _status: UserStatus = 'available';
+
+  /** 当前状态 */
+  get status(): UserStatus {
+    return this._status;
+  }
+
+  constructor(bus: EventBus<AppEvents>, quietMode?: QuietModeManager) {
+    this.bus = bus;
+    this.quietMode = quietMode ?? null;
+  }
+
+  /** 注册发布目标（注册后立即推送一次当前状态） */
+  addSink(sink: StatusSink): void {
+    this.sinks.push(sink);
+    this.publishTo(sink, this._status);
+  }
+
+  /** 把当前状态重新推送给所有发布目标（发布目标的配置变化后调用） */
+  republish(): void {
+    for (const sink of this.sinks) this.publishTo(sink, this._status);
+  }
+
+  start(): void {
+    this.stop();
+
+    this.unsubscribers.push(
+      this.bus.on('context:changed', ({ to }) => {
+        this.context = to;
+        if (to === 'coding') {
+          if (this.codingStartTime === 0) this.codingStartTime = Date.now();
+        } else {
+          this.codingStartTime = 0;
+        }
+        this.evaluate();
+      }),
+      this.bus.on('pomodoro:focus', () => {
+        this.pomodoroFocus = true;
+        this.evaluate();
+      }),
+      this.bus.on('pomodoro:break', () => {
+        this.pomodoroFocus = false;
+        this.evaluate();
+      }),
+      this.bus.on('pomodoro:stop', () => {
+        this.pomodoroFocus = false;
+        this.evaluate();
+      }),
+    );
+
+    // 专注阈值、收尾时段等依赖时间推移，需要定期复核
+    this.timer = window.setInterval(() => this.evaluate(), REEVALUATE_INTERVAL);
+    this.evaluate();
+  }
+
+  /** 是否在推导并发布状态 */
+  isRunning(): boolean {
+    return this.timer !== null;
+  }
+
+  stop(): void {
+    for (const unsub of this.unsubscribers) unsub();
+    this.unsubscribers = [];
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+  }
+
+  // ─── 内部 ───
+
+  private evaluate(): void {
+    const next = resolveStatus({
+      context: this.context,
+      pomodoroFocus: this.pomodoroFocus,
+      codingMs: this.codingStartTime > 0 ? Date.now() - this.codingStartTime : 0,
+      hour: new Date().getHours(),
+      nightMode: this.quietMode?.isNightMode() ?? false,
+    });
+    if (next === this._status) return;
+
+    const from = this._status;
+    this._status = next;
+    this.bus.emit('status:changed', { from, to: next });
+    for (const sink of this.sinks) this.publishTo(sink, next);
+  }
+
+  private publishTo(sink: StatusSink, status: UserStatus): void {
+    try {
+      const result = sink.publish(status);
+      if (result instanceof Promise) {
+        result.catch((e) => console.warn(`状态发布失败 (${sink.name}):`, e));
+      }
+    } catch (e) {
+      console.warn(`状态发布失败 (${sink.name}):`, e);
+    }
+  }
+}
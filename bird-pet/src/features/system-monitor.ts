@@ -2,11 +2,19 @@
  * 系统资源监控
  *
  * 周期性调用 Rust 后端获取 CPU/内存使用情况，
- * 在资源紧张时通过气泡提醒用户。
+ * 在资源持续紧张时通过气泡提醒用户。
+ * 报警判定交给 AlertRuleEngine（持续时长 + 回差），短时尖峰不会触发。
+ * 规则在每次 start 时按偏好中的阈值重建，设置窗口保存后重启即可生效。
  */
 import { invoke } from '@tauri-apps/api/core';
 import type { BubbleManager } from '../core/bubble-manager';
 import type { StorageService } from '../core/storage';
+import {
+  AlertRuleEngine,
+  DEFAULT_ALERT_RULES,
+  rulesFromThresholds,
+  type AlertRule,
+} from './alert-rules';
 
 /** Rust 端 get_system_stats 返回的数据结构 */
 interface SystemStats {
@@ -16,14 +24,8 @@ interface SystemStats {
   memory_usage_percent: number;
 }
 
-/** CPU 报警阈值（%） */
-const CPU_ALERT_THRESHOLD = 85;
-/** 内存报警阈值（%） */
-const MEMORY_ALERT_THRESHOLD = 90;
 /** 轮询间隔（毫秒）= 30 秒 */
 const POLL_INTERVAL = 30 * 1000;
-/** 报警冷却时间（毫秒）= 5 分钟 */
-const ALERT_COOLDOWN = 5 * 60 * 1000;
 /** 首次检查延迟（毫秒，留时间给 CPU 基线） */
 const FIRST_CHECK_DELAY = 15 * 1000;
 
@@ -32,11 +34,15 @@ export class SystemMonitor {
   private storage: StorageService | null;
   private delayTimer: number | null = null;
   private timer: number | null = null;
-  private lastAlertAt = 0;
+  private rules: AlertRuleEngine;
+  /** 构造时指定的规则，优先于偏好中的阈值 */
+  private fixedRules: readonly AlertRule[] | null;
 
-  constructor(bubble: BubbleManager, storage?: StorageService) {
+  constructor(bubble: BubbleManager, storage?: StorageService, rules?: readonly AlertRule[]) {
     this.bubble = bubble;
     this.storage = storage ?? null;
+    this.fixedRules = rules ?? null;
+    this.rules = new AlertRuleEngine(rules);
   }
 
   /** 启动系统监控轮询 */
@@ -44,10 +50,14 @@ export class SystemMonitor {
     // 防重入：先清理可能存在的旧定时器
     this.stop();
 
+    let rules = this.fixedRules ?? DEFAULT_ALERT_RULES;
     if (this.storage) {
       const prefs = await this.storage.getPreferences();
       if (!prefs.systemMonitorEnabled) return;
+      rules = this.fixedRules ?? rulesFromThresholds(prefs.alertThresholds);
     }
+    // 重建引擎：清空计时状态并应用最新阈值
+    this.rules = new AlertRuleEngine(rules);
     // 首次延迟检查（让 Rust 端 CPU 基线稳定）
     this.delayTimer = window.setTimeout(() => {
       this.delayTimer = null;
@@ -56,6 +66,11 @@ export class SystemMonitor {
     }, FIRST_CHECK_DELAY);
   }
 
+  /** 是否在监控系统资源 */
+  isRunning(): boolean {
+    return this.timer !== null || this.delayTimer !== null;
+  }
+
   /** 停止监控 */
   stop(): void {
     if (this.delayTimer !== null) {
@@ -79,28 +94,17 @@ export class SystemMonitor {
   }
 
   private evaluate(stats: SystemStats): void {
-    const now = Date.now();
-    if (now - this.lastAlertAt < ALERT_COOLDOWN) return;
-
-    // CPU 过高
-    if (stats.cpu_usage > CPU_ALERT_THRESHOLD) {
-      this.bubble.say({
-        text: `电脑好努力哦！CPU ${Math.round(stats.cpu_usage)}%，要不要关些程序？🥵`,
-        priority: 'low',
-        duration: 4000,
-      });
-      this.lastAlertAt = now;
-      return; // 一次只提醒一个
-    }
-
-    // 内存过高
-    if (stats.memory_usage_percent > MEMORY_ALERT_THRESHOLD) {
-      this.bubble.say({
-        text: `内存快满啦！${stats.memory_used_gb.toFixed(1)}/${stats.memory_total_gb.toFixed(1)} GB 💾`,
-        priority: 'low',
-        duration: 4000,
-      });
-      this.lastAlertAt = now;
+    const fired = this.rules.evaluate({
+      cpu: stats.cpu_usage,
+      memory: stats.memory_usage_percent,
+    });
+    // 同一轮触发的规则逐条提醒：已触发的规则在回落前不会再次触发，跳过就等于漏报
+    for (const rule of fired) {
+      const text =
+        rule.metric === 'cpu'
+          ? `电脑好努力哦！CPU ${Math.round(stats.cpu_usage)}%，要不要关些程序？🥵`
+          : `内存快满啦！${stats.memory_used_gb.toFixed(1)}/${stats.memory_total_gb.toFixed(1)} GB 💾`;
+      this.bubble.say({ text, priority: 'low', duration: 4000 });
     }
   }
 }
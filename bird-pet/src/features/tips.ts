@@ -0,0 +1,218 @@
+/**
+ * 上手小贴士引擎
+ *
+ * v1.3.0 新增。
+ * 观察真实使用情况（是否打开过右键菜单、回忆面板、番茄钟、点击穿透），
+ * 在合适的时机由小鸟提示尚未发现的功能。
+ *
+ * 频率控制：
+ * - 任意两条小贴士间隔 ≥ 4 小时
+ * - 每条小贴士最多展示 2 次，之后永久退休
+ * - 用户用过对应功能后，该小贴士立即永久退休（"不再显示"记录持久化）
+ * - 静默 / 专注 / 深夜时不提示
+ */
+import type { EventBus } from '../events';
+import type { AppEvents } from '../types';
+import type { BubbleManager } from '../core/bubble-manager';
+import type { MemorySystem } from '../core/memory';
+import { STORE_KEYS, type StorageService } from '../core/storage';
+import type { AppContext } from './dialogue-engine';
+import type { QuietModeManager } from './quiet-mode';
+import { MODIFIER_KEY } from '../config';
+
+/** 可被小贴士引导的功能 */
+export type TipFeature = 'menu' | 'memories' | 'pomodoro' | 'clickthrough';
+
+/** 触发判断所需的运行时信号 */
+export interface TipSignals {
+  /** 本次启动以来的点击次数 */
+  sessionClicks: number;
+  /** 本次启动以来的拖动次数 */
+  sessionDrags: number;
+  /** 累计互动次数 */
+  totalInteractions: number;
+  /** 当前行为上下文 */
+  context: AppContext;
+}
+
+/** 小贴士规则 */
+interface TipRule {
+  feature: TipFeature;
+  text: string;
+  when: (signals: TipSignals) => boolean;
+}
+
+/** 持久化状态 */
+export interface TipsState {
+  /** 已永久退休的小贴士（用过功能或展示次数已满） */
+  retired: TipFeature[];
+  /** 各小贴士已展示次数 */
+  shownCounts: Partial<Record<TipFeature, number>>;
+  /** 最近一次展示时间（ms） */
+  lastShownAt: number;
+}
+
+/** 任意两条小贴士的最小间隔（毫秒）= 4 小时 */
+const MIN_TIP_INTERVAL = 4 * 60 * 60 * 1000;
+/** 每条小贴士最多展示次数 */
+const MAX_SHOWS_PER_TIP = 2;
+/** 定时检查间隔（毫秒）= 10 分钟 */
+const CHECK_INTERVAL = 10 * 60 * 1000;
+
+/** 默认持久化状态（每次新建，避免共享数组被修改） */
+function createDefaultState(): TipsState {
+  return { retired: [], shownCounts: {}, lastShownAt: 0 };
+}
+
+/** 规则按优先级排列，每次最多命中一条 */
+const TIP_RULES: TipRule[] = [
+  {
+    feature: 'menu',
+    text: '小提示：右键点我可以打开功能菜单哦～ 🐦',
+    when: (s) => s.sessionClicks >= 5,
+  },
+  {
+    feature: 'clickthrough',
+    text: `挡住你了吗？按 ${MODIFIER_KEY}+Shift+P 我就变成"穿透模式"，不挡鼠标啦 👻`,
+    when: (s) => s.sessionDrags >= 3,
+  },
+  {
+    feature: 'pomodoro',
+    text: '写代码累不累？右键菜单里有番茄钟，陪你专注 25 分钟 🍅',
+    when: (s) => s.context === 'coding',
+  },
+  {
+    feature: 'memories',
+    text: '托盘菜单里的「查看回忆」记录着我们的点点滴滴，去看看吧 📖',
+    when: (s) => s.totalInteractions >= 30,
+  },
+];
+
+export class TipsEngine {
+  private bus: EventBus<AppEvents>;
+  private bubble: BubbleManager;
+  private memory: MemorySystem;
+  private storage: StorageService;
+  private quietMode: QuietModeManager | null;
+
+  private state: TipsState = createDefaultState();
+  private enabled = false;
+  private sessionClicks = 0;
+  private sessionDrags = 0;
+  private context: AppContext = 'unknown';
+  private timer: number | null = null;
+  private unsubscribers: (() => void)[] = [];
+
+  constructor(
+    bus: EventBus<AppEvents>,
+    bubble: BubbleManager,
+    memory: MemorySystem,
+    storage: StorageService,
+    quietMode?: QuietModeManager,
+  ) {
+    this.bus = bus;
+    this.bubble = bubble;
+    this.memory = memory;
+    this.storage = storage;
+    this.quietMode = quietMode ?? null;
+  }
+
+  async start(): Promise<void> {
+    this.stop();
+
+    const prefs = await this.storage.getPreferences();
+    this.enabled = prefs.tipsEnabled;
+    const stored = await this.storage.get<Partial<TipsState>>(STORE_KEYS.TIPS_STATE, {});
+    this.state = {
+      retired: [...(stored.retired ?? [])],
+      shownCounts: { ...stored.shownCounts },
+      lastShownAt: stored.lastShownAt ?? 0,
+    };
+
+    // 功能使用信号：用过即退休对应小贴士
+    this.unsubscribers.push(
+      this.bus.on('menu:opened', () => this.markUsed('menu')),
+      this.bus.on('memories:opened', () => this.markUsed('memories')),
+      this.bus.on('pomodoro:focus', () => this.markUsed('pomodoro')),
+      this.bus.on('clickthrough:changed', () => this.markUsed('clickthrough')),
+      this.bus.on('context:changed', ({ to }) => {
+        this.context = to;
+      }),
+      this.bus.on('pet:dragged', () => {
+        this.sessionDrags++;
+      }),
+      // 用户刚和小鸟互动时是提示的好时机
+      this.bus.on('pet:clicked', () => {
+        this.sessionClicks++;
+        this.maybeShowTip();
+      }),
+    );
+
+    if (!this.enabled) return;
+    this.timer = window.setInterval(() => this.maybeShowTip(), CHECK_INTERVAL);
+  }
+
+  /** 是否在收集功能使用信号 */
+  isRunning(): boolean {
+    return this.unsubscribers.length > 0;
+  }
+
+  stop(): void {
+    for (const unsub of this.unsubscribers) unsub();
+    this.unsubscribers = [];
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+  }
+
+  /**
+   * 检查并展示一条小贴士
+   *
+   * @returns 展示的小贴士对应功能，未展示返回 null
+   */
+  maybeShowTip(now = Date.now()): TipFeature | null {
+    if (!this.enabled) return null;
+    if (now - this.state.lastShownAt < MIN_TIP_INTERVAL) return null;
+    if (this.quietMode?.shouldSuppress()) return null;
+
+    const signals: TipSignals = {
+      sessionClicks: this.sessionClicks,
+      sessionDrags: this.sessionDrags,
+      totalInteractions: this.memory.getProfile().totalInteractions,
+      context: this.context,
+    };
+    const rule = TIP_RULES.find((r) => !this.state.retired.includes(r.feature) && r.when(signals));
+    if (!rule) return null;
+
+    const shown = (this.state.shownCounts[rule.feature] ?? 0) + 1;
+    this.state.shownCounts[rule.feature] = shown;
+    this.state.lastShownAt = now;
+    if (shown >= MAX_SHOWS_PER_TIP) this.state.retired.push(rule.feature);
+    void this.persist();
+
+    this.bubble.say({ text: rule.text, priority: 'low', duration: 6000 });
+    return rule.feature;
+  }
+
+  /** 获取持久化状态（只读） */
+  getState(): Readonly<TipsState> {
+    return this.state;
+  }
+
+  // ─── 内部 ───
+
+  private markUsed(feature: TipFeature): void {
+    if (this.state.retired.includes(feature)) return;
+    this.state.retired.push(feature);
+    void this.persist();
+  }
+
+  private async persist(): Promise<void> {
+    try {
+      await this.storage.set(STORE_KEYS.TIPS_STATE, this.state);
+    } catch (e) {
+      console.warn('保存小贴士状态失败:', e);
+    }
+  }
+}
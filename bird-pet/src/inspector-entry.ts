@@ -0,0 +1,70 @@
+/**
+ * 开发者事件检查器子窗口入口（v1.3.0）
+ *
+ * 监听主窗口每秒推送的快照，渲染监视器状态、命令耗时表和最近事件列表（新事件在上）。
+ */
+import './inspector.css';
+import { listen } from '@tauri-apps/api/event';
+import type { InspectorSnapshot } from './core/dev-inspector';
+
+function formatTime(at: number): string {
+  const d = new Date(at);
+  const pad = (n: number, len = 2) => String(n).padStart(len, '0');
+  const hms = `${pad(d.getHours())}:${pad(d.getMinutes())}:${pad(d.getSeconds())}`;
+  return `${hms}.${pad(d.getMilliseconds(), 3)}`;
+}
+
+function formatData(data: unknown): string {
+  if (data === undefined) return '';
+  try {
+    return JSON.stringify(data);
+  } catch {
+    return String(data);
+  }
+}
+
+function render(snapshot: InspectorSnapshot): void {
+  document.getElementById('watchers')!.textContent = JSON.stringify(snapshot.watchers, null, 2);
+
+  const commands = document.getElementById('commands')!;
+  commands.innerHTML = '';
+  const sorted = [...snapshot.commands].sort((a, b) => b.maxMs - a.maxMs);
+  for (const stat of sorted) {
+    const row = document.createElement('tr');
+    if (stat.errors > 0) row.className = 'has-errors';
+    const cells = [
+      stat.name,
+      String(stat.count),
+      String(stat.errors),
+      stat.lastMs.toFixed(1),
+      stat.avgMs.toFixed(1),
+      stat.maxMs.toFixed(1),
+    ];
+    for (const text of cells) {
+      const td = document.createElement('td');
+      td.textContent = text;
+      row.appendChild(td);
+    }
+    commands.appendChild(row);
+  }
+
+  const events = document.getElementById('events')!;
+  events.innerHTML = '';
+  for (const event of [...snapshot.events].reverse()) {
+    const li = document.createElement('li');
+    li.className = `source-${event.source}`;
+    const time = document.createElement('span');
+    time.className = 'event-time';
+    time.textContent = `${formatTime(event.at)} `;
+    const name = document.createElement('span');
+    name.className = 'event-name';
+    name.textContent = event.name;
+    li.append(time, name, ` ${formatData(event.data)}`);
+    events.appendChild(li);
+  }
+  document.getElementById('event-count')!.textContent = `(${snapshot.events.length})`;
+}
+
+listen<InspectorSnapshot>('inspector:snapshot', (event) => {
+  render(event.payload);
+});
@@ -7,36 +7,62 @@
  * 3. 管理生命周期与退出流程
  */
 import './style.css';
-import { emit, listen } from '@tauri-apps/api/event';
+import { emit } from '@tauri-apps/api/event';
 import { disable, enable, isEnabled } from '@tauri-apps/plugin-autostart';
 import { exit } from '@tauri-apps/plugin-process';
 import { bindBusinessEvents } from '@/app/business-events';
 import { initLifecycle } from '@/app/lifecycle';
-import { createMenuItems } from '@/app/menu-items';
+import {
+  createInspectorMenuItem,
+  createMenuItems,
+  getCancelOperationsLabel,
+  getFidelityLabel,
+} from '@/app/menu-items';
 import { restoreWindowPosition, startModules, syncAutoStart } from '@/app/runtime';
+import { bindSessionEvents } from '@/app/session-events';
+import { bindSettingsEvents } from '@/app/settings-events';
 import { getAutoSaveIntervalMs, runDailyStartupFlow } from '@/app/startup-flow';
 import type { CoreModules, FeatureModules } from '@/app/types';
 import { AnimationEngine } from '@/core/animation';
 import { BubbleManager } from '@/core/bubble-manager';
 import { ClickThroughManager } from '@/core/click-through';
+import { DataBackup } from '@/core/data-backup';
+import { DaylightTracker } from '@/core/daylight';
+import { DiskGuard } from '@/core/disk-guard';
+import { DevInspector } from '@/core/dev-inspector';
 import { EffectsManager } from '@/core/effects';
 import { setupInteraction } from '@/core/interaction';
 import { MenuController } from '@/core/menu';
+import { JobQueue } from '@/core/job-queue';
+import { OperationTracker } from '@/core/operations';
 import { MemorySystem } from '@/core/memory';
+import { SessionGuard } from '@/core/session-guard';
 import { StorageService } from '@/core/storage';
 import { UpdateController } from '@/core/updater';
+import { listenToCurrentWindow } from '@/core/window-events';
 import { EventBus } from '@/events';
+import { AutoMemoryRecorder } from '@/features/auto-memories';
 import { ContextAwareness } from '@/features/context-awareness';
 import { DialogueEngine } from '@/features/dialogue-engine';
+import { GitCelebration } from '@/features/git-celebration';
 import { GreetingManager } from '@/features/greeting';
 import { HourlyChime } from '@/features/hourly-chime';
 import { IdleCareScheduler } from '@/features/idle-care';
 import { MemoryCardManager } from '@/features/memory-card';
+import { MonthlyRecapGenerator } from '@/features/monthly-recap';
 import { MemoryPanelManager } from '@/features/memory-panel';
 import { DIALOGUE_ENTRIES } from '@/features/messages';
+import { MorningRoutine } from '@/features/morning-routine';
 import { PomodoroTimer } from '@/features/pomodoro';
 import { QuietModeManager } from '@/features/quiet-mode';
 import { SpecialDateManager } from '@/features/special-dates';
+import {
+  backendStatusSink,
+  discordStatusSink,
+  mqttStatusSink,
+  StatusPublisher,
+} from '@/features/status-publisher';
+import { TipsEngine } from '@/features/tips';
 import { SystemMonitor } from '@/features/system-monitor';
 import type { AppEvents } from '@/types';
 import { calcDaysSinceMet, initHint, showHint } from '@/utils';
@@ -94,6 +120,13 @@ async function initCore(): Promise<CoreModules> {
 
   const memory = new MemorySystem(bus, storage);
   const quietMode = new QuietModeManager(bus, storage);
+  const daylight = new DaylightTracker(bus);
+  const diskGuard = new DiskGuard(bus);
+  const sessionGuard = new SessionGuard(bus);
+  const jobs = new JobQueue(storage);
+  const operations = new OperationTracker(bus);
+  const backup = new DataBackup(jobs, storage, memory, operations);
+  const inspector = new DevInspector(bus);
   const updater = createUpdaterController();
 
   return {
@@ -109,6 +142,13 @@ async function initCore(): Promise<CoreModules> {
     effects,
     memory,
     quietMode,
+    daylight,
+    diskGuard,
+    sessionGuard,
+    jobs,
+    backup,
+    operations,
+    inspector,
     updater,
     petOwner,
   };
@@ -152,7 +192,23 @@ function initFeatures(core: CoreModules): FeatureModules {
     core.petOwner,
     core.petOwner.metDate,
   );
-  const memoryPanel = new MemoryPanelManager(core.memory, core.petOwner.metDate);
+  const autoMemories = new AutoMemoryRecorder(core.bus, core.storage);
+  const memoryPanel = new MemoryPanelManager(core.memory, core.petOwner.metDate, autoMemories);
+  const morningRoutine = new MorningRoutine(
+    greeting,
+    pomodoro,
+    core.storage,
+    core.quietMode,
+  );
+  const statusPublisher = new StatusPublisher(core.bus, core.quietMode);
+  statusPublisher.addSink(backendStatusSink);
+  statusPublisher.addSink(mqttStatusSink);
+  statusPublisher.addSink(discordStatusSink);
+  const tips = new TipsEngine(core.bus, core.bubble, core.memory, core.storage, core.quietMode);
+  const gitCelebration = new GitCelebration(core.bus, core.storage);
+  const monthlyRecap = new MonthlyRecapGenerator(core.bus, core.storage, () =>
+    autoMemories.getMemories(),
+  );
 
   return {
     idleCare,
@@ -164,6 +220,12 @@ function initFeatures(core: CoreModules): FeatureModules {
     greeting,
     memoryCard,
     memoryPanel,
+    morningRoutine,
+    statusPublisher,
+    tips,
+    autoMemories,
+    gitCelebration,
+    monthlyRecap,
   };
 }
 
@@ -172,6 +234,8 @@ async function main() {
     const core = await initCore();
     const features = initFeatures(core);
     bindBusinessEvents(core);
+    bindSessionEvents(core, features);
+    bindSettingsEvents(core, features);
 
     if (hasDirtyShutdown()) {
       console.warn('检测到上次非正常退出');
@@ -187,9 +251,30 @@ async function main() {
     const lifecycle = initLifecycle(core, features);
 
     core.menu.setItems(createMenuItems(core, features, lifecycle.gracefulShutdown));
+
+    // 开发者模式：尽早开始采集事件，并在菜单中加入检查器入口
+    core.inspector.addProbe('context', () => features.contextAwareness.currentContext);
+    core.inspector.addProbe('pomodoro', () => features.pomodoro.state);
+    core.inspector.addProbe('jobs', () => core.jobs.listJobs());
+    core.inspector.addProbe('traits', () => core.memory.getTraits());
+    core.inspector.addProbe('daylight', () => core.daylight.phase);
+    core.inspector.addProbe('lowDisk', () => core.diskGuard.isLow);
+    core.inspector.addProbe('sessionActive', () => core.sessionGuard.isActive);
+    core.inspector.addProbe('operations', () => core.operations.active);
+    if (await core.inspector.start()) {
+      core.menu.addItemBefore(createInspectorMenuItem(core), 'quit');
+    }
     core.bus.on('menu:opened', () => {
       const el = document.querySelector('[data-id="pomodoro"]');
       if (el) el.textContent = features.pomodoro.getStatusLabel();
+      const cancelEl = document.querySelector('[data-id="cancel-operations"]');
+      if (cancelEl) cancelEl.textContent = getCancelOperationsLabel(core);
+      const fidelityEl = document.querySelector('[data-id="activity-fidelity"]');
+      if (fidelityEl) {
+        void core.storage.getPreferences().then((prefs) => {
+          fidelityEl.textContent = getFidelityLabel(prefs.activityFidelity);
+        });
+      }
     });
 
     const cleanupInteraction = setupInteraction({
@@ -211,7 +296,7 @@ async function main() {
     await syncAutoStart(core.storage);
 
     lifecycle.setUnlistenAutostart(
-      listen('tray:toggle-autostart', async () => {
+      listenToCurrentWindow('tray:toggle-autostart', async () => {
         try {
           const enabled = await isEnabled();
           if (enabled) {
@@ -228,9 +313,10 @@ async function main() {
     );
 
     lifecycle.setUnlistenMemories(
-      listen('tray:open-memories', async () => {
+      listenToCurrentWindow('tray:open-memories', async () => {
         try {
           await features.memoryPanel.showPanel();
+          core.bus.emit('memories:opened');
         } catch (e) {
           console.warn('打开回忆面板失败:', e);
         }
@@ -248,7 +334,7 @@ async function main() {
     lifecycle.setAutoSaveTimer(autoSaveTimer);
 
     lifecycle.setUnlistenRequestQuit(
-      listen('app:request-quit', async () => {
+      listenToCurrentWindow('app:request-quit', async () => {
         await lifecycle.gracefulShutdown();
         try {
           await emit('app:shutdown-complete', {});
@@ -30,6 +30,8 @@ interface PanelData {
     activeHours: [number, number];
   }>;
   totalPomodoros: number;
+  /** 自动回忆，最新的在前（v1.3.0） */
+  autoMemories: Array<{ tag: 'auto' | 'cli'; kind: string; message: string; at: number }>;
 }
 
 /** 作息模式中文 */
@@ -139,6 +141,24 @@ function renderPanel(data: PanelData): void {
     el.textContent = text;
     insights.appendChild(el);
   }
+
+  // ─── 自动回忆 ───
+  const autoSection = document.getElementById('auto-memories-section')!;
+  const autoList = document.getElementById('auto-memories')!;
+  autoList.innerHTML = '';
+  const autoMemories = data.autoMemories ?? [];
+  autoSection.style.display = autoMemories.length > 0 ? '' : 'none';
+  for (const memory of autoMemories) {
+    const el = document.createElement('div');
+    el.className = 'insight-item auto-memory-item';
+    const date = getLocalDateKey(new Date(memory.at));
+    el.textContent = `${date} · ${memory.message}`;
+    const tag = document.createElement('span');
+    tag.className = 'auto-memory-tag';
+    tag.textContent = memory.tag === 'cli' ? '终端' : '自动';
+    el.appendChild(tag);
+    autoList.appendChild(el);
+  }
 }
 
 // ─── 入口 ───
@@ -0,0 +1,294 @@
+/**
+ * 设置窗口入口（v1.3.0）
+ *
+ * 每个区块是一个独立表单：打开时从偏好填充，提交时只写回本区块的字段，
+ * 写入后立即落盘并通知主窗口重新应用。仓库目录保存前由后端确认确实是 Git 仓库。
+ * 授权区块完整展示说明文字，用户点「同意」时按所展示说明的版本登记授权。
+ * 后台任务区块向主窗口请求任务快照，失败的任务可手动重试。
+ */
+import './settings.css';
+import { invoke } from '@tauri-apps/api/core';
+import {
+  CONSENT_KINDS_IN_USE,
+  getConsents,
+  grantConsent,
+  revokeConsent,
+  type ConsentStatus,
+} from './core/consent';
+import type { Job } from './core/job-queue';
+import {
+  ALERT_METRIC_LABELS,
+  validateAlertThreshold,
+  type AlertMetric,
+  type AlertThreshold,
+  type AlertThresholds,
+} from './features/alert-rules';
+import { StorageService, type UserPreferences } from './core/storage';
+import {
+  listJobs,
+  notifySettingsSaved,
+  onJobsSnapshot,
+  onSettingsFocus,
+  retryJob,
+  type SettingsSection,
+} from './core/settings-window';
+
+const storage = new StorageService();
+
+/** 表单区块：从偏好填充字段，提交时把字段转换回偏好 */
+interface SectionBinding {
+  section: SettingsSection;
+  formId: string;
+  fill(form: HTMLFormElement, prefs: UserPreferences): void;
+  /** 返回要写回的偏好；字段不合法时抛出错误，错误信息直接展示给用户 */
+  read(form: HTMLFormElement): Partial<UserPreferences> | Promise<Partial<UserPreferences>>;
+}
+
+function field(form: HTMLFormElement, name: string): HTMLInputElement | HTMLTextAreaElement {
+  return form.elements.namedItem(name) as HTMLInputElement | HTMLTextAreaElement;
+}
+
+const DEFAULT_MQTT_PORT = 1883;
+const DEFAULT_MQTT_TOPIC = 'bird-pet/status';
+
+/** 解析经纬度输入；超出范围时抛出错误 */
+function parseCoordinate(text: string, limit: number, label: string): number {
+  const value = Number(text);
+  if (!Number.isFinite(value) || Math.abs(value) > limit) {
+    throw new Error(`${label}需要在 -${limit} 到 ${limit} 之间`);
+  }
+  return value;
+}
+
+/** 报警阈值表单中的指标（字段名以指标为前缀） */
+const ALERT_METRICS: AlertMetric[] = ['cpu', 'memory'];
+
+/** 读取一个指标的阈值字段；不合法时抛出带指标名的错误 */
+function readThreshold(form: HTMLFormElement, metric: AlertMetric): AlertThreshold {
+  const threshold = {
+    triggerAbove: Number(field(form, `${metric}TriggerAbove`).value),
+    sustainMinutes: Number(field(form, `${metric}SustainMinutes`).value),
+    clearBelow: Number(field(form, `${metric}ClearBelow`).value),
+  };
+  const error = validateAlertThreshold(threshold);
+  if (error) throw new Error(`${ALERT_METRIC_LABELS[metric]}：${error}`);
+  return threshold;
+}
+
+const SECTIONS: SectionBinding[] = [
+  {
+    section: 'location',
+    formId: 'location-form',
+    fill(form, prefs) {
+      field(form, 'latitude').value = prefs.location ? String(prefs.location.latitude) : '';
+      field(form, 'longitude').value = prefs.location ? String(prefs.location.longitude) : '';
+    },
+    read(form) {
+      const latitude = field(form, 'latitude').value.trim();
+      const longitude = field(form, 'longitude').value.trim();
+      if (!latitude && !longitude) return { location: null };
+      if (!latitude || !longitude) throw new Error('请同时填写纬度和经度，或都留空');
+      return {
+        location: {
+          latitude: parseCoordinate(latitude, 90, '纬度'),
+          longitude: parseCoordinate(longitude, 180, '经度'),
+        },
+      };
+    },
+  },
+  {
+    section: 'status',
+    formId: 'status-form',
+    fill(form, prefs) {
+      field(form, 'mqttHost').value = prefs.statusMqtt?.host ?? '';
+      field(form, 'mqttPort').value = prefs.statusMqtt ? String(prefs.statusMqtt.port) : '';
+      field(form, 'mqttTopic').value = prefs.statusMqtt?.topic ?? '';
+      field(form, 'discordClientId').value = prefs.statusDiscordClientId ?? '';
+    },
+    read(form) {
+      const host = field(form, 'mqttHost').value.trim();
+      const portText = field(form, 'mqttPort').value.trim();
+      const port = portText ? Number(portText) : DEFAULT_MQTT_PORT;
+      if (host && (!Number.isInteger(port) || port < 1 || port > 65535)) {
+        throw new Error('MQTT 端口需要是 1-65535 之间的整数');
+      }
+      const topic = field(form, 'mqttTopic').value.trim() || DEFAULT_MQTT_TOPIC;
+      const clientId = field(form, 'discordClientId').value.trim();
+      return {
+        statusMqtt: host ? { host, port, topic } : null,
+        statusDiscordClientId: clientId || null,
+      };
+    },
+  },
+  {
+    section: 'alerts',
+    formId: 'alerts-form',
+    fill(form, prefs) {
+      for (const metric of ALERT_METRICS) {
+        const threshold = prefs.alertThresholds[metric];
+        field(form, `${metric}TriggerAbove`).value = String(threshold.triggerAbove);
+        field(form, `${metric}SustainMinutes`).value = String(threshold.sustainMinutes);
+        field(form, `${metric}ClearBelow`).value = String(threshold.clearBelow);
+      }
+    },
+    read(form) {
+      const alertThresholds = {} as AlertThresholds;
+      for (const metric of ALERT_METRICS) alertThresholds[metric] = readThreshold(form, metric);
+      return { alertThresholds };
+    },
+  },
+  {
+    section: 'git',
+    formId: 'git-form',
+    fill(form, prefs) {
+      field(form, 'repos').value = prefs.gitWatchRepos.join('\n');
+    },
+    async read(form) {
+      const lines = field(form, 'repos').value.split('\n').map((line) => line.trim());
+      const repos = [...new Set(lines.filter(Boolean))];
+      const invalid = await invoke<string[]>('find_invalid_repos', { paths: repos });
+      if (invalid.length > 0) throw new Error(`不是 Git 仓库：${invalid.join('、')}`);
+      return { gitWatchRepos: repos };
+    },
+  },
+];
+
+function bind(binding: SectionBinding, prefs: UserPreferences): void {
+  const form = document.getElementById(binding.formId) as HTMLFormElement | null;
+  if (!form) return;
+  const result = form.querySelector('.save-result')!;
+  binding.fill(form, prefs);
+
+  form.addEventListener('submit', async (e) => {
+    e.preventDefault();
+    try {
+      await storage.setPreferences(await binding.read(form));
+      await storage.save();
+      binding.fill(form, await storage.getPreferences());
+      await notifySettingsSaved(binding.section);
+      result.textContent = '已保存 ✓';
+    } catch (err) {
+      result.textContent = err instanceof Error ? err.message : '保存失败，请稍后再试';
+    }
+  });
+}
+
+/** 授权类别的标题 */
+const CONSENT_TITLES: Record<ConsentStatus['kind'], string> = {
+  activity_titles: '窗口标题感知',
+  clipboard: '剪贴板',
+  microphone_level: '麦克风音量',
+  telemetry: '匿名使用统计',
+};
+
+async function renderConsents(): Promise<void> {
+  const container = document.getElementById('consents')!;
+  const statuses = (await getConsents()).filter((s) => CONSENT_KINDS_IN_USE.includes(s.kind));
+  container.innerHTML = '';
+  for (const status of statuses) {
+    const item = document.createElement('div');
+    item.className = 'consent-item';
+    const title = document.createElement('div');
+    title.className = 'consent-title';
+    title.textContent = `${CONSENT_TITLES[status.kind]} · ${status.granted ? '已同意' : '未开启'}`;
+    const text = document.createElement('p');
+    text.className = 'consent-text';
+    text.textContent = status.text;
+    const actions = document.createElement('div');
+    actions.className = 'section-actions';
+    const result = document.createElement('span');
+    result.className = 'save-result';
+    const button = document.createElement('button');
+    button.type = 'button';
+    button.textContent = status.granted ? '撤回' : '我已阅读，同意';
+    if (status.granted) button.className = 'secondary';
+    button.addEventListener('click', async () => {
+      button.disabled = true;
+      try {
+        // 只登记本窗口展示过的说明版本；说明已更新时后端会拒绝
+        if (status.granted) await revokeConsent(status.kind);
+        else await grantConsent(status.kind, status.textVersion);
+        await notifySettingsSaved('consent');
+        await renderConsents();
+      } catch (err) {
+        result.textContent = err instanceof Error ? err.message : String(err);
+        button.disabled = false;
+      }
+    });
+    actions.append(result, button);
+    item.append(title, text, actions);
+    container.appendChild(item);
+  }
+}
+
+function scrollToSection(section: string): void {
+  document.querySelector(`[data-section="${section}"]`)?.scrollIntoView({ block: 'start' });
+}
+
+/** 任务类型的显示名 */
+const JOB_LABELS: Record<string, string> = {
+  backup: '数据备份',
+};
+
+/** 任务状态的显示名 */
+const JOB_STATUS_LABELS: Record<Job['status'], string> = {
+  pending: '等待执行',
+  running: '执行中',
+  failed: '已失败',
+};
+
+function renderJobs(jobs: Job[]): void {
+  const list = document.getElementById('jobs')!;
+  list.innerHTML = '';
+  if (jobs.length === 0) {
+    const empty = document.createElement('li');
+    empty.className = 'job-empty';
+    empty.textContent = '没有排队中的任务';
+    list.appendChild(empty);
+    return;
+  }
+  for (const job of jobs) {
+    const item = document.createElement('li');
+    item.className = 'job-item';
+    const info = document.createElement('div');
+    info.className = 'job-info';
+    const title = document.createElement('div');
+    const label = JOB_LABELS[job.kind] ?? job.kind;
+    title.textContent = `${label} · ${JOB_STATUS_LABELS[job.status]}（已尝试 ${job.attempts} 次）`;
+    info.appendChild(title);
+    if (job.lastError) {
+      const error = document.createElement('div');
+      error.className = 'job-error';
+      error.textContent = job.lastError;
+      error.title = job.lastError;
+      info.appendChild(error);
+    }
+    item.appendChild(info);
+    if (job.status === 'failed') {
+      const retry = document.createElement('button');
+      retry.type = 'button';
+      retry.textContent = '重试';
+      retry.addEventListener('click', () => {
+        retry.disabled = true;
+        void retryJob(job.id);
+      });
+      item.appendChild(retry);
+    }
+    list.appendChild(item);
+  }
+}
+
+async function init(): Promise<void> {
+  const prefs = await storage.getPreferences();
+  for (const binding of SECTIONS) bind(binding, prefs);
+  await renderConsents();
+
+  await onSettingsFocus(scrollToSection);
+  if (location.hash) scrollToSection(location.hash.slice(1));
+
+  await onJobsSnapshot(renderJobs);
+  document.getElementById('jobs-refresh')!.addEventListener('click', () => void listJobs());
+  await listJobs();
+}
+
+void init();
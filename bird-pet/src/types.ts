@@ -31,9 +31,19 @@ export interface BubbleDismissedPayload {
 // ────────────────────────────────────────
 
 import type { AppContext } from './features/dialogue-engine';
+import type { UserStatus } from './features/status-publisher';
 
-/** 记忆事件类型 */
-export type MemoryEventType = 'interaction' | 'context_switch' | 'pomodoro_complete' | 'app_active';
+/** 记忆事件类型；tracking_paused / tracking_resumed 为系统界面导致的采集暂停与恢复（v1.3.0） */
+export type MemoryEventType =
+  | 'interaction'
+  | 'context_switch'
+  | 'pomodoro_complete'
+  | 'app_active'
+  | 'tracking_paused'
+  | 'tracking_resumed';
+
+/** 无法追踪前台窗口的原因：UAC / 锁屏等安全桌面，或以管理员身份运行的窗口（v1.3.0） */
+export type UntrackedReason = 'secure_desktop' | 'elevated';
 
 /** 单条记忆事件 */
 export interface MemoryEvent {
@@ -42,6 +52,46 @@ export interface MemoryEvent {
   data?: Record<string, unknown>;
 }
 
+/** 时间线片段（v1.3.0，由 Rust 端 get_timeline 拼装） */
+export interface TimelineSegment {
+  /**
+   * activity=应用活动，idle=闲置，focus/break=番茄专注/休息（叠加在活动之上），
+   * untracked=处于系统界面、未追踪
+   */
+  kind: 'activity' | 'idle' | 'focus' | 'break' | 'untracked';
+  /** 开始时间戳（毫秒） */
+  start: number;
+  /** 结束时间戳（毫秒，不含） */
+  end: number;
+  /** 行为上下文，仅 activity / idle 有；untracked 固定为 'system' */
+  category: AppContext | 'system' | null;
+  /** 进入该上下文时的前台应用名 */
+  app: string | null;
+}
+
+/** 报告周期（v1.3.0） */
+export type ReportPeriod = 'week';
+
+/** 报告导出格式（v1.3.0；PDF 暂不提供，可导出 HTML 后用浏览器打印） */
+export type ReportFormat = 'html';
+
+/** 长任务状态（v1.3.0） */
+export type OperationState = 'running' | 'done' | 'cancelled' | 'failed';
+
+/** 长任务进度（Rust 端 `op:progress` 事件负载） */
+export interface OperationProgress {
+  id: string;
+  /** 操作类型，如 'report' */
+  kind: string;
+  state: OperationState;
+  /** 已完成步数 */
+  done: number;
+  /** 总步数 */
+  total: number;
+  /** 当前步骤说明；失败时为错误信息 */
+  message: string | null;
+}
+
 /** 每日汇总 */
 export interface DailySummary {
   /** 日期 YYYY-MM-DD */
@@ -70,6 +120,23 @@ export interface UserProfile {
   dailySummaries: DailySummary[];
 }
 
+/** 自动回忆类别（v1.3.0）；note 为 memo-cli 记下的回忆 */
+export type AutoMemoryKind =
+  | 'new_machine'
+  | 'os_upgrade'
+  | 'new_monitor'
+  | 'pomodoro_total'
+  | 'note';
+
+/** 回忆面板「小鸟记下的瞬间」条目：auto 由系统事件自动生成，cli 来自终端 memo-cli */
+export interface AutoMemory {
+  tag: 'auto' | 'cli';
+  kind: AutoMemoryKind;
+  message: string;
+  /** 记录时间（ms） */
+  at: number;
+}
+
 /** 记忆快照（传入对话引擎的聚合数据） */
 export interface MemorySnapshot {
   affinityLevel: number;
@@ -77,6 +144,69 @@ export interface MemorySnapshot {
   dominantApp: AppContext;
   streak: number;
   workloadTrend: 'increasing' | 'stable' | 'decreasing';
+  /** 长期信任度 0-1（v1.3.0） */
+  trust: number;
+  /** 长期熟悉度 0-1（v1.3.0） */
+  familiarity: number;
+}
+
+/** 昼夜相位（v1.3.0，由 Rust 端按日出日落计算） */
+export type DaylightPhase = 'dawn' | 'day' | 'dusk' | 'night';
+
+/** 昼夜信息 */
+export interface DaylightInfo {
+  phase: DaylightPhase;
+  /** 今日日出时间戳（ms），极昼/极夜为 null */
+  sunrise: number | null;
+  /** 今日日落时间戳（ms），极昼/极夜为 null */
+  sunset: number | null;
+}
+
+/** 数据目录所在磁盘的状态（v1.3.0，由 Rust 端检查） */
+export interface DiskStatus {
+  /** 剩余空间（字节），无法识别磁盘时为 null */
+  freeBytes: number | null;
+  /** 低磁盘阈值（字节） */
+  thresholdBytes: number;
+  /** 是否处于低磁盘模式 */
+  low: boolean;
+}
+
+/** 当前用户会话的连接状态（v1.3.0，由 Rust 端检查） */
+export interface SessionStatus {
+  /** 会话是否连接在本机或远程桌面上；切换到其他用户或远程断开时为 false */
+  active: boolean;
+}
+
+/** 仓库中的代码动态（v1.3.0，由 Rust 端监视 .git 推送） */
+export interface GitActivity {
+  kind: 'commit' | 'push';
+  /** 仓库目录名 */
+  repo: string;
+  /** 推送到的远程分支（如 origin/main），提交时为 null */
+  branch: string | null;
+  /** 提交说明首行，取不到时为 null */
+  summary: string | null;
+}
+
+/** 月度回顾（v1.3.0，月末由 MonthlyRecapGenerator 生成） */
+export interface MonthlyRecap {
+  /** 月份 YYYY-MM */
+  month: string;
+  /** 使用最多的应用（按分钟降序，最多 5 个） */
+  topApps: { app: string; minutes: number }[];
+  /** 完成番茄最多的一天，本月没有番茄时为 null */
+  bestFocusDay: { date: string; pomodoros: number } | null;
+  /** 本月完成的番茄总数 */
+  totalPomodoros: number;
+  /** 本月有互动或番茄的天数 */
+  activeDays: number;
+  /** 回忆亮点（本月的自动 / 终端回忆） */
+  highlights: string[];
+  /** 本月达成的里程碑 */
+  milestones: string[];
+  /** 生成时间（ms） */
+  generatedAt: number;
 }
 
 /**
@@ -106,10 +236,34 @@ export type AppEvents = {
   'pomodoro:focus': void;
   'pomodoro:break': void;
   'pomodoro:stop': void;
-  /** 行为上下文变更（v0.3.0） */
-  'context:changed': { from: AppContext; to: AppContext };
+  /** 行为上下文变更（v0.3.0）；app 为切换时的前台应用名（v1.3.0） */
+  'context:changed': { from: AppContext; to: AppContext; app?: string };
+  /** 进入系统界面，暂停行为采集（v1.3.0） */
+  'tracking:paused': { reason: UntrackedReason };
+  /** 离开系统界面，恢复行为采集（v1.3.0） */
+  'tracking:resumed': void;
   /** 记忆系统洞察事件（v0.4.0） */
   'memory:insight': { type: string; message: string };
   /** 记忆系统里程碑事件（v1.0.0） */
   'memory:milestone': { kind: string; value: number; message: string };
+  /** 回忆面板被打开（v1.3.0） */
+  'memories:opened': void;
+  /** 自动记录了一条回忆（v1.3.0） */
+  'memory:auto-recorded': AutoMemory;
+  /** 用户状态变更（v1.3.0） */
+  'status:changed': { from: UserStatus; to: UserStatus };
+  /** 昼夜相位变更（v1.3.0） */
+  'daylight:phase-changed': DaylightInfo;
+  /** 位置被清除，昼夜相位不再可用（v1.3.0） */
+  'daylight:cleared': void;
+  /** 进入/退出低磁盘模式（v1.3.0） */
+  'disk:space-changed': DiskStatus;
+  /** 用户会话断开 / 重新连接（快速切换用户、远程桌面，v1.3.0） */
+  'session:changed': SessionStatus;
+  /** 配置的仓库有新提交或推送（v1.3.0） */
+  'git:activity': GitActivity;
+  /** 上月的月度回顾已生成（v1.3.0） */
+  'recap:ready': MonthlyRecap;
+  /** 长任务进度更新（v1.3.0） */
+  'op:progress': OperationProgress;
 };
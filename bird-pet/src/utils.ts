@@ -78,3 +78,15 @@ export function loadImage(src: string): Promise<HTMLImageElement> {
     img.src = src;
   });
 }
+
+let debugEnabled = true;
+
+/** 开关调试输出（低磁盘模式下由 DiskGuard 关闭） */
+export function setDebugEnabled(enabled: boolean): void {
+  debugEnabled = enabled;
+}
+
+/** 调试输出：关闭时静默；警告和错误请直接用 console.warn / console.error */
+export function debugLog(...args: unknown[]): void {
+  if (debugEnabled) console.debug(...args);
+}
@@ -0,0 +1,163 @@
+/**
+ * 活动采样精细度单元测试
+ *
+ * 覆盖 appendContextSwitch：high 逐次记录、medium 按分钟合并、low 按小时合并且不记应用名，
+ * 合并记录保留真实时间与桶内停留时长、不跨越暂停事件合并；
+ * compactContextSwitches 只压缩指定时间之前的切换。
+ */
+import { describe, it, expect } from 'vitest';
+import {
+  appendContextSwitch,
+  compactContextSwitches,
+  getFidelitySettings,
+  nextFidelity,
+  type ActivityFidelity,
+} from '../src/core/activity-fidelity';
+import type { AppContext } from '../src/features/dialogue-engine';
+import type { MemoryEvent } from '../src/types';
+
+const MINUTE = 60_000;
+const HOUR = 60 * MINUTE;
+/** 某个整点 */
+const BASE = 1000 * HOUR;
+
+/** 依次写入一组切换：[from, to, app, 时间] */
+function record(
+  fidelity: ActivityFidelity,
+  changes: [AppContext, AppContext, string, number][],
+  events: MemoryEvent[] = [],
+): MemoryEvent[] {
+  for (const [from, to, app, at] of changes) {
+    appendContextSwitch(events, { from, to, app }, at, fidelity);
+  }
+  return events;
+}
+
+describe('appendContextSwitch', () => {
+  it('high 每次切换都记录原始时间和应用名', () => {
+    const events = record('high', [
+      ['idle', 'coding', 'Code', BASE + 5],
+      ['coding', 'browsing', 'Chrome', BASE + 10],
+    ]);
+    expect(events).toEqual([
+      {
+        type: 'context_switch',
+        timestamp: BASE + 5,
+        data: { from: 'idle', to: 'coding', app: 'Code' },
+      },
+      {
+        type: 'context_switch',
+        timestamp: BASE + 10,
+        data: { from: 'coding', to: 'browsing', app: 'Chrome' },
+      },
+    ]);
+  });
+
+  it('medium 同一分钟内的切换合并为一条', () => {
+    const events = record('medium', [['idle', 'coding', 'Code', BASE + 1000]]);
+    events.push({ type: 'interaction', timestamp: BASE + 2000 });
+    record(
+      'medium',
+      [
+        ['coding', 'music', 'Spotify', BASE + 30_000],
+        ['music', 'coding', 'Code', BASE + MINUTE],
+      ],
+      events,
+    );
+
+    const switches = events.filter((e) => e.type === 'context_switch');
+    expect(switches).toHaveLength(2);
+    expect(switches[0]).toEqual({
+      type: 'context_switch',
+      timestamp: BASE + 1000,
+      data: {
+        from: 'idle',
+        to: 'music',
+        app: 'Spotify',
+        durations: { coding: 29_000 },
+        lastAt: BASE + 30_000,
+      },
+    });
+    expect(switches[1].timestamp).toBe(BASE + MINUTE);
+  });
+
+  it('low 每小时一条且不记录应用名', () => {
+    const events = record('low', [
+      ['idle', 'coding', 'Code', BASE + MINUTE],
+      ['coding', 'gaming', 'Steam', BASE + 50 * MINUTE],
+    ]);
+    expect(events).toEqual([
+      {
+        type: 'context_switch',
+        timestamp: BASE + MINUTE,
+        data: {
+          from: 'idle',
+          to: 'gaming',
+          durations: { coding: 49 * MINUTE },
+          lastAt: BASE + 50 * MINUTE,
+        },
+      },
+    ]);
+  });
+
+  it('中间有暂停采集事件时不合并，事件保持时间顺序', () => {
+    const events = record('low', [['idle', 'coding', 'Code', BASE + MINUTE]]);
+    events.push({ type: 'tracking_paused', timestamp: BASE + 2 * MINUTE });
+    record('low', [['coding', 'browsing', 'Chrome', BASE + 10 * MINUTE]], events);
+
+    expect(events.map((e) => [e.type, e.timestamp])).toEqual([
+      ['context_switch', BASE + MINUTE],
+      ['tracking_paused', BASE + 2 * MINUTE],
+      ['context_switch', BASE + 10 * MINUTE],
+    ]);
+  });
+});
+
+describe('compactContextSwitches', () => {
+  it('只把指定时间之前的切换按小时合并，其余事件原样保留', () => {
+    const events = record('high', [
+      ['idle', 'coding', 'Code', BASE + MINUTE],
+      ['coding', 'browsing', 'Chrome', BASE + 2 * MINUTE],
+    ]);
+    events.push({ type: 'interaction', timestamp: BASE + 3 * MINUTE });
+    record('high', [['browsing', 'music', 'Spotify', BASE + HOUR + MINUTE]], events);
+
+    expect(compactContextSwitches(events, BASE + HOUR)).toEqual([
+      {
+        type: 'context_switch',
+        timestamp: BASE + MINUTE,
+        data: {
+          from: 'idle',
+          to: 'browsing',
+          durations: { coding: MINUTE },
+          lastAt: BASE + 2 * MINUTE,
+        },
+      },
+      { type: 'interaction', timestamp: BASE + 3 * MINUTE },
+      events[3],
+    ]);
+  });
+
+  it('重复压缩结果不变', () => {
+    const events = record('high', [['idle', 'coding', 'Code', BASE + MINUTE]]);
+    const once = compactContextSwitches(events, BASE + HOUR);
+    expect(compactContextSwitches(once, BASE + HOUR)).toEqual(once);
+  });
+});
+
+describe('fidelity settings', () => {
+  it('精细度越低轮询越慢', () => {
+    expect(getFidelitySettings('high').pollMs).toBeLessThan(getFidelitySettings('medium').pollMs);
+    expect(getFidelitySettings('medium').pollMs).toBeLessThan(getFidelitySettings('low').pollMs);
+  });
+
+  it('旧数据缺字段时按 high 处理', () => {
+    expect(getFidelitySettings(undefined)).toBe(getFidelitySettings('high'));
+  });
+
+  it('菜单按 high → medium → low → high 循环', () => {
+    expect(nextFidelity('high')).toBe('medium');
+    expect(nextFidelity('medium')).toBe('low');
+    expect(nextFidelity('low')).toBe('high');
+  });
+});
@@ -0,0 +1,135 @@
+/**
+ * AlertRuleEngine 单元测试
+ *
+ * 覆盖：持续时长判定、短时尖峰不触发、回差解除、多规则独立计时，
+ * 以及偏好阈值到规则的转换与校验。
+ */
+import { describe, it, expect } from 'vitest';
+import {
+  AlertRuleEngine,
+  DEFAULT_ALERT_RULES,
+  rulesFromThresholds,
+  validateAlertThreshold,
+  type AlertRule,
+} from '../src/features/alert-rules';
+
+const MINUTE = 60 * 1000;
+
+const CPU_RULE: AlertRule = {
+  id: 'cpu-high',
+  metric: 'cpu',
+  triggerAbove: 85,
+  sustainMs: 3 * MINUTE,
+  clearBelow: 60,
+};
+
+const MEMORY_RULE: AlertRule = {
+  id: 'memory-high',
+  metric: 'memory',
+  triggerAbove: 90,
+  sustainMs: 0,
+  clearBelow: 80,
+};
+
+function sample(cpu: number, memory = 20) {
+  return { cpu, memory };
+}
+
+describe('AlertRuleEngine', () => {
+  it('持续超过阈值达到时长才触发', () => {
+    const engine = new AlertRuleEngine([CPU_RULE]);
+
+    expect(engine.evaluate(sample(95), 0)).toEqual([]);
+    expect(engine.evaluate(sample(92), 2 * MINUTE)).toEqual([]);
+    expect(engine.evaluate(sample(90), 3 * MINUTE)).toEqual([CPU_RULE]);
+    expect(engine.isActive('cpu-high')).toBe(true);
+  });
+
+  it('中途回落到阈值以下会重新计时', () => {
+    const engine = new AlertRuleEngine([CPU_RULE]);
+
+    engine.evaluate(sample(95), 0);
+    engine.evaluate(sample(70), 1 * MINUTE);
+    engine.evaluate(sample(95), 2 * MINUTE);
+
+    expect(engine.evaluate(sample(95), 4 * MINUTE)).toEqual([]);
+    expect(engine.evaluate(sample(95), 5 * MINUTE)).toEqual([CPU_RULE]);
+  });
+
+  it('报警后需低于解除阈值才复位，期间不重复触发', () => {
+    const engine = new AlertRuleEngine([CPU_RULE]);
+    engine.evaluate(sample(95), 0);
+    engine.evaluate(sample(95), 3 * MINUTE);
+
+    // 落在回差区间内：仍处于报警状态
+    expect(engine.evaluate(sample(70), 4 * MINUTE)).toEqual([]);
+    expect(engine.evaluate(sample(95), 10 * MINUTE)).toEqual([]);
+    expect(engine.isActive('cpu-high')).toBe(true);
+
+    // 低于解除阈值后复位，再次持续超标才会报警
+    engine.evaluate(sample(50), 11 * MINUTE);
+    expect(engine.isActive('cpu-high')).toBe(false);
+    expect(engine.evaluate(sample(95), 12 * MINUTE)).toEqual([]);
+    expect(engine.evaluate(sample(95), 15 * MINUTE)).toEqual([CPU_RULE]);
+  });
+
+  it('多条规则独立计时，sustainMs=0 立即触发', () => {
+    const engine = new AlertRuleEngine([CPU_RULE, MEMORY_RULE]);
+
+    expect(engine.evaluate(sample(95, 95), 0)).toEqual([MEMORY_RULE]);
+    expect(engine.evaluate(sample(95, 95), 3 * MINUTE)).toEqual([CPU_RULE]);
+  });
+
+  it('reset() 清空计时与报警状态', () => {
+    const engine = new AlertRuleEngine([CPU_RULE]);
+    engine.evaluate(sample(95), 0);
+    engine.evaluate(sample(95), 3 * MINUTE);
+
+    engine.reset();
+
+    expect(engine.isActive('cpu-high')).toBe(false);
+    expect(engine.evaluate(sample(95), 4 * MINUTE)).toEqual([]);
+  });
+});
+
+describe('rulesFromThresholds', () => {
+  it('默认阈值生成默认规则（CPU 85%/3 分钟，内存 90%/1 分钟）', () => {
+    expect(DEFAULT_ALERT_RULES).toEqual([CPU_RULE, { ...MEMORY_RULE, sustainMs: MINUTE }]);
+  });
+
+  it('按偏好中的阈值生成规则，分钟换算为毫秒', () => {
+    const rules = rulesFromThresholds({
+      cpu: { triggerAbove: 70, sustainMinutes: 0.5, clearBelow: 50 },
+      memory: { triggerAbove: 95, sustainMinutes: 0, clearBelow: 85 },
+    });
+    expect(rules).toEqual([
+      { id: 'cpu-high', metric: 'cpu', triggerAbove: 70, sustainMs: 30_000, clearBelow: 50 },
+      { id: 'memory-high', metric: 'memory', triggerAbove: 95, sustainMs: 0, clearBelow: 85 },
+    ]);
+  });
+
+  it('缺失或不合法的指标回退到默认阈值，不影响其他指标', () => {
+    const rules = rulesFromThresholds({
+      cpu: { triggerAbove: 50, sustainMinutes: 1, clearBelow: 60 },
+    });
+    expect(rules).toEqual(DEFAULT_ALERT_RULES);
+
+    expect(rulesFromThresholds(undefined)).toEqual(DEFAULT_ALERT_RULES);
+  });
+
+  it('validateAlertThreshold 拒绝越界与解除阈值高于触发阈值', () => {
+    const valid = { triggerAbove: 85, sustainMinutes: 3, clearBelow: 60 };
+    expect(validateAlertThreshold(valid)).toBe(null);
+
+    const invalid = [
+      { ...valid, triggerAbove: 0, clearBelow: 0 },
+      { ...valid, triggerAbove: 101 },
+      { ...valid, triggerAbove: 80, clearBelow: 90 },
+      { ...valid, sustainMinutes: -1 },
+      { ...valid, triggerAbove: Number.NaN },
+    ];
+    for (const threshold of invalid) {
+      expect(validateAlertThreshold(threshold)).not.toBe(null);
+    }
+  });
+});
@@ -0,0 +1,152 @@
+/**
+ * 自动回忆记录器单元测试
+ *
+ * 覆盖：基线不生成回忆、新电脑 / 系统升级 / 新显示器检测、番茄累计里程碑、默认关闭、并入 memo-cli 回忆。
+ */
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}));
+
+import { EventBus } from '../src/events';
+import {
+  AutoMemoryRecorder,
+  detectMachineChanges,
+  type AutoMemoryState,
+  type MachineInfo,
+} from '../src/features/auto-memories';
+import { STORE_KEYS } from '../src/core/storage';
+import type { AppEvents } from '../src/types';
+
+function machine(overrides: Partial<MachineInfo> = {}): MachineInfo {
+  return {
+    host_name: 'home-pc',
+    os_name: 'Windows',
+    os_version: '10 (19045)',
+    monitors: [{ name: 'DELL U2720Q', width: 3840, height: 2160 }],
+    ...overrides,
+  };
+}
+
+function emptyState(): AutoMemoryState {
+  return {
+    initialized: false,
+    knownHosts: [],
+    osVersions: {},
+    knownMonitors: [],
+    pomodoroTotal: 0,
+    memories: [],
+  };
+}
+
+function createRecorder(
+  options: {
+    enabled?: boolean;
+    stored?: Partial<AutoMemoryState>;
+    cliMemories?: { message: string; at: number }[];
+  } = {},
+) {
+  const bus = new EventBus<AppEvents>();
+  const data: Record<string, unknown> = { [STORE_KEYS.AUTO_MEMORIES]: options.stored };
+  const storage = {
+    getPreferences: vi.fn(async () => ({ autoMemoriesEnabled: options.enabled ?? true })),
+    get: vi.fn(async (key: string, fallback: unknown) => data[key] ?? fallback),
+    set: vi.fn(async (key: string, value: unknown) => {
+      data[key] = value;
+    }),
+  };
+  const readMachine = vi.fn(async () => machine());
+  const takeCliMemories = vi.fn(async () => options.cliMemories ?? []);
+  const recorder = new AutoMemoryRecorder(bus, storage as any, readMachine, takeCliMemories);
+  return { recorder, bus, storage, readMachine };
+}
+
+describe('detectMachineChanges', () => {
+  it('首次检测只记录基线', () => {
+    const state = emptyState();
+    expect(detectMachineChanges(state, machine())).toEqual([]);
+    expect(state.initialized).toBe(true);
+    expect(state.knownHosts).toEqual(['home-pc']);
+    expect(state.knownMonitors).toEqual(['DELL U2720Q@3840x2160']);
+  });
+
+  it('识别系统升级与新显示器', () => {
+    const state = emptyState();
+    detectMachineChanges(state, machine());
+    const found = detectMachineChanges(
+      state,
+      machine({
+        os_version: '11 (22631)',
+        monitors: [
+          { name: 'DELL U2720Q', width: 3840, height: 2160 },
+          { name: null, width: 1920, height: 1080 },
+        ],
+      }),
+    );
+    expect(found.map((f) => f.kind)).toEqual(['os_upgrade', 'new_monitor']);
+    expect(found[0].message).toContain('Windows 11 (22631)');
+  });
+
+  it('新电脑只记录一条，其显示器直接计入基线', () => {
+    const state = emptyState();
+    detectMachineChanges(state, machine());
+    const found = detectMachineChanges(
+      state,
+      machine({
+        host_name: 'office-laptop',
+        monitors: [{ name: 'BOE', width: 2560, height: 1600 }],
+      }),
+    );
+    expect(found.map((f) => f.kind)).toEqual(['new_machine']);
+    expect(detectMachineChanges(state, machine({ host_name: 'office-laptop' }))).toEqual([]);
+  });
+});
+
+describe('AutoMemoryRecorder', () => {
+  it('默认关闭时不读取本机信息也不计数', async () => {
+    const { recorder, bus, readMachine, storage } = createRecorder({ enabled: false });
+    await recorder.start();
+    bus.emit('pomodoro:break');
+    expect(readMachine).not.toHaveBeenCalled();
+    expect(storage.set).not.toHaveBeenCalled();
+    recorder.stop();
+  });
+
+  it('第 100 个番茄生成带 auto 标签的回忆', async () => {
+    const baseline = emptyState();
+    detectMachineChanges(baseline, machine());
+    const { recorder, bus } = createRecorder({ stored: { ...baseline, pomodoroTotal: 99 } });
+    const handler = vi.fn();
+    bus.on('memory:auto-recorded', handler);
+    await recorder.start();
+
+    bus.emit('pomodoro:break');
+    await vi.waitFor(() => expect(handler).toHaveBeenCalledTimes(1));
+    expect(handler.mock.calls[0][0]).toMatchObject({ tag: 'auto', kind: 'pomodoro_total' });
+    expect(recorder.getMemories()[0].message).toContain('第 100 个番茄钟');
+    recorder.stop();
+  });
+
+  it('关闭时也并入 memo-cli 记下的回忆，并按时间排序', async () => {
+    const { recorder, storage } = createRecorder({
+      enabled: false,
+      stored: {
+        memories: [{ tag: 'auto', kind: 'new_monitor', message: '新屏幕', at: 200 }],
+      },
+      cliMemories: [
+        { message: '修好了一个老 bug', at: 100 },
+        { message: '下班啦', at: 300 },
+      ],
+    });
+    await recorder.start();
+
+    expect(recorder.getMemories().map((m) => [m.tag, m.message])).toEqual([
+      ['cli', '下班啦'],
+      ['auto', '新屏幕'],
+      ['cli', '修好了一个老 bug'],
+    ]);
+    expect(storage.set).toHaveBeenCalledTimes(1);
+    recorder.stop();
+  });
+});
@@ -0,0 +1,69 @@
+/**
+ * 授权封装单元测试
+ *
+ * 覆盖：同意时携带展示的说明版本、撤回，
+ * 以及 needsConsentPrompt 只提示从未对当前说明做过决定的用户。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}));
+
+import { invoke } from '@tauri-apps/api/core';
+import {
+  grantConsent,
+  needsConsentPrompt,
+  revokeConsent,
+  type ConsentStatus,
+} from '../src/core/consent';
+
+function status(granted: boolean, record: ConsentStatus['record'] = null): ConsentStatus {
+  return {
+    kind: 'activity_titles',
+    granted,
+    text: '说明',
+    textVersion: 3,
+    record,
+  };
+}
+
+describe('授权命令', () => {
+  beforeEach(() => {
+    vi.mocked(invoke).mockReset();
+  });
+
+  it('同意时携带展示的说明版本', async () => {
+    await grantConsent('activity_titles', 3);
+    expect(invoke).toHaveBeenLastCalledWith('grant_consent', {
+      kind: 'activity_titles',
+      textVersion: 3,
+    });
+  });
+
+  it('撤回', async () => {
+    await revokeConsent('activity_titles');
+    expect(invoke).toHaveBeenLastCalledWith('revoke_consent', { kind: 'activity_titles' });
+  });
+});
+
+describe('needsConsentPrompt', () => {
+  it('从未操作过且未提示过时需要提示', () => {
+    expect(needsConsentPrompt(status(false), 0)).toBe(true);
+  });
+
+  it('同一说明版本只提示一次', () => {
+    expect(needsConsentPrompt(status(false), 3)).toBe(false);
+  });
+
+  it('已授权或明确撤回过当前说明时不提示', () => {
+    const revoked = { granted: false, textVersion: 3, updatedAt: 1 };
+    expect(needsConsentPrompt(status(true), 0)).toBe(false);
+    expect(needsConsentPrompt(status(false, revoked), 0)).toBe(false);
+  });
+
+  it('只同意过旧版说明时重新提示', () => {
+    const outdated = { granted: true, textVersion: 2, updatedAt: 1 };
+    expect(needsConsentPrompt(status(false, outdated), 2)).toBe(true);
+  });
+});
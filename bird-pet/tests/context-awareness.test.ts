@@ -50,8 +50,8 @@ describe('ContextAwareness', () => {
     await (awareness as any).poll();
 
     expect(events).toEqual([
-      { from: 'unknown', to: 'meeting' },
-      { from: 'meeting', to: 'unknown' },
+      { from: 'unknown', to: 'meeting', app: 'Zoom' },
+      { from: 'meeting', to: 'unknown', app: 'NotMatchedApp' },
     ]);
     expect(awareness.currentContext).toBe('unknown');
     expect((bubble as any).say).toHaveBeenCalledTimes(1);
@@ -75,4 +75,23 @@ describe('ContextAwareness', () => {
     expect(awareness.currentContext).toBe('unknown');
     expect((bubble as any).say).not.toHaveBeenCalled();
   });
+
+  it('系统界面只广播一次暂停，离开后先恢复再继续识别', async () => {
+    vi.mocked(invoke)
+      .mockResolvedValueOnce({ app_name: 'Code', title: 'main.ts' })
+      .mockResolvedValueOnce({ app_name: '', title: '', untracked: 'secure_desktop' })
+      .mockResolvedValueOnce({ app_name: '', title: '', untracked: 'elevated' })
+      .mockResolvedValueOnce({ app_name: 'Code', title: 'main.ts', untracked: null });
+
+    const awareness = new ContextAwareness(bus, mockBubble(), mockDialogue());
+    const events: string[] = [];
+    bus.on('context:changed', ({ to }) => events.push(`context:${to}`));
+    bus.on('tracking:paused', ({ reason }) => events.push(`paused:${reason}`));
+    bus.on('tracking:resumed', () => events.push('resumed'));
+
+    for (let i = 0; i < 4; i++) await (awareness as any).poll();
+
+    expect(events).toEqual(['context:coding', 'paused:secure_desktop', 'resumed']);
+    expect(awareness.currentContext).toBe('coding');
+  });
 });
@@ -0,0 +1,107 @@
+/**
+ * DataBackup 单元测试
+ *
+ * 覆盖：每天只排队一次备份、备份前先落盘、失败经任务队列重试、
+ * 重试耗尽后再次排队时复用原任务、取消的备份不重试。
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(async () => '/data/backups/pet-state-1.json'),
+}));
+
+import { invoke } from '@tauri-apps/api/core';
+import { BACKUP_JOB, DataBackup } from '../src/core/data-backup';
+import { JobQueue } from '../src/core/job-queue';
+import type { MemorySystem } from '../src/core/memory';
+import { OPERATION_CANCELLED, OperationTracker } from '../src/core/operations';
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import { StorageService, STORE_KEYS } from '../src/core/storage';
+import { EventBus } from '../src/events';
+import type { AppEvents } from '../src/types';
+import { getLocalDateKey } from '../src/utils';
+
+function createBackup(initial: Record<string, unknown> = {}) {
+  const storage = new StorageService(new MemoryStorageBackend(initial));
+  const memory = { save: vi.fn(async () => {}) } as unknown as MemorySystem;
+  const jobs = new JobQueue(storage);
+  const operations = new OperationTracker(new EventBus<AppEvents>());
+  const backup = new DataBackup(jobs, storage, memory, operations);
+  return { storage, memory, jobs, backup };
+}
+
+describe('DataBackup', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    vi.stubGlobal('window', globalThis);
+    vi.mocked(invoke).mockClear();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('当天首次启动时经任务队列备份，备份前先保存记忆', async () => {
+    const { storage, memory, jobs, backup } = createBackup();
+    await jobs.start();
+    await backup.start();
+    await jobs.tick();
+
+    expect(memory.save).toHaveBeenCalled();
+    expect(invoke).toHaveBeenCalledWith('backup_data', {
+      opId: expect.stringMatching(/^backup-/),
+    });
+    expect(await storage.get(STORE_KEYS.LAST_BACKUP_DATE, '')).toBe(getLocalDateKey());
+    expect(jobs.listJobs()).toEqual([]);
+
+    await backup.scheduleDaily();
+    await jobs.tick();
+    expect(invoke).toHaveBeenCalledTimes(1);
+    backup.stop();
+    jobs.stop();
+  });
+
+  it('今天已备份过时不再排队', async () => {
+    const { jobs, backup } = createBackup({ [STORE_KEYS.LAST_BACKUP_DATE]: getLocalDateKey() });
+    await backup.start();
+    expect(jobs.listJobs()).toEqual([]);
+    backup.stop();
+  });
+
+  it('备份失败后由队列保留并重试；重试耗尽后再次备份复用原任务', async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error('存储文件正在写入，请稍后重试'));
+    const { jobs, backup } = createBackup();
+    await jobs.start();
+    await backup.start();
+    await jobs.tick();
+
+    const [job] = jobs.listJobs();
+    expect(job).toMatchObject({ kind: BACKUP_JOB, status: 'pending', attempts: 1 });
+    expect(job.lastError).toBe('存储文件正在写入，请稍后重试');
+    expect(await backup.backupNow()).toBe(false);
+
+    await vi.advanceTimersByTimeAsync(10 * 60 * 1000);
+    expect(jobs.listJobs()[0].status).toBe('failed');
+
+    vi.mocked(invoke).mockResolvedValue('/data/backups/pet-state-2.json');
+    expect(await backup.backupNow()).toBe(true);
+    await jobs.tick();
+    expect(jobs.listJobs()).toEqual([]);
+    backup.stop();
+    jobs.stop();
+  });
+
+  it('取消的备份不重试，也不记为今天已备份', async () => {
+    vi.mocked(invoke).mockRejectedValueOnce(OPERATION_CANCELLED);
+    const { storage, jobs, backup } = createBackup();
+    await jobs.start();
+    await backup.start();
+    await jobs.tick();
+
+    expect(jobs.listJobs()).toEqual([]);
+    expect(await storage.get(STORE_KEYS.LAST_BACKUP_DATE, '')).toBe('');
+    backup.stop();
+    jobs.stop();
+  });
+});
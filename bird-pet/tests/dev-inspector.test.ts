@@ -0,0 +1,98 @@
+/**
+ * 开发者事件检查器单元测试
+ *
+ * 覆盖：非开发者模式不拦截、总线事件采集、invoke 耗时统计与还原、probe 容错。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}));
+vi.mock('@tauri-apps/api/event', () => ({
+  emitTo: vi.fn(async () => {}),
+  listen: vi.fn(async () => () => {}),
+}));
+vi.mock('@tauri-apps/api/webviewWindow', () => ({
+  WebviewWindow: { getByLabel: vi.fn(async () => null) },
+}));
+
+import { invoke } from '@tauri-apps/api/core';
+import { DevInspector } from '../src/core/dev-inspector';
+import { EventBus } from '../src/events';
+import type { AppEvents } from '../src/types';
+
+describe('DevInspector', () => {
+  let internalInvoke: ReturnType<typeof vi.fn>;
+
+  beforeEach(() => {
+    vi.mocked(invoke).mockReset();
+    internalInvoke = vi.fn(async (cmd: string) => {
+      if (cmd === 'boom') throw new Error('boom');
+      return 'ok';
+    });
+    Object.defineProperty(globalThis, 'window', {
+      value: {
+        setInterval: vi.fn(() => 1),
+        __TAURI_INTERNALS__: { invoke: internalInvoke },
+      },
+      configurable: true,
+    });
+  });
+
+  it('非开发者模式下不采集、不拦截 invoke', async () => {
+    vi.mocked(invoke).mockResolvedValue(false);
+    const bus = new EventBus<AppEvents>();
+    const inspector = new DevInspector(bus);
+
+    expect(await inspector.start()).toBe(false);
+    bus.emit('pet:clicked');
+
+    const internals = (window as any).__TAURI_INTERNALS__;
+    expect(internals.invoke).toBe(internalInvoke);
+    expect(inspector.getSnapshot().events).toEqual([]);
+  });
+
+  it('开发者模式下记录总线事件', async () => {
+    vi.mocked(invoke).mockResolvedValue(true);
+    const bus = new EventBus<AppEvents>();
+    const inspector = new DevInspector(bus);
+
+    expect(await inspector.start()).toBe(true);
+    bus.emit('animation:play', { name: 'look' });
+
+    const [event] = inspector.getSnapshot().events;
+    expect(event).toMatchObject({ source: 'bus', name: 'animation:play', data: { name: 'look' } });
+    inspector.stop();
+  });
+
+  it('统计命令耗时与失败次数，stop 后还原 invoke', async () => {
+    vi.mocked(invoke).mockResolvedValue(true);
+    const inspector = new DevInspector(new EventBus<AppEvents>());
+    await inspector.start();
+
+    const internals = (window as any).__TAURI_INTERNALS__;
+    await internals.invoke('get_system_stats');
+    await internals.invoke('get_system_stats');
+    await expect(internals.invoke('boom')).rejects.toThrow('boom');
+    await internals.invoke('plugin:event|emit_to');
+
+    const commands = inspector.getSnapshot().commands;
+    expect(commands.map((c) => [c.name, c.count, c.errors])).toEqual([
+      ['get_system_stats', 2, 0],
+      ['boom', 1, 1],
+    ]);
+
+    inspector.stop();
+    expect(internals.invoke).toBe(internalInvoke);
+  });
+
+  it('probe 抛错时记录错误信息', () => {
+    const inspector = new DevInspector(new EventBus<AppEvents>());
+    inspector.addProbe('ok', () => 42);
+    inspector.addProbe('bad', () => {
+      throw new Error('nope');
+    });
+
+    expect(inspector.getSnapshot().watchers).toEqual({ ok: 42, bad: 'probe error: nope' });
+  });
+});
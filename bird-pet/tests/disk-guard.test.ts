@@ -0,0 +1,67 @@
+/**
+ * 低磁盘守护单元测试
+ *
+ * 覆盖：状态变化时转发到 EventBus、低磁盘模式下静默调试输出并在恢复后还原。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+type Handler = (e: { payload: unknown }) => void;
+const listeners = new Map<string, Handler>();
+vi.mock('../src/core/window-events', () => ({
+  listenToCurrentWindow: vi.fn(async (event: string, handler: Handler) => {
+    listeners.set(event, handler);
+    return () => listeners.delete(event);
+  }),
+}));
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(async () => ({ freeBytes: 10, thresholdBytes: 1, low: false })),
+}));
+
+import { EventBus } from '../src/events';
+import { DiskGuard } from '../src/core/disk-guard';
+import type { AppEvents, DiskStatus } from '../src/types';
+import { debugLog } from '../src/utils';
+
+function push(low: boolean): void {
+  const status: DiskStatus = { freeBytes: low ? 0 : 10, thresholdBytes: 1, low };
+  listeners.get('disk:space-changed')?.({ payload: status });
+}
+
+describe('DiskGuard', () => {
+  beforeEach(() => {
+    listeners.clear();
+  });
+
+  it('只在状态变化时转发', async () => {
+    const bus = new EventBus<AppEvents>();
+    const handler = vi.fn();
+    bus.on('disk:space-changed', handler);
+    const guard = new DiskGuard(bus);
+    await guard.start();
+
+    push(true);
+    push(true);
+    expect(handler).toHaveBeenCalledTimes(1);
+    expect(guard.isLow).toBe(true);
+    guard.stop();
+  });
+
+  it('低磁盘模式下静默调试输出，恢复后还原，不改动全局 console', async () => {
+    const log = console.log;
+    const debug = vi.spyOn(console, 'debug').mockImplementation(() => {});
+    const guard = new DiskGuard(new EventBus<AppEvents>());
+    await guard.start();
+
+    push(true);
+    debugLog('muted');
+    expect(debug).not.toHaveBeenCalled();
+    expect(console.log).toBe(log);
+
+    push(false);
+    debugLog('restored');
+    expect(debug).toHaveBeenCalledWith('restored');
+    guard.stop();
+    debug.mockRestore();
+  });
+});
@@ -74,4 +74,18 @@ describe('EventBus', () => {
     const bus = new EventBus<TestEvents>();
     expect(() => bus.emit('test:void')).not.toThrow();
   });
+
+  it('onAny should receive every event with its name', () => {
+    const bus = new EventBus<TestEvents>();
+    const handler = vi.fn();
+    const unsub = bus.onAny(handler);
+    bus.emit('test:data', { value: 1 });
+    bus.emit('test:void');
+    expect(handler).toHaveBeenNthCalledWith(1, 'test:data', { value: 1 });
+    expect(handler).toHaveBeenNthCalledWith(2, 'test:void', undefined);
+
+    unsub();
+    bus.emit('test:string', 'ignored');
+    expect(handler).toHaveBeenCalledTimes(2);
+  });
 });
@@ -0,0 +1,72 @@
+/**
+ * 代码提交庆祝单元测试
+ *
+ * 覆盖：提交 / 推送台词、过长提交说明截断、默认关闭不订阅、开启后转发后端事件。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+type Handler = (e: { payload: unknown }) => void;
+const listeners = new Map<string, Handler>();
+vi.mock('../src/core/window-events', () => ({
+  listenToCurrentWindow: vi.fn(async (event: string, handler: Handler) => {
+    listeners.set(event, handler);
+    return () => listeners.delete(event);
+  }),
+}));
+
+import { EventBus } from '../src/events';
+import { GitCelebration, formatGitActivity } from '../src/features/git-celebration';
+import type { AppEvents, GitActivity } from '../src/types';
+
+function activity(overrides: Partial<GitActivity> = {}): GitActivity {
+  return { kind: 'commit', repo: 'memo', branch: null, summary: 'fix: 修复登录', ...overrides };
+}
+
+function createCelebration(enabled: boolean) {
+  const bus = new EventBus<AppEvents>();
+  let prefs = { gitWatchEnabled: enabled, gitWatchRepos: ['/code/memo'] };
+  const storage = {
+    getPreferences: vi.fn(async () => prefs),
+    setPreferences: vi.fn(async (update: Partial<typeof prefs>) => {
+      prefs = { ...prefs, ...update };
+    }),
+  };
+  return { bus, celebration: new GitCelebration(bus, storage as any) };
+}
+
+describe('formatGitActivity', () => {
+  it('提交与推送使用不同台词', () => {
+    expect(formatGitActivity(activity())).toBe('✅ memo 又提交了一笔「fix: 修复登录」，继续加油！');
+    expect(
+      formatGitActivity(activity({ kind: 'push', branch: 'origin/feature/login', summary: null })),
+    ).toBe('🚀 推送成功！memo → origin/feature/login 终于上路啦');
+  });
+
+  it('过长的提交说明被截断', () => {
+    const text = formatGitActivity(activity({ summary: '很'.repeat(60) }));
+    expect(text).toContain(`「${'很'.repeat(40)}…」`);
+  });
+});
+
+describe('GitCelebration', () => {
+  beforeEach(() => listeners.clear());
+
+  it('默认关闭时不订阅后端事件', async () => {
+    const { celebration } = createCelebration(false);
+    await celebration.start();
+    expect(listeners.has('git:activity')).toBe(false);
+  });
+
+  it('开启后把后端事件转发到 EventBus，关闭后停止', async () => {
+    const { bus, celebration } = createCelebration(false);
+    const handler = vi.fn();
+    bus.on('git:activity', handler);
+
+    expect(await celebration.toggle()).toEqual({ enabled: true, repoCount: 1 });
+    listeners.get('git:activity')?.({ payload: activity({ kind: 'push' }) });
+    expect(handler).toHaveBeenCalledWith(activity({ kind: 'push' }));
+
+    expect((await celebration.toggle()).enabled).toBe(false);
+    await vi.waitFor(() => expect(listeners.has('git:activity')).toBe(false));
+  });
+});
@@ -0,0 +1,158 @@
+/**
+ * JobQueue 单元测试
+ *
+ * 覆盖：入队即执行、失败指数退避、重试耗尽标记 failed、
+ * retryJob 手动重试、重启后恢复中断的任务、低磁盘暂停。
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { JobQueue, getRetryDelay, type Job } from '../src/core/job-queue';
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import { StorageService, STORE_KEYS } from '../src/core/storage';
+
+function createQueue(initial: Record<string, unknown> = {}) {
+  const backend = new MemoryStorageBackend(initial);
+  const queue = new JobQueue(new StorageService(backend));
+  return { queue, backend };
+}
+
+describe('JobQueue', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    vi.stubGlobal('window', globalThis);
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('重试延迟逐次翻倍并有上限', () => {
+    expect(getRetryDelay(1)).toBe(30_000);
+    expect(getRetryDelay(2)).toBe(60_000);
+    expect(getRetryDelay(3)).toBe(120_000);
+    expect(getRetryDelay(20)).toBe(30 * 60 * 1000);
+  });
+
+  it('入队后立即执行，成功后移出队列', async () => {
+    const { queue, backend } = createQueue();
+    const handler = vi.fn(async () => {});
+    queue.register('backup', handler);
+    await queue.start();
+
+    await queue.enqueue('backup', { file: 'a' });
+    await queue.tick();
+
+    expect(handler).toHaveBeenCalledWith({ file: 'a' });
+    expect(queue.listJobs()).toEqual([]);
+    expect(backend.snapshot()[STORE_KEYS.JOB_QUEUE]).toEqual([]);
+    queue.stop();
+  });
+
+  it('失败后按退避延迟重试，用尽次数后标记 failed', async () => {
+    const { queue } = createQueue();
+    const handler = vi.fn(async () => {
+      throw new Error('network down');
+    });
+    queue.register('sync', handler);
+    await queue.start();
+
+    await queue.enqueue('sync', null, { maxAttempts: 2 });
+    await queue.tick();
+    expect(handler).toHaveBeenCalledTimes(1);
+    expect(queue.listJobs()[0]).toMatchObject({ status: 'pending', attempts: 1 });
+
+    // 退避未到期不执行
+    await vi.advanceTimersByTimeAsync(15_000);
+    expect(handler).toHaveBeenCalledTimes(1);
+
+    await vi.advanceTimersByTimeAsync(15_000);
+    expect(handler).toHaveBeenCalledTimes(2);
+    expect(queue.listJobs()[0]).toMatchObject({
+      status: 'failed',
+      attempts: 2,
+      lastError: 'network down',
+    });
+
+    // failed 任务不再自动执行
+    await vi.advanceTimersByTimeAsync(60 * 60 * 1000);
+    expect(handler).toHaveBeenCalledTimes(2);
+    queue.stop();
+  });
+
+  it('retryJob 仅对 failed 任务生效并重新执行', async () => {
+    const { queue } = createQueue();
+    let fail = true;
+    const handler = vi.fn(async () => {
+      if (fail) throw new Error('boom');
+    });
+    queue.register('ocr', handler);
+    await queue.start();
+
+    const job = await queue.enqueue('ocr', null, { maxAttempts: 1 });
+    await queue.tick();
+    expect(queue.listJobs()[0].status).toBe('failed');
+
+    fail = false;
+    expect(await queue.retryJob('missing')).toBe(false);
+    expect(await queue.retryJob(job.id)).toBe(true);
+    await queue.tick();
+
+    expect(handler).toHaveBeenCalledTimes(2);
+    expect(queue.listJobs()).toEqual([]);
+    queue.stop();
+  });
+
+  it('启动时恢复上次中断的 running 任务', async () => {
+    const interrupted: Job = {
+      id: 'j1',
+      kind: 'backup',
+      payload: 1,
+      status: 'running',
+      attempts: 1,
+      maxAttempts: 5,
+      nextRunAt: 0,
+      lastError: null,
+      createdAt: 0,
+    };
+    const { queue } = createQueue({ [STORE_KEYS.JOB_QUEUE]: [interrupted] });
+    const handler = vi.fn(async () => {});
+    queue.register('backup', handler);
+
+    await queue.start();
+    await queue.tick();
+
+    expect(handler).toHaveBeenCalledWith(1);
+    expect(queue.listJobs()).toEqual([]);
+    queue.stop();
+  });
+
+  it('未注册处理器的任务保持 pending', async () => {
+    const { queue } = createQueue();
+    await queue.start();
+
+    await queue.enqueue('embedding', null);
+    await queue.tick();
+
+    expect(queue.listJobs()[0]).toMatchObject({ status: 'pending', attempts: 0 });
+    queue.stop();
+  });
+
+  it('暂停时不执行任务，恢复后补跑', async () => {
+    const { queue } = createQueue();
+    const handler = vi.fn(async () => {});
+    queue.register('backup', handler);
+    await queue.start();
+
+    queue.setPaused(true);
+    await queue.enqueue('backup', null);
+    await queue.tick();
+    expect(handler).not.toHaveBeenCalled();
+    expect(queue.listJobs()).toHaveLength(1);
+
+    queue.setPaused(false);
+    await queue.tick();
+    expect(handler).toHaveBeenCalledOnce();
+    expect(queue.listJobs()).toEqual([]);
+    queue.stop();
+  });
+});
@@ -35,7 +35,7 @@ function createStorageMock(events: MemoryEvent[], profile: UserProfile) {
       if (key === STORE_KEYS.USER_PROFILE) return profile;
       return fallback;
     }),
-    set: vi.fn(async () => {}),
+    set: vi.fn(async (_key: string, _value: unknown) => {}),
   };
 }
 
@@ -95,4 +95,111 @@ describe('MemorySystem summarizeDay', () => {
     expect(nextProfile.streakDays).toBe(5);
     expect(nextProfile.lastActiveDate).toBe(today);
   });
+
+  it('汇总时计入合并记录中各上下文的实际停留时长', async () => {
+    const yesterday = getDateKeyDaysAgo(1);
+    const events: MemoryEvent[] = [
+      {
+        type: 'context_switch',
+        timestamp: toTimestamp(yesterday, 9),
+        data: { from: 'idle', to: 'browsing', durations: { coding: 30 * 60_000 } },
+      },
+    ];
+    const profile: UserProfile = {
+      totalInteractions: 0,
+      streakDays: 1,
+      lastActiveDate: yesterday,
+      dailySummaries: [],
+    };
+
+    const memory = new MemorySystem(createBusMock(), createStorageMock(events, profile) as any);
+    await memory.start();
+
+    const [summary] = memory.getProfile().dailySummaries;
+    expect(summary.contextDurations).toEqual({ browsing: 15, coding: 30 });
+    expect(summary.dominantContext).toBe('coding');
+  });
+
+  it('跨天汇总时应按天演化长期性格特质，缺席的日子也计入', async () => {
+    const lastActiveDate = getDateKeyDaysAgo(3);
+    const day1 = getDateKeyDaysAgo(1);
+    const events: MemoryEvent[] = [
+      { type: 'interaction', timestamp: toTimestamp(lastActiveDate, 10) },
+      { type: 'interaction', timestamp: toTimestamp(day1, 10) },
+    ];
+    const profile: UserProfile = {
+      totalInteractions: 2,
+      streakDays: 1,
+      lastActiveDate,
+      dailySummaries: [],
+    };
+
+    const storage = createStorageMock(events, profile);
+    const memory = new MemorySystem(createBusMock(), storage as any);
+    await memory.start();
+
+    const traits = memory.getTraits();
+    expect(traits.updatedDate).toBe(day1);
+    expect(traits.familiarity).toBeGreaterThan(0);
+    expect(memory.getSnapshot().familiarity).toBe(traits.familiarity);
+
+    await memory.save();
+    expect(storage.set).toHaveBeenCalledWith(STORE_KEYS.PET_TRAITS, traits);
+  });
+
+  it('进入低磁盘模式后压缩往日的上下文切换而不删除事件，并立即保存', async () => {
+    const today = getDateKeyDaysAgo(0);
+    const day2 = getDateKeyDaysAgo(2);
+    const events: MemoryEvent[] = [
+      { type: 'interaction', timestamp: toTimestamp(day2, 10) },
+      {
+        type: 'context_switch',
+        timestamp: toTimestamp(day2, 10) + 60_000,
+        data: { from: 'idle', to: 'coding', app: 'Code' },
+      },
+      {
+        type: 'context_switch',
+        timestamp: toTimestamp(day2, 10) + 120_000,
+        data: { from: 'coding', to: 'browsing', app: 'Chrome' },
+      },
+      { type: 'interaction', timestamp: Date.now() },
+    ];
+    const profile: UserProfile = {
+      totalInteractions: 2,
+      streakDays: 1,
+      lastActiveDate: today,
+      dailySummaries: [],
+    };
+
+    const listeners = new Map<string, (data: unknown) => void>();
+    const bus = {
+      ...createBusMock(),
+      on: vi.fn((event: string, listener: (data: unknown) => void) => {
+        listeners.set(event, listener);
+        return () => {};
+      }),
+    } as unknown as EventBus<AppEvents>;
+    const storage = createStorageMock(events, profile);
+    const memory = new MemorySystem(bus, storage as any);
+    await memory.start();
+
+    listeners.get('disk:space-changed')?.({ low: true, freeBytes: 0, thresholdBytes: 1 });
+    await vi.waitFor(() => {
+      const saved = storage.set.mock.calls.find(([key]) => key === STORE_KEYS.MEMORY_EVENTS);
+      expect(saved?.[1]).toEqual([
+        events[0],
+        {
+          type: 'context_switch',
+          timestamp: toTimestamp(day2, 10) + 60_000,
+          data: {
+            from: 'idle',
+            to: 'browsing',
+            durations: { coding: 60_000 },
+            lastAt: toTimestamp(day2, 10) + 120_000,
+          },
+        },
+        events[3],
+      ]);
+    });
+  });
 });
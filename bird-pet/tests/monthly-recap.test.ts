@@ -0,0 +1,114 @@
+/**
+ * 月度回顾单元测试
+ *
+ * 覆盖 buildRecap 的排行与最佳专注日、跨月时生成回顾并广播 recap:ready、应用使用时长累计。
+ */
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}));
+
+import { EventBus } from '../src/events';
+import {
+  MonthlyRecapGenerator,
+  buildRecap,
+  formatRecap,
+  getMonthKey,
+  type MonthTally,
+} from '../src/features/monthly-recap';
+import { STORE_KEYS } from '../src/core/storage';
+import type { AppEvents, AutoMemory, MonthlyRecap } from '../src/types';
+
+const MINUTE = 60_000;
+
+function tally(overrides: Partial<MonthTally> = {}): MonthTally {
+  return { month: '2026-03', appMinutes: {}, days: {}, milestones: [], ...overrides };
+}
+
+function createGenerator(stored: unknown, memories: AutoMemory[] = []) {
+  const bus = new EventBus<AppEvents>();
+  const data: Record<string, unknown> = { [STORE_KEYS.MONTHLY_RECAP]: stored };
+  const storage = {
+    getPreferences: vi.fn(async () => ({ activityFidelity: 'high' })),
+    get: vi.fn(async (key: string, fallback: unknown) => data[key] ?? fallback),
+    set: vi.fn(async (key: string, value: unknown) => {
+      data[key] = value;
+    }),
+  };
+  const generator = new MonthlyRecapGenerator(bus, storage as any, () => memories);
+  return { bus, generator };
+}
+
+describe('buildRecap', () => {
+  it('按时长排出常用应用，找出番茄最多的一天', () => {
+    const recap = buildRecap(
+      tally({
+        appMinutes: { Code: 600.4, Chrome: 120, Spotify: 0.2 },
+        days: {
+          '2026-03-02': { pomodoros: 3, interactions: 5 },
+          '2026-03-12': { pomodoros: 6, interactions: 1 },
+          '2026-03-20': { pomodoros: 0, interactions: 2 },
+        },
+        milestones: ['认识 100 天啦'],
+      }),
+      [
+        { tag: 'auto', kind: 'new_monitor', message: '接上了新屏幕', at: new Date(2026, 2, 5).getTime() },
+        { tag: 'cli', kind: 'note', message: '上个月的事', at: new Date(2026, 1, 27).getTime() },
+      ],
+      1,
+    );
+
+    expect(recap.topApps).toEqual([
+      { app: 'Code', minutes: 600 },
+      { app: 'Chrome', minutes: 120 },
+    ]);
+    expect(recap.bestFocusDay).toEqual({ date: '2026-03-12', pomodoros: 6 });
+    expect(recap.totalPomodoros).toBe(9);
+    expect(recap.activeDays).toBe(3);
+    expect(recap.highlights).toEqual(['接上了新屏幕']);
+    expect(recap.milestones).toEqual(['认识 100 天啦']);
+    expect(formatRecap(recap)).toContain('最常用的是 Code（600 分钟）');
+  });
+});
+
+describe('MonthlyRecapGenerator', () => {
+  it('跨月时生成上月回顾并开始新的累计', async () => {
+    const { bus, generator } = createGenerator({
+      tally: tally({ days: { '2026-03-12': { pomodoros: 2, interactions: 0 } } }),
+      recaps: [],
+    });
+    const handler = vi.fn<(recap: MonthlyRecap) => void>();
+    bus.on('recap:ready', handler);
+
+    await generator.start();
+    generator.stop();
+
+    expect(handler).toHaveBeenCalledTimes(1);
+    expect(handler.mock.calls[0][0].month).toBe('2026-03');
+    expect(generator.getLatest()?.bestFocusDay).toEqual({ date: '2026-03-12', pomodoros: 2 });
+  });
+
+  it('按上下文切换累计应用时长，暂停追踪时停止计时', async () => {
+    vi.useFakeTimers();
+    try {
+      const { bus, generator } = createGenerator(undefined);
+      await generator.start();
+      const month = getMonthKey();
+
+      bus.emit('context:changed', { from: 'unknown', to: 'coding', app: 'Code' });
+      vi.advanceTimersByTime(30 * MINUTE);
+      bus.emit('tracking:paused', { reason: 'secure_desktop' });
+      vi.advanceTimersByTime(10 * MINUTE);
+      bus.emit('tracking:resumed');
+
+      // 模拟跨月，读取累计结果
+      await generator.check(new Date(2999, 0, 1).getTime());
+      generator.stop();
+      expect(generator.getLatest()?.month).toBe(month);
+      expect(generator.getLatest()?.topApps).toEqual([{ app: 'Code', minutes: 30 }]);
+    } finally {
+      vi.useRealTimers();
+    }
+  });
+});
@@ -0,0 +1,106 @@
+/**
+ * MorningRoutine 单元测试
+ *
+ * 覆盖：步骤开关、每日只执行一次、番茄钟仅在空闲时启动、静默时跳过问候。
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { MorningRoutine } from '../src/features/morning-routine';
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import {
+  DEFAULT_PREFERENCES,
+  StorageService,
+  STORE_KEYS,
+  type UserPreferences,
+} from '../src/core/storage';
+import { getLocalDateKey } from '../src/utils';
+
+function createRoutine(
+  prefs: Partial<UserPreferences> = {},
+  options: { lastRunDate?: string; pomodoroState?: string; fullSilent?: boolean } = {},
+) {
+  const storage = new StorageService(
+    new MemoryStorageBackend({
+      [STORE_KEYS.PREFERENCES]: { ...DEFAULT_PREFERENCES, morningRoutineEnabled: true, ...prefs },
+      [STORE_KEYS.MORNING_ROUTINE_DATE]: options.lastRunDate ?? '',
+    }),
+  );
+  vi.spyOn(storage, 'set');
+  const greeting = { greetMorning: vi.fn(() => true) };
+  const pomodoro = { state: options.pomodoroState ?? 'idle', start: vi.fn(async () => {}) };
+  const quietMode = { isFullSilent: vi.fn(() => options.fullSilent ?? false) };
+
+  const routine = new MorningRoutine(
+    greeting as any,
+    pomodoro as any,
+    storage,
+    quietMode as any,
+  );
+  return { routine, storage, greeting, pomodoro };
+}
+
+describe('MorningRoutine', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    vi.stubGlobal('window', globalThis);
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('按偏好执行已开启的步骤并记录日期', async () => {
+    const { routine, storage, greeting, pomodoro } = createRoutine();
+
+    const steps = await routine.run();
+
+    expect(steps).toEqual(['greeting']);
+    expect(greeting.greetMorning).toHaveBeenCalledTimes(1);
+    expect(pomodoro.start).not.toHaveBeenCalled();
+    expect(storage.set).toHaveBeenCalledWith(STORE_KEYS.MORNING_ROUTINE_DATE, getLocalDateKey());
+  });
+
+  it('今日已执行过时不再重复', async () => {
+    const { routine, greeting } = createRoutine({}, { lastRunDate: getLocalDateKey() });
+
+    const steps = await routine.run();
+
+    expect(steps).toEqual([]);
+    expect(greeting.greetMorning).not.toHaveBeenCalled();
+  });
+
+  it('番茄钟步骤仅在番茄钟空闲时启动', async () => {
+    const idle = createRoutine({ morningRoutinePomodoro: true });
+    expect(await idle.routine.run()).toContain('pomodoro');
+
+    const busy = createRoutine({ morningRoutinePomodoro: true }, { pomodoroState: 'focus' });
+    expect(await busy.routine.run()).not.toContain('pomodoro');
+    expect(busy.pomodoro.start).not.toHaveBeenCalled();
+  });
+
+  it('完全静默时跳过问候，其他步骤照常执行', async () => {
+    const { routine, greeting, pomodoro } = createRoutine(
+      { morningRoutinePomodoro: true },
+      { fullSilent: true },
+    );
+
+    const steps = await routine.run();
+
+    expect(steps).toEqual(['pomodoro']);
+    expect(greeting.greetMorning).not.toHaveBeenCalled();
+    expect(pomodoro.start).toHaveBeenCalled();
+  });
+
+  it('未启用时 start() 不调度任何执行', async () => {
+    const { routine, greeting, pomodoro } = createRoutine({
+      morningRoutineEnabled: false,
+      morningRoutinePomodoro: true,
+    });
+
+    await routine.start();
+    await vi.advanceTimersByTimeAsync(48 * 60 * 60 * 1000);
+
+    expect(greeting.greetMorning).not.toHaveBeenCalled();
+    expect(pomodoro.start).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,86 @@
+/**
+ * 长任务进度跟踪单元测试
+ *
+ * 覆盖 OperationTracker：进行中列表维护、进度转发、批量取消，以及取消错误识别。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}));
+
+vi.mock('@tauri-apps/api/event', () => ({
+  listen: vi.fn().mockResolvedValue(() => {}),
+}));
+
+import { invoke } from '@tauri-apps/api/core';
+import { EventBus } from '../src/events';
+import type { AppEvents, OperationProgress } from '../src/types';
+import {
+  OPERATION_CANCELLED,
+  OperationTracker,
+  formatProgress,
+  isCancelledError,
+} from '../src/core/operations';
+
+function progress(id: string, state: OperationProgress['state'], done = 0): OperationProgress {
+  return { id, kind: 'report', state, done, total: 3, message: null };
+}
+
+describe('OperationTracker', () => {
+  let bus: EventBus<AppEvents>;
+  let tracker: OperationTracker;
+
+  beforeEach(() => {
+    vi.mocked(invoke).mockReset();
+    bus = new EventBus<AppEvents>();
+    tracker = new OperationTracker(bus);
+  });
+
+  it('进行中的操作加入列表，结束后移除', () => {
+    tracker.apply(progress('a', 'running'));
+    tracker.apply(progress('a', 'running', 1));
+    tracker.apply(progress('b', 'running'));
+    expect(tracker.active.map((op) => op.id)).toEqual(['a', 'b']);
+    expect(tracker.active[0].done).toBe(1);
+
+    tracker.apply(progress('a', 'done', 3));
+    tracker.apply(progress('b', 'cancelled'));
+    expect(tracker.active).toEqual([]);
+  });
+
+  it('进度事件转发到 EventBus', () => {
+    const handler = vi.fn();
+    bus.on('op:progress', handler);
+    tracker.apply(progress('a', 'failed'));
+    expect(handler).toHaveBeenCalledWith(progress('a', 'failed'));
+  });
+
+  it('cancelAll 对每个进行中的操作请求取消', async () => {
+    vi.mocked(invoke).mockResolvedValueOnce(true).mockResolvedValueOnce(false);
+    tracker.apply(progress('a', 'running'));
+    tracker.apply(progress('b', 'running'));
+
+    await expect(tracker.cancelAll()).resolves.toBe(1);
+    expect(invoke).toHaveBeenCalledWith('cancel_operation', { id: 'a' });
+    expect(invoke).toHaveBeenCalledWith('cancel_operation', { id: 'b' });
+  });
+
+  it('createId 每次生成不同的 id', () => {
+    const a = tracker.createId('report');
+    const b = tracker.createId('report');
+    expect(a).toMatch(/^report-/);
+    expect(a).not.toBe(b);
+  });
+});
+
+describe('operation helpers', () => {
+  it('识别取消错误', () => {
+    expect(isCancelledError(OPERATION_CANCELLED)).toBe(true);
+    expect(isCancelledError('磁盘空间不足')).toBe(false);
+  });
+
+  it('格式化进度', () => {
+    expect(formatProgress(progress('a', 'running', 2))).toBe('导出报告 2/3');
+  });
+});
@@ -97,4 +97,27 @@ describe('PomodoroTimer persistence', () => {
 
     await timer.stop();
   });
+
+  it('暂停期间不计时，恢复后从剩余时间继续', async () => {
+    const today = getLocalDateKey();
+    const { timer } = createPomodoro(async (key, fallback) => {
+      if (key === STORE_KEYS.POMODORO_DATE) return today;
+      if (key === STORE_KEYS.POMODORO_COUNT) return 0;
+      return fallback;
+    });
+
+    await timer.start();
+    await vi.advanceTimersByTimeAsync(10 * 60 * 1000);
+    timer.pause();
+    await vi.advanceTimersByTimeAsync(60 * 60 * 1000);
+    expect(timer.completed).toBe(0);
+    expect(timer.remainingMs).toBe(15 * 60 * 1000);
+
+    timer.resume();
+    await vi.advanceTimersByTimeAsync(15 * 60 * 1000);
+    expect(timer.completed).toBe(1);
+    expect(timer.state).toBe('break');
+
+    await timer.stop();
+  });
 });
@@ -3,24 +3,21 @@
  */
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import { EventBus } from '../src/events';
-import type { AppEvents } from '../src/types';
+import type { AppEvents, DaylightPhase } from '../src/types';
 import { QuietModeManager } from '../src/features/quiet-mode';
-import type { StorageService, UserPreferences } from '../src/core/storage';
-
-/** 创建 mock StorageService */
-function mockStorage(prefs: Partial<UserPreferences> = {}): StorageService {
-  const defaults: UserPreferences = {
-    hourlyChimeEnabled: true,
-    systemMonitorEnabled: true,
-    contextAwarenessEnabled: true,
-    quietHoursStart: -1,
-    quietHoursEnd: -1,
-    nightModeEnabled: true,
-    autoStartEnabled: false,
-  };
-  return {
-    getPreferences: vi.fn().mockResolvedValue({ ...defaults, ...prefs }),
-  } as unknown as StorageService;
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import {
+  DEFAULT_PREFERENCES,
+  StorageService,
+  STORE_KEYS,
+  type UserPreferences,
+} from '../src/core/storage';
+
+/** 创建基于内存后端的 StorageService（未指定的偏好取默认值） */
+function createStorage(prefs: Partial<UserPreferences> = {}): StorageService {
+  return new StorageService(
+    new MemoryStorageBackend({ [STORE_KEYS.PREFERENCES]: { ...DEFAULT_PREFERENCES, ...prefs } }),
+  );
 }
 
 describe('QuietModeManager', () => {
@@ -36,7 +33,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should return null (no suppress) by default', async () => {
-    const qm = new QuietModeManager(bus, mockStorage());
+    const qm = new QuietModeManager(bus, createStorage());
     await qm.start();
     // Assuming test runs during normal hours (not midnight)
     // At worst it returns night_mode, so just check it doesn't error
@@ -48,7 +45,7 @@ describe('QuietModeManager', () => {
     const hour = new Date().getHours();
     const qm = new QuietModeManager(
       bus,
-      mockStorage({
+      createStorage({
         quietHoursStart: hour,
         quietHoursEnd: hour + 2,
       }),
@@ -63,7 +60,7 @@ describe('QuietModeManager', () => {
     // Create a range that definitely includes current hour
     const qm = new QuietModeManager(
       bus,
-      mockStorage({
+      createStorage({
         quietHoursStart: (hour + 23) % 24, // 1 hour before → wraps if needed
         quietHoursEnd: (hour + 2) % 24,
       }),
@@ -76,7 +73,7 @@ describe('QuietModeManager', () => {
     const hour = new Date().getHours();
     const qm = new QuietModeManager(
       bus,
-      mockStorage({
+      createStorage({
         quietHoursStart: (hour + 5) % 24,
         quietHoursEnd: (hour + 7) % 24,
         nightModeEnabled: false,
@@ -87,7 +84,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should suppress when in meeting', async () => {
-    const qm = new QuietModeManager(bus, mockStorage({ nightModeEnabled: false }));
+    const qm = new QuietModeManager(bus, createStorage({ nightModeEnabled: false }));
     await qm.start();
 
     bus.emit('context:changed', { from: 'idle', to: 'meeting' });
@@ -96,7 +93,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should clear meeting state when context changes', async () => {
-    const qm = new QuietModeManager(bus, mockStorage({ nightModeEnabled: false }));
+    const qm = new QuietModeManager(bus, createStorage({ nightModeEnabled: false }));
     await qm.start();
 
     bus.emit('context:changed', { from: 'idle', to: 'meeting' });
@@ -105,7 +102,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should clear meeting state when context changes to unknown', async () => {
-    const qm = new QuietModeManager(bus, mockStorage({ nightModeEnabled: false }));
+    const qm = new QuietModeManager(bus, createStorage({ nightModeEnabled: false }));
     await qm.start();
 
     bus.emit('context:changed', { from: 'idle', to: 'meeting' });
@@ -114,7 +111,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should detect deep focus after coding threshold', async () => {
-    const qm = new QuietModeManager(bus, mockStorage({ nightModeEnabled: false }));
+    const qm = new QuietModeManager(bus, createStorage({ nightModeEnabled: false }));
     await qm.start();
 
     // Simulate coding context
@@ -130,7 +127,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should reset coding timer when switching away from coding', async () => {
-    const qm = new QuietModeManager(bus, mockStorage({ nightModeEnabled: false }));
+    const qm = new QuietModeManager(bus, createStorage({ nightModeEnabled: false }));
     await qm.start();
 
     bus.emit('context:changed', { from: 'idle', to: 'coding' });
@@ -144,7 +141,7 @@ describe('QuietModeManager', () => {
   });
 
   it('should clean up on stop', async () => {
-    const qm = new QuietModeManager(bus, mockStorage());
+    const qm = new QuietModeManager(bus, createStorage());
     await qm.start();
     qm.stop();
 
@@ -155,4 +152,47 @@ describe('QuietModeManager', () => {
     // This tests that unsubscribers were called
     expect(qm.shouldSuppress()).not.toBe('meeting');
   });
+
+  describe('daylight-aware night mode', () => {
+    afterEach(() => {
+      vi.useRealTimers();
+    });
+
+    async function nightModeAt(hour: number, phase: DaylightPhase): Promise<boolean> {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 15, hour, 30));
+      const qm = new QuietModeManager(bus, createStorage());
+      await qm.start();
+      bus.emit('daylight:phase-changed', { phase, sunrise: null, sunset: null });
+      return qm.isNightMode();
+    }
+
+    it('冬天傍晚天黑但未到 21 点时不降频', async () => {
+      expect(await nightModeAt(18, 'night')).toBe(false);
+    });
+
+    it('天黑且已过 21 点时降频', async () => {
+      expect(await nightModeAt(21, 'night')).toBe(true);
+    });
+
+    it('黎明时即使早于 6 点也不再降频', async () => {
+      expect(await nightModeAt(5, 'dawn')).toBe(false);
+    });
+
+    it('夏天 23 点后天还亮着也不降频', async () => {
+      expect(await nightModeAt(23, 'dusk')).toBe(false);
+    });
+
+    it('位置被清除后回退到固定时段', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 6, 15, 23, 30));
+      const qm = new QuietModeManager(bus, createStorage());
+      await qm.start();
+      bus.emit('daylight:phase-changed', { phase: 'dusk', sunrise: null, sunset: null });
+      expect(qm.isNightMode()).toBe(false);
+
+      bus.emit('daylight:cleared');
+      expect(qm.isNightMode()).toBe(true);
+    });
+  });
 });
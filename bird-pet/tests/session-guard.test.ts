@@ -0,0 +1,129 @@
+/**
+ * 用户会话守护单元测试
+ *
+ * 覆盖：只在连接状态变化时转发事件、断开时暂停轮询类功能、动画与番茄计时，
+ * 重连后恢复窗口位置并只重启断开前正在运行的功能。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+type Handler = (e: { payload: unknown }) => void;
+const listeners = new Map<string, Handler>();
+vi.mock('../src/core/window-events', () => ({
+  listenToCurrentWindow: vi.fn(async (event: string, handler: Handler) => {
+    listeners.set(event, handler);
+    return () => listeners.delete(event);
+  }),
+}));
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(async () => ({ active: true })),
+}));
+vi.mock('@tauri-apps/api/window', () => ({
+  getCurrentWindow: () => ({ outerPosition: async () => ({ x: 1200, y: 640 }) }),
+}));
+vi.mock('../src/app/runtime', () => ({
+  moveWindowIfVisible: vi.fn(async () => {}),
+}));
+
+import { EventBus } from '../src/events';
+import { SessionGuard } from '../src/core/session-guard';
+import { bindSessionEvents } from '../src/app/session-events';
+import { moveWindowIfVisible } from '../src/app/runtime';
+import type { AppEvents } from '../src/types';
+
+function pausable(running = true) {
+  const item = {
+    running,
+    isRunning: () => item.running,
+    start: vi.fn(async () => {
+      item.running = true;
+    }),
+    stop: vi.fn(() => {
+      item.running = false;
+    }),
+  };
+  return item;
+}
+
+function createFeatures() {
+  return {
+    contextAwareness: pausable(),
+    systemMonitor: pausable(),
+    autoMemories: pausable(),
+    idleCare: pausable(),
+    hourlyChime: pausable(),
+    tips: pausable(),
+    morningRoutine: pausable(),
+    statusPublisher: pausable(),
+    gitCelebration: pausable(),
+    monthlyRecap: pausable(),
+  };
+}
+
+describe('SessionGuard', () => {
+  beforeEach(() => listeners.clear());
+
+  it('只在连接状态变化时发出 session:changed', async () => {
+    const bus = new EventBus<AppEvents>();
+    const handler = vi.fn();
+    bus.on('session:changed', handler);
+    const guard = new SessionGuard(bus);
+    await guard.start();
+    expect(guard.isActive).toBe(true);
+    expect(handler).not.toHaveBeenCalled();
+
+    const push = listeners.get('session:changed')!;
+    push({ payload: { active: false } });
+    push({ payload: { active: false } });
+    expect(guard.isActive).toBe(false);
+    push({ payload: { active: true } });
+    expect(handler.mock.calls.map((c) => c[0])).toEqual([{ active: false }, { active: true }]);
+    guard.stop();
+  });
+});
+
+describe('bindSessionEvents', () => {
+  it('断开时暂停功能、动画与番茄计时，重连后移回原位置并恢复', async () => {
+    const bus = new EventBus<AppEvents>();
+    const animation = pausable();
+    const pomodoro = { pause: vi.fn(), resume: vi.fn() };
+    const features = createFeatures();
+    bindSessionEvents({ bus, animation } as any, { ...features, pomodoro } as any);
+
+    bus.emit('session:changed', { active: false });
+    await vi.waitFor(() => expect(animation.stop).toHaveBeenCalled());
+    expect(pomodoro.pause).toHaveBeenCalledTimes(1);
+    for (const feature of Object.values(features)) {
+      expect(feature.stop).toHaveBeenCalledTimes(1);
+      expect(feature.start).not.toHaveBeenCalled();
+    }
+
+    bus.emit('session:changed', { active: true });
+    await vi.waitFor(() => expect(features.monthlyRecap.start).toHaveBeenCalled());
+    expect(moveWindowIfVisible).toHaveBeenCalledWith({ x: 1200, y: 640 });
+    expect(animation.start).toHaveBeenCalledTimes(1);
+    expect(pomodoro.resume).toHaveBeenCalledTimes(1);
+    for (const feature of Object.values(features)) {
+      expect(feature.start).toHaveBeenCalledTimes(1);
+    }
+  });
+
+  it('断开时未运行的功能重连后保持关闭，重复断开不丢失记录', async () => {
+    const bus = new EventBus<AppEvents>();
+    const animation = pausable();
+    const pomodoro = { pause: vi.fn(), resume: vi.fn() };
+    const features = { ...createFeatures(), systemMonitor: pausable(false) };
+    bindSessionEvents({ bus, animation } as any, { ...features, pomodoro } as any);
+
+    bus.emit('session:changed', { active: false });
+    await vi.waitFor(() => expect(animation.stop).toHaveBeenCalled());
+    bus.emit('session:changed', { active: false });
+    await vi.waitFor(() => expect(pomodoro.pause).toHaveBeenCalledTimes(2));
+    expect(features.systemMonitor.stop).not.toHaveBeenCalled();
+
+    bus.emit('session:changed', { active: true });
+    await vi.waitFor(() => expect(features.monthlyRecap.start).toHaveBeenCalled());
+    expect(features.systemMonitor.start).not.toHaveBeenCalled();
+    expect(features.contextAwareness.start).toHaveBeenCalledTimes(1);
+    expect(animation.start).toHaveBeenCalledTimes(1);
+  });
+});
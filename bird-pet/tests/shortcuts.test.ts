@@ -0,0 +1,111 @@
+/**
+ * ShortcutManager 单元测试
+ *
+ * 覆盖：按布局解析物理键、无法映射时回退并警告、布局变化时重新注册、只在按下时触发。
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('@tauri-apps/plugin-global-shortcut', () => ({
+  register: vi.fn(async () => {}),
+  unregister: vi.fn(async () => {}),
+}));
+
+import { register, unregister } from '@tauri-apps/plugin-global-shortcut';
+import { resolveAccelerator, ShortcutManager, type KeyboardLayout } from '../src/core/shortcuts';
+
+const US: KeyboardLayout = new Map([
+  ['KeyQ', 'q'],
+  ['KeyA', 'a'],
+  ['KeyP', 'p'],
+  ['Comma', ','],
+]);
+
+const AZERTY: KeyboardLayout = new Map([
+  ['KeyQ', 'a'],
+  ['KeyA', 'q'],
+  ['KeyP', 'p'],
+  ['KeyM', ','],
+]);
+
+describe('resolveAccelerator', () => {
+  it('按当前布局找到产生该字符的物理键', () => {
+    expect(resolveAccelerator('CommandOrControl+Shift+Q', US)).toEqual({
+      accelerator: 'CommandOrControl+Shift+KeyQ',
+      mapped: true,
+    });
+    expect(resolveAccelerator('CommandOrControl+Shift+Q', AZERTY)).toEqual({
+      accelerator: 'CommandOrControl+Shift+KeyA',
+      mapped: true,
+    });
+    expect(resolveAccelerator('Ctrl+,', AZERTY).accelerator).toBe('Ctrl+KeyM');
+  });
+
+  it('当前布局找不到的字符回退到美式位置并标记未映射', () => {
+    expect(resolveAccelerator('Ctrl+/', US)).toEqual({ accelerator: 'Ctrl+Slash', mapped: false });
+  });
+
+  it('命名键与不支持 Keyboard API 时原样保留', () => {
+    expect(resolveAccelerator('Ctrl+Escape', US)).toEqual({
+      accelerator: 'Ctrl+Escape',
+      mapped: true,
+    });
+    expect(resolveAccelerator('Ctrl+Q', null)).toEqual({ accelerator: 'Ctrl+Q', mapped: true });
+  });
+});
+
+describe('ShortcutManager', () => {
+  beforeEach(() => {
+    vi.mocked(register).mockClear();
+    vi.mocked(unregister).mockClear();
+    Object.defineProperty(globalThis, 'window', {
+      value: {
+        setInterval: vi.fn(() => 1),
+        addEventListener: vi.fn(),
+        removeEventListener: vi.fn(),
+      },
+      configurable: true,
+    });
+  });
+
+  it('布局变化时注销旧快捷键并按新布局重新注册', async () => {
+    let layout = US;
+    const manager = new ShortcutManager(async () => layout);
+    manager.add('CommandOrControl+Shift+Q', vi.fn());
+    await manager.start();
+    expect(register).toHaveBeenCalledWith('CommandOrControl+Shift+KeyQ', expect.any(Function));
+
+    await manager.refresh();
+    expect(register).toHaveBeenCalledTimes(1);
+
+    layout = AZERTY;
+    await manager.refresh();
+    expect(unregister).toHaveBeenCalledWith('CommandOrControl+Shift+KeyQ');
+    expect(register).toHaveBeenLastCalledWith('CommandOrControl+Shift+KeyA', expect.any(Function));
+
+    await manager.stop();
+    expect(unregister).toHaveBeenLastCalledWith('CommandOrControl+Shift+KeyA');
+  });
+
+  it('无法映射的快捷键给出警告', async () => {
+    const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+    const manager = new ShortcutManager(async () => US);
+    manager.add('Ctrl+/', vi.fn());
+    await manager.start();
+
+    expect(manager.getWarnings()).toHaveLength(1);
+    expect(warn).toHaveBeenCalled();
+    warn.mockRestore();
+  });
+
+  it('只在按下时触发处理函数', async () => {
+    const handler = vi.fn();
+    const manager = new ShortcutManager(async () => null);
+    manager.add('Ctrl+Q', handler);
+    await manager.start();
+
+    const callback = vi.mocked(register).mock.calls[0][1] as (event: { state: string }) => void;
+    callback({ state: 'Pressed' });
+    callback({ state: 'Released' });
+    expect(handler).toHaveBeenCalledOnce();
+  });
+});
@@ -0,0 +1,140 @@
+/**
+ * StatusPublisher 单元测试
+ *
+ * 覆盖状态优先级推导，以及事件驱动下的广播与发布目标推送（含 MQTT / Discord 目标）。
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(async () => {}),
+}));
+
+import { invoke } from '@tauri-apps/api/core';
+import { EventBus } from '../src/events';
+import {
+  discordStatusSink,
+  mqttStatusSink,
+  resolveStatus,
+  StatusPublisher,
+  type StatusInputs,
+} from '../src/features/status-publisher';
+import type { AppEvents } from '../src/types';
+
+const BASE: StatusInputs = {
+  context: 'browsing',
+  pomodoroFocus: false,
+  codingMs: 0,
+  hour: 14,
+  nightMode: false,
+};
+
+describe('resolveStatus', () => {
+  it('会议优先于一切', () => {
+    expect(resolveStatus({ ...BASE, context: 'meeting', pomodoroFocus: true, hour: 23 })).toBe(
+      'in_meeting',
+    );
+  });
+
+  it('番茄专注或长时间编码视为专注', () => {
+    expect(resolveStatus({ ...BASE, pomodoroFocus: true })).toBe('focused');
+    expect(resolveStatus({ ...BASE, context: 'coding', codingMs: 31 * 60 * 1000 })).toBe('focused');
+    expect(resolveStatus({ ...BASE, context: 'coding', codingMs: 5 * 60 * 1000 })).toBe(
+      'available',
+    );
+  });
+
+  it('闲置上下文视为离开，且优先于收尾', () => {
+    expect(resolveStatus({ ...BASE, context: 'idle', hour: 22 })).toBe('away');
+  });
+
+  it('晚间或深夜模式视为收尾', () => {
+    expect(resolveStatus({ ...BASE, hour: 21 })).toBe('winding_down');
+    expect(resolveStatus({ ...BASE, hour: 3, nightMode: true })).toBe('winding_down');
+  });
+});
+
+describe('StatusPublisher', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    vi.setSystemTime(new Date(2026, 2, 10, 14, 0, 0));
+    vi.stubGlobal('window', globalThis);
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('状态变化时广播事件并推送给发布目标', () => {
+    const bus = new EventBus<AppEvents>();
+    const publisher = new StatusPublisher(bus);
+    const sink = { name: 'test', publish: vi.fn() };
+    const changes: string[] = [];
+    bus.on('status:changed', ({ to }) => changes.push(to));
+
+    publisher.addSink(sink);
+    publisher.start();
+    bus.emit('context:changed', { from: 'unknown', to: 'meeting' });
+    bus.emit('context:changed', { from: 'meeting', to: 'meeting' });
+    bus.emit('pomodoro:focus');
+    bus.emit('context:changed', { from: 'meeting', to: 'browsing' });
+
+    expect(changes).toEqual(['in_meeting', 'focused']);
+    expect(sink.publish.mock.calls.map((c) => c[0])).toEqual([
+      'available',
+      'in_meeting',
+      'focused',
+    ]);
+    publisher.stop();
+  });
+
+  it('连续编码跨过阈值后由定时复核切换为专注', () => {
+    const bus = new EventBus<AppEvents>();
+    const publisher = new StatusPublisher(bus);
+    publisher.start();
+
+    bus.emit('context:changed', { from: 'unknown', to: 'coding' });
+    expect(publisher.status).toBe('available');
+
+    vi.advanceTimersByTime(31 * 60 * 1000);
+    expect(publisher.status).toBe('focused');
+    publisher.stop();
+  });
+
+  it('发布目标抛错不影响其他目标', () => {
+    const bus = new EventBus<AppEvents>();
+    const publisher = new StatusPublisher(bus);
+    const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+    const bad = {
+      name: 'bad',
+      publish: vi.fn(() => {
+        throw new Error('boom');
+      }),
+    };
+    const good = { name: 'good', publish: vi.fn() };
+
+    publisher.addSink(bad);
+    publisher.addSink(good);
+    publisher.start();
+    bus.emit('context:changed', { from: 'unknown', to: 'idle' });
+
+    expect(good.publish).toHaveBeenLastCalledWith('away');
+    publisher.stop();
+    warn.mockRestore();
+  });
+
+  it('republish 把当前状态重推给所有目标，MQTT / Discord 目标调用对应后端命令', () => {
+    const bus = new EventBus<AppEvents>();
+    const publisher = new StatusPublisher(bus);
+    publisher.addSink(mqttStatusSink);
+    publisher.addSink(discordStatusSink);
+    publisher.start();
+    bus.emit('context:changed', { from: 'unknown', to: 'idle' });
+    vi.mocked(invoke).mockClear();
+
+    publisher.republish();
+    expect(invoke).toHaveBeenCalledWith('publish_status_mqtt', { status: 'away' });
+    expect(invoke).toHaveBeenCalledWith('publish_status_discord', { status: 'away' });
+    publisher.stop();
+  });
+});
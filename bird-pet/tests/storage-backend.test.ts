@@ -0,0 +1,65 @@
+/**
+ * StorageBackend 单元测试
+ *
+ * 覆盖内存后端的拷贝语义，以及 StorageService 通过注入后端工作
+ * （不依赖 tauri-plugin-store）。
+ */
+import { describe, it, expect } from 'vitest';
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import { StorageService, STORE_KEYS } from '../src/core/storage';
+
+describe('MemoryStorageBackend', () => {
+  it('未写入的 key 返回 undefined', async () => {
+    const backend = new MemoryStorageBackend();
+    expect(await backend.get('missing')).toBeUndefined();
+  });
+
+  it('读写经过序列化，修改读出的对象不影响存储', async () => {
+    const backend = new MemoryStorageBackend();
+    const value = { list: [1, 2] };
+    await backend.set('k', value);
+    value.list.push(3);
+
+    const read = await backend.get<{ list: number[] }>('k');
+    expect(read).toEqual({ list: [1, 2] });
+    read!.list.push(4);
+    expect(await backend.get('k')).toEqual({ list: [1, 2] });
+  });
+
+  it('写入 undefined 等同删除', async () => {
+    const backend = new MemoryStorageBackend({ k: 1 });
+    await backend.set('k', undefined);
+    expect(backend.snapshot()).toEqual({});
+  });
+});
+
+describe('StorageService + MemoryStorageBackend', () => {
+  it('偏好读写与默认值合并', async () => {
+    const backend = new MemoryStorageBackend({
+      [STORE_KEYS.PREFERENCES]: { hourlyChimeEnabled: false },
+    });
+    const storage = new StorageService(backend);
+
+    expect((await storage.getPreferences()).hourlyChimeEnabled).toBe(false);
+    expect((await storage.getPreferences()).tipsEnabled).toBe(true);
+
+    await storage.setPreferences({ tipsEnabled: false });
+    expect(backend.snapshot()[STORE_KEYS.PREFERENCES]).toMatchObject({
+      hourlyChimeEnabled: false,
+      tipsEnabled: false,
+    });
+  });
+
+  it('并发递增交互次数不丢失', async () => {
+    const backend = new MemoryStorageBackend();
+    const storage = new StorageService(backend);
+
+    await Promise.all([
+      storage.incrementInteraction(),
+      storage.incrementInteraction(),
+      storage.incrementInteraction(),
+    ]);
+
+    expect(await storage.getInteractionCount()).toBe(3);
+  });
+});
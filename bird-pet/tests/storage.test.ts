@@ -4,33 +4,17 @@
  * 覆盖 schema 迁移/兜底修复：验证 getPreferences() 和 getPetOwner()
  * 在存储数据缺少字段时自动补全默认值。
  */
-import { describe, it, expect, vi, beforeEach } from 'vitest';
-
-// ─── Mock tauri-plugin-store ───
-
-let mockStoreData: Record<string, unknown> = {};
-
-vi.mock('@tauri-apps/plugin-store', () => {
-  class MockLazyStore {
-    async get(key: string) {
-      return mockStoreData[key] ?? null;
-    }
-    async set(key: string, value: unknown) {
-      mockStoreData[key] = value;
-    }
-    async save() {}
-  }
-  return { LazyStore: MockLazyStore };
-});
-
-import { StorageService } from '../src/core/storage';
+import { describe, it, expect, beforeEach } from 'vitest';
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import { StorageService, STORE_KEYS } from '../src/core/storage';
 
 describe('StorageService', () => {
+  let backend: MemoryStorageBackend;
   let storage: StorageService;
 
   beforeEach(() => {
-    mockStoreData = {};
-    storage = new StorageService();
+    backend = new MemoryStorageBackend();
+    storage = new StorageService(backend);
   });
 
   describe('getPreferences - schema 兜底', () => {
@@ -39,21 +23,37 @@ describe('StorageService', () => {
       expect(prefs).toEqual({
         hourlyChimeEnabled: true,
         systemMonitorEnabled: true,
+        alertThresholds: {
+          cpu: { triggerAbove: 85, sustainMinutes: 3, clearBelow: 60 },
+          memory: { triggerAbove: 90, sustainMinutes: 1, clearBelow: 80 },
+        },
         contextAwarenessEnabled: true,
         quietHoursStart: -1,
         quietHoursEnd: -1,
         nightModeEnabled: true,
         autoStartEnabled: true,
+        morningRoutineEnabled: false,
+        workdayStartHour: 9,
+        morningRoutineGreeting: true,
+        morningRoutinePomodoro: false,
+        tipsEnabled: true,
+        location: null,
+        autoMemoriesEnabled: false,
+        activityFidelity: 'high',
+        gitWatchEnabled: false,
+        gitWatchRepos: [],
+        statusMqtt: null,
+        statusDiscordClientId: null,
       });
     });
 
     it('旧版数据缺少 nightModeEnabled/autoStartEnabled 时自动补全', async () => {
       // 模拟 v0.3.0 时代的旧数据（只有 3 个字段）
-      mockStoreData.preferences = {
+      await backend.set(STORE_KEYS.PREFERENCES, {
         hourlyChimeEnabled: true,
         systemMonitorEnabled: false,
         contextAwarenessEnabled: true,
-      };
+      });
 
       const prefs = await storage.getPreferences();
 
@@ -70,7 +70,7 @@ describe('StorageService', () => {
     });
 
     it('用户修改的值不被默认值覆盖', async () => {
-      mockStoreData.preferences = {
+      await backend.set(STORE_KEYS.PREFERENCES, {
         hourlyChimeEnabled: false,
         systemMonitorEnabled: false,
         contextAwarenessEnabled: false,
@@ -78,7 +78,7 @@ describe('StorageService', () => {
         quietHoursEnd: 7,
         nightModeEnabled: false,
         autoStartEnabled: false,
-      };
+      });
 
       const prefs = await storage.getPreferences();
       expect(prefs.hourlyChimeEnabled).toBe(false);
@@ -98,12 +98,12 @@ describe('StorageService', () => {
     });
 
     it('旧版数据缺少 birthday 时自动补全', async () => {
-      mockStoreData.petOwner = {
+      await backend.set(STORE_KEYS.PET_OWNER, {
         name: '小明',
         nicknames: ['明明'],
         metDate: '2025-06-01',
         // 缺少 birthday
-      };
+      });
 
       const owner = await storage.getPetOwner();
       expect(owner.name).toBe('小明');
@@ -112,12 +112,12 @@ describe('StorageService', () => {
     });
 
     it('旧版数据缺少 nicknames 时自动补全（防止 undefined 访问）', async () => {
-      mockStoreData.petOwner = {
+      await backend.set(STORE_KEYS.PET_OWNER, {
         name: '小红',
         metDate: '2025-01-01',
         birthday: '03-15',
         // 缺少 nicknames
-      };
+      });
 
       const owner = await storage.getPetOwner();
       expect(owner.nicknames).toEqual(['芊芊', '雨芊', '小芊', '芊宝']); // 默认值
@@ -127,11 +127,11 @@ describe('StorageService', () => {
   describe('setPreferences - 写入路径兼容', () => {
     it('部分更新应保留其他字段的完整性', async () => {
       // 先存入旧版不完整数据
-      mockStoreData.preferences = {
+      await backend.set(STORE_KEYS.PREFERENCES, {
         hourlyChimeEnabled: true,
         systemMonitorEnabled: true,
         contextAwarenessEnabled: true,
-      };
+      });
 
       // 更新一个字段
       await storage.setPreferences({ systemMonitorEnabled: false });
@@ -2,7 +2,8 @@
  * SystemMonitor 单元测试
  *
  * 覆盖 stop() 泄漏修复：验证 setTimeout 句柄被正确清理，
- * 以及防重入保护。
+ * 以及防重入保护；报警需持续超标才触发，同一轮触发的多条规则都会提醒，
+ * 报警阈值取自偏好。
  */
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 
@@ -20,7 +21,13 @@ vi.mock('@tauri-apps/api/core', () => ({
 import { invoke } from '@tauri-apps/api/core';
 import { SystemMonitor } from '../src/features/system-monitor';
 import type { BubbleManager } from '../src/core/bubble-manager';
-import type { StorageService, UserPreferences } from '../src/core/storage';
+import { MemoryStorageBackend } from '../src/core/storage-backend';
+import {
+  DEFAULT_PREFERENCES,
+  StorageService,
+  STORE_KEYS,
+  type UserPreferences,
+} from '../src/core/storage';
 
 /** 创建 mock BubbleManager */
 function mockBubble(): BubbleManager {
@@ -30,20 +37,11 @@ function mockBubble(): BubbleManager {
   } as unknown as BubbleManager;
 }
 
-/** 创建 mock StorageService */
-function mockStorage(prefs: Partial<UserPreferences> = {}): StorageService {
-  const defaults: UserPreferences = {
-    hourlyChimeEnabled: true,
-    systemMonitorEnabled: true,
-    contextAwarenessEnabled: true,
-    quietHoursStart: -1,
-    quietHoursEnd: -1,
-    nightModeEnabled: true,
-    autoStartEnabled: true,
-  };
-  return {
-    getPreferences: vi.fn().mockResolvedValue({ ...defaults, ...prefs }),
-  } as unknown as StorageService;
+/** 创建基于内存后端的 StorageService（未指定的偏好取默认值） */
+function createStorage(prefs: Partial<UserPreferences> = {}): StorageService {
+  return new StorageService(
+    new MemoryStorageBackend({ [STORE_KEYS.PREFERENCES]: { ...DEFAULT_PREFERENCES, ...prefs } }),
+  );
 }
 
 describe('SystemMonitor', () => {
@@ -51,7 +49,7 @@ describe('SystemMonitor', () => {
     vi.useFakeTimers();
     // Node.js 环境中 window 不存在，需要 stub
     vi.stubGlobal('window', globalThis);
-    vi.mocked(invoke).mockClear();
+    vi.mocked(invoke).mockReset();
   });
 
   afterEach(() => {
@@ -60,7 +58,7 @@ describe('SystemMonitor', () => {
   });
 
   it('启动后 15 秒内调用 stop() 应阻止 interval 创建', async () => {
-    const monitor = new SystemMonitor(mockBubble(), mockStorage());
+    const monitor = new SystemMonitor(mockBubble(), createStorage());
     await monitor.start();
 
     // 5 秒后 stop
@@ -73,7 +71,7 @@ describe('SystemMonitor', () => {
   });
 
   it('正常流程：15 秒后首次 poll + 后续轮询', async () => {
-    const monitor = new SystemMonitor(mockBubble(), mockStorage());
+    const monitor = new SystemMonitor(mockBubble(), createStorage());
     await monitor.start();
 
     // 快进 15 秒 → 触发首次 poll
@@ -86,7 +84,7 @@ describe('SystemMonitor', () => {
   });
 
   it('stop() 应同时清除 delay timer 和 interval', async () => {
-    const monitor = new SystemMonitor(mockBubble(), mockStorage());
+    const monitor = new SystemMonitor(mockBubble(), createStorage());
     await monitor.start();
 
     // 快进 15 秒，让 interval 创建
@@ -101,7 +99,7 @@ describe('SystemMonitor', () => {
   });
 
   it('重复调用 start() 不应创建多个 timer（防重入）', async () => {
-    const monitor = new SystemMonitor(mockBubble(), mockStorage());
+    const monitor = new SystemMonitor(mockBubble(), createStorage());
     await monitor.start();
     await monitor.start(); // 第二次调用
 
@@ -112,7 +110,7 @@ describe('SystemMonitor', () => {
   });
 
   it('systemMonitorEnabled=false 时不启动', async () => {
-    const monitor = new SystemMonitor(mockBubble(), mockStorage({ systemMonitorEnabled: false }));
+    const monitor = new SystemMonitor(mockBubble(), createStorage({ systemMonitorEnabled: false }));
     await monitor.start();
 
     vi.advanceTimersByTime(60_000);
@@ -126,4 +124,76 @@ describe('SystemMonitor', () => {
     vi.advanceTimersByTime(15_000);
     expect(invoke).toHaveBeenCalledTimes(1);
   });
+
+  it('CPU 短时尖峰不报警，持续 3 分钟超标才提醒一次', async () => {
+    vi.mocked(invoke).mockResolvedValue({
+      cpu_usage: 95,
+      memory_used_gb: 4,
+      memory_total_gb: 16,
+      memory_usage_percent: 25,
+    });
+    const bubble = mockBubble();
+    const monitor = new SystemMonitor(bubble, createStorage());
+    await monitor.start();
+
+    // 首次 poll + 5 次轮询 = 2.5 分钟持续超标
+    await vi.advanceTimersByTimeAsync(15_000 + 5 * 30_000);
+    expect(bubble.say).not.toHaveBeenCalled();
+
+    await vi.advanceTimersByTimeAsync(30_000);
+    expect(bubble.say).toHaveBeenCalledTimes(1);
+
+    // 未回落前不重复提醒
+    await vi.advanceTimersByTimeAsync(10 * 30_000);
+    expect(bubble.say).toHaveBeenCalledTimes(1);
+    monitor.stop();
+  });
+
+  it('同一轮同时触发的规则都会提醒', async () => {
+    vi.mocked(invoke).mockResolvedValue({
+      cpu_usage: 95,
+      memory_used_gb: 15,
+      memory_total_gb: 16,
+      memory_usage_percent: 95,
+    });
+    const bubble = mockBubble();
+    const monitor = new SystemMonitor(bubble, createStorage(), [
+      { id: 'cpu-high', metric: 'cpu', triggerAbove: 85, sustainMs: 60_000, clearBelow: 60 },
+      { id: 'memory-high', metric: 'memory', triggerAbove: 90, sustainMs: 60_000, clearBelow: 80 },
+    ]);
+    await monitor.start();
+
+    await vi.advanceTimersByTimeAsync(15_000 + 2 * 30_000);
+    expect(bubble.say).toHaveBeenCalledTimes(2);
+    const texts = vi.mocked(bubble.say).mock.calls.map(([msg]) => msg.text);
+    expect(texts[0]).toContain('CPU 95%');
+    expect(texts[1]).toContain('15.0/16.0 GB');
+    monitor.stop();
+  });
+
+  it('按偏好中的阈值报警', async () => {
+    vi.mocked(invoke).mockResolvedValue({
+      cpu_usage: 75,
+      memory_used_gb: 4,
+      memory_total_gb: 16,
+      memory_usage_percent: 25,
+    });
+    const bubble = mockBubble();
+    const monitor = new SystemMonitor(
+      bubble,
+      createStorage({
+        alertThresholds: {
+          cpu: { triggerAbove: 70, sustainMinutes: 0, clearBelow: 50 },
+          memory: { triggerAbove: 90, sustainMinutes: 1, clearBelow: 80 },
+        },
+      }),
+    );
+    await monitor.start();
+
+    // 默认阈值（85%）下不会报警，偏好阈值 70% 且立即触发
+    await vi.advanceTimersByTimeAsync(15_000);
+    expect(bubble.say).toHaveBeenCalledTimes(1);
+    expect(vi.mocked(bubble.say).mock.calls[0][0].text).toContain('CPU 75%');
+    monitor.stop();
+  });
 });
@@ -0,0 +1,130 @@
+/**
+ * TipsEngine 单元测试
+ *
+ * 覆盖：触发条件、频率上限、用过功能后永久退休、全局关闭。
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { EventBus } from '../src/events';
+import { TipsEngine, type TipsState } from '../src/features/tips';
+import { STORE_KEYS } from '../src/core/storage';
+import type { AppEvents } from '../src/types';
+
+const HOUR = 60 * 60 * 1000;
+
+function createEngine(
+  options: { tipsEnabled?: boolean; stored?: Partial<TipsState>; suppress?: string | null } = {},
+) {
+  const bus = new EventBus<AppEvents>();
+  const bubble = { say: vi.fn() };
+  const memory = { getProfile: vi.fn(() => ({ totalInteractions: 0 })) };
+  const data: Record<string, unknown> = { [STORE_KEYS.TIPS_STATE]: options.stored };
+  const storage = {
+    getPreferences: vi.fn(async () => ({ tipsEnabled: options.tipsEnabled ?? true })),
+    get: vi.fn(async (key: string, fallback: unknown) => data[key] ?? fallback),
+    set: vi.fn(async (key: string, value: unknown) => {
+      data[key] = value;
+    }),
+  };
+  const quietMode = { shouldSuppress: vi.fn(() => options.suppress ?? null) };
+  const engine = new TipsEngine(
+    bus,
+    bubble as any,
+    memory as any,
+    storage as any,
+    quietMode as any,
+  );
+  return { engine, bus, bubble, memory, storage };
+}
+
+function clickTimes(bus: EventBus<AppEvents>, n: number): void {
+  for (let i = 0; i < n; i++) bus.emit('pet:clicked');
+}
+
+describe('TipsEngine', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    vi.stubGlobal('window', globalThis);
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('点击 5 次且从未打开菜单时提示右键菜单', async () => {
+    const { engine, bus, bubble } = createEngine();
+    await engine.start();
+
+    clickTimes(bus, 4);
+    expect(bubble.say).not.toHaveBeenCalled();
+
+    clickTimes(bus, 1);
+    expect(bubble.say).toHaveBeenCalledTimes(1);
+    expect(engine.getState().shownCounts.menu).toBe(1);
+    engine.stop();
+  });
+
+  it('两条小贴士之间至少间隔 4 小时', async () => {
+    const { engine, bus, bubble } = createEngine();
+    await engine.start();
+
+    clickTimes(bus, 5);
+    clickTimes(bus, 3);
+    expect(bubble.say).toHaveBeenCalledTimes(1);
+
+    vi.advanceTimersByTime(4 * HOUR);
+    clickTimes(bus, 1);
+    expect(bubble.say).toHaveBeenCalledTimes(2);
+    // 同一条最多展示 2 次，随后退休
+    expect(engine.getState().retired).toContain('menu');
+    engine.stop();
+  });
+
+  it('用过对应功能后该小贴士永久退休并持久化', async () => {
+    const { engine, bus, bubble, storage } = createEngine();
+    await engine.start();
+
+    bus.emit('menu:opened');
+    clickTimes(bus, 5);
+
+    expect(bubble.say).not.toHaveBeenCalled();
+    expect(storage.set).toHaveBeenCalledWith(
+      STORE_KEYS.TIPS_STATE,
+      expect.objectContaining({ retired: ['menu'] }),
+    );
+    engine.stop();
+  });
+
+  it('恢复已退休记录，不再提示', async () => {
+    const { engine, bus, bubble } = createEngine({ stored: { retired: ['menu'] } });
+    await engine.start();
+
+    clickTimes(bus, 10);
+    expect(bubble.say).not.toHaveBeenCalled();
+    engine.stop();
+  });
+
+  it('编码时提示番茄钟，静默时不提示', async () => {
+    const quiet = createEngine({ stored: { retired: ['menu'] }, suppress: 'meeting' });
+    await quiet.engine.start();
+    quiet.bus.emit('context:changed', { from: 'unknown', to: 'coding' });
+    expect(quiet.engine.maybeShowTip()).toBeNull();
+    quiet.engine.stop();
+
+    const active = createEngine({ stored: { retired: ['menu'] } });
+    await active.engine.start();
+    active.bus.emit('context:changed', { from: 'unknown', to: 'coding' });
+    expect(active.engine.maybeShowTip()).toBe('pomodoro');
+    active.engine.stop();
+  });
+
+  it('tipsEnabled=false 时从不提示', async () => {
+    const { engine, bus, bubble } = createEngine({ tipsEnabled: false });
+    await engine.start();
+
+    clickTimes(bus, 10);
+    vi.advanceTimersByTime(24 * HOUR);
+    expect(bubble.say).not.toHaveBeenCalled();
+    engine.stop();
+  });
+});
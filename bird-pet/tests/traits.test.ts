@@ -0,0 +1,53 @@
+import { describe, it, expect } from 'vitest';
+import { DEFAULT_TRAITS, evolveTraits, type PetTraits } from '../src/core/traits';
+import type { DailySummary } from '../src/types';
+
+function day(overrides: Partial<DailySummary> = {}): DailySummary {
+  return {
+    date: '2026-01-01',
+    activeHours: [9, 18],
+    dominantContext: 'coding',
+    contextDurations: {},
+    interactionCount: 30,
+    pomodoroCount: 2,
+    ...overrides,
+  };
+}
+
+function evolveFor(days: number, summary: DailySummary | null, from = DEFAULT_TRAITS): PetTraits {
+  let traits = from;
+  for (let i = 0; i < days; i++) {
+    traits = evolveTraits(traits, `2026-01-${String(i + 1).padStart(2, '0')}`, summary);
+  }
+  return traits;
+}
+
+describe('evolveTraits', () => {
+  it('单日变化应足够缓慢', () => {
+    const next = evolveTraits(DEFAULT_TRAITS, '2026-01-01', day());
+    expect(next.familiarity).toBeGreaterThan(0);
+    expect(next.familiarity).toBeLessThan(0.05);
+    expect(next.trust - DEFAULT_TRAITS.trust).toBeLessThan(0.05);
+    expect(next.updatedDate).toBe('2026-01-01');
+  });
+
+  it('数周的健康相处后信任度与熟悉度应明显上升', () => {
+    const traits = evolveFor(60, day());
+    expect(traits.familiarity).toBeGreaterThan(0.8);
+    expect(traits.trust).toBeGreaterThan(0.7);
+  });
+
+  it('长期熬夜应拉低信任度', () => {
+    const healthy = evolveFor(60, day());
+    const lateNight = evolveFor(60, day({ activeHours: [14, 1], pomodoroCount: 0 }));
+    expect(lateNight.trust).toBeLessThan(healthy.trust);
+  });
+
+  it('缺席的日子熟悉度缓慢回落，信任度保持不变', () => {
+    const start: PetTraits = { trust: 0.8, familiarity: 0.8, updatedDate: '' };
+    const next = evolveFor(7, null, start);
+    expect(next.trust).toBe(0.8);
+    expect(next.familiarity).toBeLessThan(0.8);
+    expect(next.familiarity).toBeGreaterThan(0.7);
+  });
+});
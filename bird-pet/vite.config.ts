@@ -23,6 +23,9 @@ export default defineConfig(async () => ({
         bubble: resolve(__dirname, 'bubble.html'),
         'memory-card': resolve(__dirname, 'memory-card.html'),
         'memory-panel': resolve(__dirname, 'memory-panel.html'),
+        nest: resolve(__dirname, 'nest.html'),
+        inspector: resolve(__dirname, 'inspector.html'),
+        settings: resolve(__dirname, 'settings.html'),
       },
     },
   },